@@ -5,21 +5,56 @@
 #![allow(non_upper_case_globals)]
 #![deny(clippy::unwrap_used, clippy::expect_used)]
 
-use fontspector_checkapi::{prelude::*, StatusCode};
+use std::path::PathBuf;
+
+use fontspector_checkapi::{prelude::*, StatusCode, WebFont};
 use pyo3::{
     prelude::*,
     types::{PyList, PyTuple},
 };
 use serde_json::json;
+use tempfile::TempDir;
 pub struct FontbakeryBridge;
 
+/// Write out faces of font collections and web fonts as plain sfnt files
+///
+/// fontTools can only open these as a whole file, so we give Python a copy of
+/// the face or the decompressed font instead. The file goes away with the
+/// returned directory.
+fn sfnt_file(testable: &Testable) -> std::io::Result<Option<(TempDir, PathBuf)>> {
+    let contents = &testable.contents;
+    if contents.face_index().is_none() && !WebFont::is_webfont(contents) {
+        return Ok(None);
+    }
+    let sfnt = contents.sfnt();
+    let extension = if sfnt.starts_with(b"OTTO") {
+        "otf"
+    } else {
+        "ttf"
+    };
+    let stem = testable
+        .filename
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or("font".to_string());
+    let directory = tempfile::tempdir()?;
+    let path = directory.path().join(format!("{}.{}", stem, extension));
+    std::fs::write(&path, sfnt)?;
+    Ok(Some((directory, path)))
+}
+
 // We isolate the Python part to avoid type/result madness.
 fn python_checkrunner_impl(
     module: &str,
     function: &str,
     testable: &Testable,
 ) -> PyResult<CheckFnResult> {
-    let filename = testable.filename.to_string_lossy();
+    let sfnt_file = sfnt_file(testable)?;
+    let filename = sfnt_file
+        .as_ref()
+        .map(|(_, path)| path)
+        .unwrap_or(&testable.filename)
+        .to_string_lossy();
     Python::with_gil(|py| {
         let module = PyModule::import_bound(py, module)?;
         let check = module.getattr(function)?;
@@ -83,6 +118,9 @@ fn python_checkrunner_impl(
                 message: Some(message),
                 severity: status,
                 code,
+                locations: vec![],
+                items: vec![],
+                images: vec![],
             });
         }
         Ok(return_result(messages))
@@ -149,6 +187,9 @@ fn register_python_checks(modulename: &str, source: &str, cr: &mut Registry) ->
                 } else {
                     py_proposal.extract()?
                 };
+                // fontbakery marks experimental checks with the date they became so
+                let experimental =
+                    obj.hasattr("experimental")? && obj.getattr("experimental")?.is_truthy()?;
                 log::info!("Registered check: {}", id);
                 let metadata = json!({
                     "module": modulename,
@@ -162,9 +203,12 @@ fn register_python_checks(modulename: &str, source: &str, cr: &mut Registry) ->
                     hotfix: None,
                     fix_source: None,
                     applies_to: "TTF",
-                    flags: CheckFlags::default(),
+                    flags: CheckFlags { experimental },
+                    tags: &[],
+                    aliases: &[],
                     implementation: CheckImplementation::CheckOne(&python_checkrunner),
                     _metadata: Some(metadata.to_string().leak()),
+                    version: 0,
                 })
             }
         }
@@ -0,0 +1,486 @@
+use std::collections::BTreeSet;
+
+use read_fonts::{
+    tables::{
+        postscript::{
+            dict::{self, Entry},
+            BlendState, FdSelect, Index, StringId,
+        },
+        variations::ItemVariationStore,
+    },
+    FontData, FontRead, TableProvider,
+};
+use skrifa::GlyphId;
+
+use crate::{CheckError, TestFont};
+
+/// The deepest subroutine nesting we will follow
+///
+/// The Type 2 charstring format limits subroutine nesting to ten levels; we
+/// go one level further so that we can see fonts which exceed the limit, but
+/// no further, so that recursive subroutines don't trap us.
+const MAX_CALL_DEPTH: usize = 11;
+
+/// What we learnt from walking a glyph's charstring
+#[derive(Debug, Clone, Default)]
+pub struct CharstringInfo {
+    /// The glyph the charstring belongs to
+    pub glyph: GlyphId,
+    /// The deepest level of subroutine nesting reached; zero if the charstring calls no subroutines
+    pub max_call_depth: usize,
+    /// The names of the charstring operators used, including those in subroutines
+    pub operators: BTreeSet<&'static str>,
+    /// Whether the charstring uses `endchar` to build an accented character, as Type 1 `seac` did
+    pub endchar_seac: bool,
+}
+
+/// The parts of a Font DICT (or of a non-CID CFF font) needed to run its charstrings
+struct FontDict<'a> {
+    /// The entries of the Private DICT
+    private: Vec<Entry>,
+    /// The local subroutines
+    subrs: Option<Index<'a>>,
+    /// The default variation store index for charstrings using this dict
+    vsindex: u16,
+}
+
+/// The charstrings of a CFF or CFF2 table, and everything needed to walk them
+struct Charstrings<'a> {
+    /// The entries of the Top DICT
+    top_dict: Vec<Entry>,
+    /// The charstrings, one per glyph
+    charstrings: Index<'a>,
+    /// The global subroutines
+    global_subrs: Index<'a>,
+    /// The font dicts; a non-CID CFF font has exactly one
+    font_dicts: Vec<FontDict<'a>>,
+    /// Which font dict each glyph uses, if there is more than one
+    fd_select: Option<FdSelect<'a>>,
+    /// The CFF2 item variation store, used to size `blend` operands
+    var_store: Option<ItemVariationStore<'a>>,
+}
+
+/// Convert a PostScript parsing error into a check error
+fn cff_error(e: impl std::fmt::Display) -> CheckError {
+    CheckError::Error(format!("Error reading CFF table: {}", e))
+}
+
+/// Read all the entries of a DICT, failing if any is malformed
+fn read_dict(data: &[u8], blend_state: Option<BlendState>) -> Result<Vec<Entry>, CheckError> {
+    dict::entries(data, blend_state)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(cff_error)
+}
+
+/// Slice the table data from an offset found in a DICT
+fn data_at(data: &[u8], offset: usize) -> Result<&[u8], CheckError> {
+    data.get(offset..)
+        .ok_or_else(|| cff_error(format!("offset {} is outside the table", offset)))
+}
+
+impl<'a> Charstrings<'a> {
+    /// Locate the charstrings, subroutines and DICTs of a CFF or CFF2 table
+    fn new(data: &'a [u8], is_cff2: bool, top_dict_data: &'a [u8]) -> Result<Self, CheckError> {
+        let top_dict = read_dict(top_dict_data, None)?;
+        let mut charstrings = None;
+        let mut private_range = None;
+        let mut fd_array = None;
+        let mut fd_select = None;
+        let mut var_store = None;
+        for entry in top_dict.iter() {
+            match entry {
+                Entry::CharstringsOffset(offset) => {
+                    charstrings =
+                        Some(Index::new(data_at(data, *offset)?, is_cff2).map_err(cff_error)?)
+                }
+                Entry::PrivateDictRange(range) => private_range = Some(range.clone()),
+                Entry::FdArrayOffset(offset) => {
+                    fd_array =
+                        Some(Index::new(data_at(data, *offset)?, is_cff2).map_err(cff_error)?)
+                }
+                Entry::FdSelectOffset(offset) => {
+                    fd_select = Some(
+                        FdSelect::read(FontData::new(data_at(data, *offset)?))
+                            .map_err(cff_error)?,
+                    )
+                }
+                Entry::VariationStoreOffset(offset) => {
+                    // The store is preceded by a uint16 length
+                    var_store = Some(
+                        ItemVariationStore::read(FontData::new(data_at(data, offset + 2)?))
+                            .map_err(cff_error)?,
+                    )
+                }
+                _ => {}
+            }
+        }
+        let charstrings =
+            charstrings.ok_or_else(|| cff_error("the Top DICT has no CharStrings offset"))?;
+        let font_dict = |range: std::ops::Range<usize>| -> Result<FontDict<'a>, CheckError> {
+            let private_data = data
+                .get(range.clone())
+                .ok_or_else(|| cff_error("the Private DICT is outside the table"))?;
+            let blend_state = var_store
+                .clone()
+                .map(|store| BlendState::new(store, &[], 0))
+                .transpose()
+                .map_err(cff_error)?;
+            let private = read_dict(private_data, blend_state)?;
+            let mut subrs = None;
+            let mut vsindex = 0;
+            for entry in private.iter() {
+                match entry {
+                    // Local subroutines are found relative to the Private DICT
+                    Entry::SubrsOffset(offset) => {
+                        subrs = Some(
+                            Index::new(data_at(data, range.start + offset)?, is_cff2)
+                                .map_err(cff_error)?,
+                        )
+                    }
+                    Entry::VariationStoreIndex(index) => vsindex = *index,
+                    _ => {}
+                }
+            }
+            Ok(FontDict {
+                private,
+                subrs,
+                vsindex,
+            })
+        };
+        let font_dicts = if let Some(fd_array) = fd_array {
+            (0..fd_array.count() as usize)
+                .map(|ix| {
+                    let fd = read_dict(fd_array.get(ix).map_err(cff_error)?, None)?;
+                    let range = fd
+                        .iter()
+                        .find_map(|entry| match entry {
+                            Entry::PrivateDictRange(range) => Some(range.clone()),
+                            _ => None,
+                        })
+                        .ok_or_else(|| cff_error("a Font DICT has no Private DICT"))?;
+                    font_dict(range)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            let range =
+                private_range.ok_or_else(|| cff_error("the Top DICT has no Private DICT"))?;
+            vec![font_dict(range)?]
+        };
+        Ok(Charstrings {
+            top_dict,
+            charstrings,
+            global_subrs: Index::default(),
+            font_dicts,
+            fd_select,
+            var_store,
+        })
+    }
+
+    /// The font dict used by a glyph
+    fn font_dict(&self, glyph: GlyphId) -> Option<&FontDict<'a>> {
+        let index = match &self.fd_select {
+            Some(fd_select) => fd_select.font_index(glyph)? as usize,
+            None => 0,
+        };
+        self.font_dicts.get(index)
+    }
+
+    /// Walk the charstring of each glyph
+    fn walk(&self) -> Result<Vec<CharstringInfo>, CheckError> {
+        (0..self.charstrings.count())
+            .map(|gid| {
+                let glyph = GlyphId::new(gid);
+                let font_dict = self
+                    .font_dict(glyph)
+                    .ok_or_else(|| cff_error(format!("glyph {} has no Font DICT", gid)))?;
+                let mut walker = CharstringWalker {
+                    charstrings: self,
+                    font_dict,
+                    vsindex: font_dict.vsindex,
+                    stack: vec![],
+                    stem_count: 0,
+                    info: CharstringInfo {
+                        glyph,
+                        ..Default::default()
+                    },
+                };
+                let charstring = self.charstrings.get(gid as usize).map_err(cff_error)?;
+                walker.walk(charstring, 0)?;
+                Ok(walker.info)
+            })
+            .collect()
+    }
+}
+
+/// The names of the one-byte charstring operators
+fn operator_name(op: u8) -> &'static str {
+    match op {
+        1 => "hstem",
+        3 => "vstem",
+        4 => "vmoveto",
+        5 => "rlineto",
+        6 => "hlineto",
+        7 => "vlineto",
+        8 => "rrcurveto",
+        10 => "callsubr",
+        11 => "return",
+        14 => "endchar",
+        15 => "vsindex",
+        16 => "blend",
+        18 => "hstemhm",
+        19 => "hintmask",
+        20 => "cntrmask",
+        21 => "rmoveto",
+        22 => "hmoveto",
+        23 => "vstemhm",
+        24 => "rcurveline",
+        25 => "rlinecurve",
+        26 => "vvcurveto",
+        27 => "hhcurveto",
+        29 => "callgsubr",
+        30 => "vhcurveto",
+        31 => "hvcurveto",
+        _ => "reserved",
+    }
+}
+
+/// The names of the two-byte (escaped) charstring operators
+fn escaped_operator_name(op: u8) -> &'static str {
+    match op {
+        0 => "dotsection",
+        3 => "and",
+        4 => "or",
+        5 => "not",
+        9 => "abs",
+        10 => "add",
+        11 => "sub",
+        12 => "div",
+        14 => "neg",
+        15 => "eq",
+        18 => "drop",
+        20 => "put",
+        21 => "get",
+        22 => "ifelse",
+        23 => "random",
+        24 => "mul",
+        26 => "sqrt",
+        27 => "dup",
+        28 => "exch",
+        29 => "index",
+        30 => "roll",
+        34 => "hflex",
+        35 => "flex",
+        36 => "hflex1",
+        37 => "flex1",
+        _ => "reserved",
+    }
+}
+
+/// State for walking one glyph's charstring through its subroutine calls
+///
+/// We don't draw anything; we only keep enough of the operand stack to
+/// resolve subroutine numbers, `blend` operands and the length of hint masks.
+struct CharstringWalker<'a, 'b> {
+    /// The charstrings and subroutines of the font
+    charstrings: &'b Charstrings<'a>,
+    /// The font dict the glyph uses
+    font_dict: &'b FontDict<'a>,
+    /// The active variation store index
+    vsindex: u16,
+    /// The operand stack
+    stack: Vec<f64>,
+    /// The number of stem hints declared so far
+    stem_count: usize,
+    /// What we have found so far
+    info: CharstringInfo,
+}
+
+impl CharstringWalker<'_, '_> {
+    /// Walk a charstring or subroutine at the given nesting depth
+    ///
+    /// Returns `false` when the glyph has ended.
+    fn walk(&mut self, data: &[u8], depth: usize) -> Result<bool, CheckError> {
+        self.info.max_call_depth = self.info.max_call_depth.max(depth);
+        if depth >= MAX_CALL_DEPTH {
+            return Ok(true);
+        }
+        let glyph = self.info.glyph;
+        let truncated = || cff_error(format!("charstring for glyph {} is truncated", glyph));
+        let mut bytes = data.iter().copied();
+        while let Some(b0) = bytes.next() {
+            let mut next = || bytes.next().ok_or_else(truncated);
+            // See "3.2 Charstring Number Encoding" in the Type 2 Charstring Format
+            match b0 {
+                28 => {
+                    let value = i16::from_be_bytes([next()?, next()?]);
+                    self.stack.push(value as f64);
+                    continue;
+                }
+                32..=246 => {
+                    self.stack.push(b0 as f64 - 139.0);
+                    continue;
+                }
+                247..=250 => {
+                    self.stack
+                        .push((b0 as f64 - 247.0) * 256.0 + next()? as f64 + 108.0);
+                    continue;
+                }
+                251..=254 => {
+                    self.stack
+                        .push(-(b0 as f64 - 251.0) * 256.0 - next()? as f64 - 108.0);
+                    continue;
+                }
+                255 => {
+                    let value = i32::from_be_bytes([next()?, next()?, next()?, next()?]);
+                    self.stack.push(value as f64 / 65536.0);
+                    continue;
+                }
+                _ => {}
+            }
+            let name = if b0 == 12 {
+                escaped_operator_name(next()?)
+            } else {
+                operator_name(b0)
+            };
+            self.info.operators.insert(name);
+            match name {
+                "callsubr" | "callgsubr" => {
+                    let Some(number) = self.stack.pop() else {
+                        continue;
+                    };
+                    let subrs = if name == "callsubr" {
+                        match &self.font_dict.subrs {
+                            Some(subrs) => subrs,
+                            None => continue,
+                        }
+                    } else {
+                        &self.charstrings.global_subrs
+                    };
+                    let index = number as i32 + subrs.subr_bias();
+                    let Ok(subr) = subrs.get(index.max(0) as usize) else {
+                        continue;
+                    };
+                    if !self.walk(subr, depth + 1)? {
+                        return Ok(false);
+                    }
+                }
+                "return" => return Ok(true),
+                "endchar" => {
+                    // An endchar with four arguments (five with a width) is
+                    // the deprecated replacement for the Type 1 seac operator
+                    if self.stack.len() >= 4 {
+                        self.info.endchar_seac = true;
+                    }
+                    return Ok(false);
+                }
+                "hstem" | "vstem" | "hstemhm" | "vstemhm" => {
+                    self.stem_count += self.stack.len() / 2;
+                    self.stack.clear();
+                }
+                "hintmask" | "cntrmask" => {
+                    // Any operands are an implied vstemhm
+                    self.stem_count += self.stack.len() / 2;
+                    self.stack.clear();
+                    for _ in 0..self.stem_count.div_ceil(8) {
+                        next()?;
+                    }
+                }
+                "vsindex" => {
+                    if let Some(index) = self.stack.pop() {
+                        self.vsindex = index as u16;
+                    }
+                    self.stack.clear();
+                }
+                "blend" => {
+                    // Replaces n default values and n * k deltas with n values
+                    let regions = self
+                        .charstrings
+                        .var_store
+                        .as_ref()
+                        .and_then(|store| {
+                            store.item_variation_data().get(self.vsindex as usize)?.ok()
+                        })
+                        .map(|data| data.region_index_count() as usize)
+                        .unwrap_or(0);
+                    let count = self.stack.pop().unwrap_or(0.0) as usize;
+                    let keep = self.stack.len().saturating_sub(count * regions);
+                    self.stack.truncate(keep);
+                }
+                // Everything else either draws, which clears the stack, or is
+                // an arithmetic operator whose results we don't track
+                _ => self.stack.clear(),
+            }
+        }
+        // CFF2 charstrings and subroutines have no endchar or return and simply run out
+        Ok(true)
+    }
+}
+
+impl TestFont<'_> {
+    /// Locate the CFF or CFF2 charstrings of the font
+    fn cff_charstring_tables(&self) -> Result<Charstrings<'_>, CheckError> {
+        if self.has_table(b"CFF2") {
+            let cff2 = self.font().cff2()?;
+            let mut charstrings =
+                Charstrings::new(cff2.offset_data().as_bytes(), true, cff2.top_dict_data())?;
+            charstrings.global_subrs = cff2.global_subrs().into();
+            Ok(charstrings)
+        } else if self.has_table(b"CFF ") {
+            let cff = self.font().cff()?;
+            let top_dict_data = cff.top_dicts().get(0).map_err(cff_error)?;
+            let mut charstrings =
+                Charstrings::new(cff.offset_data().as_bytes(), false, top_dict_data)?;
+            charstrings.global_subrs = cff.global_subrs().into();
+            Ok(charstrings)
+        } else {
+            Err(CheckError::skip(
+                "no-cff",
+                "This check only applies to fonts with CFF or CFF2 outlines",
+            ))
+        }
+    }
+
+    /// The entries of the Top DICT of the font's CFF or CFF2 table
+    pub fn cff_top_dict(&self) -> Result<Vec<Entry>, CheckError> {
+        Ok(self.cff_charstring_tables()?.top_dict)
+    }
+
+    /// The entries of each Private DICT of the font's CFF or CFF2 table
+    ///
+    /// There is one Private DICT for each Font DICT of a CID-keyed or CFF2
+    /// font, and exactly one otherwise.
+    pub fn cff_private_dicts(&self) -> Result<Vec<Vec<Entry>>, CheckError> {
+        Ok(self
+            .cff_charstring_tables()?
+            .font_dicts
+            .into_iter()
+            .map(|fd| fd.private)
+            .collect())
+    }
+
+    /// The raw contents of the String INDEX of the font's CFF table
+    ///
+    /// CFF2 tables have no strings, so this is empty for CFF2 fonts.
+    pub fn cff_strings(&self) -> Result<Vec<Vec<u8>>, CheckError> {
+        if !self.has_table(b"CFF ") {
+            return Ok(vec![]);
+        }
+        let strings = self.font().cff()?.strings();
+        (0..strings.count() as usize)
+            .map(|ix| strings.get(ix).map(|s| s.to_vec()).map_err(cff_error))
+            .collect()
+    }
+
+    /// Look up a string referenced by a CFF DICT, either a standard string or one in the String INDEX
+    pub fn cff_string(&self, id: StringId) -> Option<Vec<u8>> {
+        Some(self.font().cff().ok()?.string(id)?.bytes().to_vec())
+    }
+
+    /// Walk the charstring of every glyph of the font's CFF or CFF2 table
+    ///
+    /// This follows subroutine calls, recording how deeply they nest and which
+    /// operators each glyph uses.
+    pub fn cff_charstrings(&self) -> Result<Vec<CharstringInfo>, CheckError> {
+        self.cff_charstring_tables()?.walk()
+    }
+}
@@ -49,6 +49,9 @@ pub enum CheckImplementation<'a> {
 /// The function signature for a hotfix function
 pub type HotfixFunction = dyn Fn(&mut Testable) -> FixFnResult;
 
+/// The function signature for a source fix function
+pub type SourceFixFunction = dyn Fn(&Testable) -> FixFnResult;
+
 #[derive(Clone)]
 /// A check definition
 pub struct Check<'a> {
@@ -65,13 +68,28 @@ pub struct Check<'a> {
     /// Function pointer implementing a hotfix to the binary file
     pub hotfix: Option<&'a HotfixFunction>,
     /// Function pointer implementing a hotfix to the font source file
-    pub fix_source: Option<&'a dyn Fn(&Testable) -> FixFnResult>,
+    pub fix_source: Option<&'a SourceFixFunction>,
     /// A registered file type that this check applies to
     pub applies_to: &'a str,
     /// Additional flags for the check
     pub flags: CheckFlags,
+    /// Tags describing the check, used to select groups of checks to run
+    ///
+    /// Tags are free-form, but by convention include things like `outline`,
+    /// `metadata`, `network`, `shaping`, `variable`, `licensing` and `slow`.
+    pub tags: &'a [&'a str],
+    /// Legacy IDs for this check
+    ///
+    /// For example, the fontbakery ID of the check. These may be used in place
+    /// of the check's ID on the command line, in configuration files and in profiles.
+    pub aliases: &'a [&'a str],
     /// Metadata for the check in JSON format
     pub _metadata: Option<&'static str>,
+    /// The version of the check's implementation
+    ///
+    /// Bump this when a change to the check alters its results, so that
+    /// results stored by earlier versions are no longer reused.
+    pub version: u32,
 }
 
 // Are we? Really? I don't know. Let's find out...
@@ -82,6 +100,11 @@ impl<'a> Check<'a> {
     pub fn runs_on_collection(&self) -> bool {
         matches!(self.implementation, CheckImplementation::CheckAll(_))
     }
+    /// Does this check carry the given tag?
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| *t == tag)
+    }
+
     /// Should the check run on the given testable?
     ///
     /// Checks declare themselves to either run on a collection of files, or
@@ -28,6 +28,8 @@ pub enum FixResult {
 pub struct CheckResult {
     /// The ID of the check
     pub check_id: CheckId,
+    /// Legacy IDs for the check
+    pub check_aliases: Vec<String>,
     /// A simple title for the check
     pub check_name: String,
     /// The rationale for the check
@@ -50,13 +52,22 @@ pub struct CheckResult {
 
 impl Serialize for CheckResult {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let fields =
-            7 + self.hotfix_result.is_some() as usize + self.sourcefix_result.is_some() as usize;
+        let fields = 7
+            + !self.check_aliases.is_empty() as usize
+            + self.source_filename.is_some() as usize
+            + self.hotfix_result.is_some() as usize
+            + self.sourcefix_result.is_some() as usize;
         let mut s = serializer.serialize_struct("CheckResult", fields)?;
         s.serialize_field("check_id", &self.check_id)?;
+        if !self.check_aliases.is_empty() {
+            s.serialize_field("check_aliases", &self.check_aliases)?;
+        }
         s.serialize_field("check_name", &self.check_name)?;
         s.serialize_field("check_rationale", &self.check_rationale)?;
         s.serialize_field("filename", &self.filename)?;
+        if let Some(source_filename) = &self.source_filename {
+            s.serialize_field("source_filename", source_filename)?;
+        }
         s.serialize_field("section", &self.section)?;
         s.serialize_field("subresults", &self.subresults)?;
         s.serialize_field("worst_status", &self.worst_status())?;
@@ -82,6 +93,7 @@ impl CheckResult {
     ) -> Self {
         Self {
             check_id: check.id.to_string(),
+            check_aliases: check.aliases.iter().map(|x| x.to_string()).collect(),
             check_name: check.title.to_string(),
             check_rationale: check.rationale.to_string(),
             filename: filename.map(|x| x.to_string()),
@@ -94,6 +106,17 @@ impl CheckResult {
         }
     }
 
+    /// The filename, with the source it was built from if known
+    ///
+    /// For example, `Foo-Bold.ttf (from sources/Foo.designspace)`.
+    pub fn filename_with_source(&self) -> Option<String> {
+        let filename = self.filename.as_ref()?;
+        Some(match &self.source_filename {
+            Some(source) => format!("{} (from {})", filename, source),
+            None => filename.clone(),
+        })
+    }
+
     /// Get the worst status of all subresults
     pub fn worst_status(&self) -> StatusCode {
         self.subresults
@@ -41,8 +41,7 @@ pub fn run_check(check: Check<'_>, font: Testable) -> Option<CheckResult> {
         network_timeout: Some(10),
         configuration: Map::new(),
         check_metadata: check.metadata(),
-        full_lists: false,
-        cache: Default::default(),
+        ..Default::default()
     };
     check.run(&TestableType::Single(&font), &ctx, None)
 }
@@ -114,5 +113,5 @@ pub fn set_name_entry(
         .copy_missing_tables(f.font())
         .build();
 
-    font.contents = new_bytes;
+    font.set(new_bytes);
 }
@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use read_fonts::{CollectionRef, ReadError};
+
+use crate::{sfnt::build_sfnt, Testable};
+
+/// Separates the collection's filename from the face index in the filename of a face
+const FACE_SEPARATOR: char = '#';
+
+/// Does this data look like a TrueType or OpenType Collection?
+pub fn is_font_collection(data: &[u8]) -> bool {
+    data.starts_with(b"ttcf")
+}
+
+/// If this filename refers to a face extracted from a font collection, return
+/// the filename of the collection and the index of the face.
+///
+/// Faces are named after their collection, so the second face of
+/// `NotoSansCJK.ttc` is called `NotoSansCJK.ttc#1`.
+pub fn collection_face(filename: &Path) -> Option<(PathBuf, u32)> {
+    let filename = filename.to_str()?;
+    let (collection, index) = filename.rsplit_once(FACE_SEPARATOR)?;
+    let collection = PathBuf::from(collection);
+    let extension = collection.extension()?.to_str()?.to_lowercase();
+    if extension != "ttc" && extension != "otc" {
+        return None;
+    }
+    Some((collection, index.parse().ok()?))
+}
+
+/// Extract one face of a font collection into a standalone sfnt font
+///
+/// The face gets a copy of each table it uses, so that checks which look at
+/// the binary contents of a font work on faces just as they do on standalone
+/// font files.
+pub(crate) fn face_sfnt(data: &[u8], index: u32) -> Result<Vec<u8>, ReadError> {
+    let face = CollectionRef::new(data)?.get(index)?;
+    let mut tables = face
+        .table_directory
+        .table_records()
+        .iter()
+        .map(|record| {
+            face.table_data(record.tag())
+                .map(|data| (record.tag(), data.as_bytes().to_vec()))
+                .ok_or(ReadError::TableIsMissing(record.tag()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    tables.sort_by_key(|(tag, _)| *tag);
+    Ok(build_sfnt(face.table_directory.sfnt_version(), &tables))
+}
+
+impl Testable {
+    /// A testable for each of the faces of a font collection
+    ///
+    /// The faces share the collection's contents, and are read from them in
+    /// place; see [Contents::face_index](crate::Contents::face_index).
+    pub fn collection_faces(&self) -> Result<Vec<Testable>, ReadError> {
+        let collection = CollectionRef::new(&self.contents)?;
+        Ok((0..collection.len())
+            .map(|index| {
+                let mut filename = self.filename.clone().into_os_string();
+                filename.push(format!("{}{}", FACE_SEPARATOR, index));
+                Testable {
+                    filename: filename.into(),
+                    source: self.source.clone(),
+                    contents: self.contents.face(index),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Does this filename have the extension of a font collection?
+///
+/// Only these files are opened to look for faces, so that other files are not
+/// read before their checks run.
+fn has_collection_extension(testable: &Testable) -> bool {
+    testable
+        .extension()
+        .is_some_and(|extension| matches!(extension.to_lowercase().as_str(), "ttc" | "otc"))
+}
+
+/// Add the faces of any font collections to a list of testables
+///
+/// The collection files themselves are kept, so that checks can be run on
+/// the collection as a whole.
+pub(crate) fn expand_font_collections(testables: Vec<Testable>) -> Vec<Testable> {
+    let mut expanded = vec![];
+    for testable in testables {
+        let faces = if has_collection_extension(&testable) && is_font_collection(&testable.contents)
+        {
+            testable.collection_faces().unwrap_or_else(|e| {
+                log::warn!(
+                    "Could not read the faces of {}: {}",
+                    testable.filename.display(),
+                    e
+                );
+                vec![]
+            })
+        } else {
+            vec![]
+        };
+        expanded.push(testable);
+        expanded.extend(faces);
+    }
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_face() {
+        assert_eq!(
+            collection_face(Path::new("fonts/NotoSansCJK.ttc#3")),
+            Some((PathBuf::from("fonts/NotoSansCJK.ttc"), 3))
+        );
+        assert_eq!(collection_face(Path::new("fonts/NotoSansCJK.ttc")), None);
+        assert_eq!(collection_face(Path::new("fonts/Foo#1.ttf")), None);
+    }
+}
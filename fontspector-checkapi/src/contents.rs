@@ -0,0 +1,274 @@
+use std::{
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
+
+#[cfg(not(target_family = "wasm"))]
+use std::path::{Path, PathBuf};
+
+use crate::{collection::face_sfnt, webfont::WebFont};
+
+/// The binary contents of a [Testable](crate::Testable)
+///
+/// On native targets, the contents of a file on disk are not read when the
+/// testable is created; the file is memory-mapped the first time its contents
+/// are needed. This means that opening a large number of files is cheap, and
+/// that the operating system can page out the contents of files which are not
+/// currently being checked. Contents provided directly (as in the WASM version
+/// of fontspector, or after a hotfix) are held in memory.
+///
+/// `Contents` dereferences to a byte slice, so it can be used wherever a
+/// `&[u8]` is expected. This is the file as it is on disk; for a face of a
+/// font collection, it is the whole collection. Checks which need a plain
+/// sfnt font, even for web fonts and collection faces, should use
+/// [Contents::sfnt].
+#[derive(Clone)]
+pub struct Contents {
+    /// Where the bytes live
+    inner: Inner,
+    /// The sfnt data of a web font or collection face, once it has been built
+    ///
+    /// This is the reason why not if the web font could not be decompressed, or
+    /// the face could not be extracted.
+    sfnt: OnceLock<Arc<Result<Vec<u8>, String>>>,
+}
+
+/// Where the bytes of a [Contents] live
+#[derive(Clone)]
+enum Inner {
+    /// Bytes held in memory
+    Memory(Vec<u8>),
+    /// A file which is mapped on first use
+    #[cfg(not(target_family = "wasm"))]
+    File {
+        /// The file to map
+        path: PathBuf,
+        /// The mapping, once it has been made
+        ///
+        /// This is `None` if the file is empty or could not be mapped.
+        map: OnceLock<Arc<Option<memmap2::Mmap>>>,
+    },
+    /// One face of a font collection
+    Face {
+        /// The contents of the collection
+        collection: Box<Contents>,
+        /// The index of the face within the collection
+        index: u32,
+    },
+}
+
+impl Contents {
+    /// Contents which will be loaded from a file when they are first needed
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn from_file(path: impl Into<PathBuf>) -> Self {
+        Inner::File {
+            path: path.into(),
+            map: OnceLock::new(),
+        }
+        .into()
+    }
+
+    /// The contents of one face of the font collection in these contents
+    ///
+    /// The face shares the collection's bytes; nothing is copied until a check
+    /// asks for the face as a standalone font with [Contents::sfnt].
+    pub(crate) fn face(&self, index: u32) -> Self {
+        Inner::Face {
+            collection: Box::new(self.clone()),
+            index,
+        }
+        .into()
+    }
+
+    /// If these are the contents of a face of a font collection, the index of the face
+    pub fn face_index(&self) -> Option<u32> {
+        match &self.inner {
+            Inner::Face { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Whether the contents have been read into memory or mapped
+    pub fn is_loaded(&self) -> bool {
+        match &self.inner {
+            Inner::Memory(_) => true,
+            #[cfg(not(target_family = "wasm"))]
+            Inner::File { map, .. } => map.get().is_some(),
+            Inner::Face { collection, .. } => collection.is_loaded(),
+        }
+    }
+
+    /// The contents as a plain sfnt font
+    ///
+    /// WOFF and WOFF2 fonts are decompressed, and the faces of font collections
+    /// are extracted into a font of their own, the first time this is called.
+    /// The result is kept for as long as the contents are, so that each check
+    /// does not build it again. Other contents, or fonts which cannot be
+    /// decompressed or extracted, are returned as they are.
+    pub fn sfnt(&self) -> &[u8] {
+        self.try_sfnt().unwrap_or(self)
+    }
+
+    /// The contents as a plain sfnt font, or why it could not be built
+    ///
+    /// This is [Contents::sfnt] for checks which need to know whether a web
+    /// font could be decompressed or a face extracted.
+    pub fn try_sfnt(&self) -> Result<&[u8], String> {
+        let face = self.face_index();
+        if face.is_none() && !WebFont::is_webfont(self) {
+            return Ok(self);
+        }
+        let sfnt = self.sfnt.get_or_init(|| {
+            let sfnt = match face {
+                Some(index) => face_sfnt(self, index).map_err(|e| {
+                    let e = format!("Could not extract face {} of font collection: {}", index, e);
+                    log::warn!("{}", e);
+                    e
+                }),
+                None => WebFont::new(self)
+                    .and_then(|webfont| webfont.to_sfnt())
+                    .map_err(|e| {
+                        let e = format!("Could not decompress web font: {}", e);
+                        log::warn!("{}", e);
+                        e
+                    }),
+            };
+            Arc::new(sfnt)
+        });
+        sfnt.as_deref().map_err(|e| e.clone())
+    }
+}
+
+impl From<Inner> for Contents {
+    fn from(inner: Inner) -> Self {
+        Contents {
+            inner,
+            sfnt: OnceLock::new(),
+        }
+    }
+}
+
+/// Memory-map a file, logging rather than failing if it cannot be read
+///
+/// The file was readable when its testable was created, so a failure here means
+/// it has been removed or changed underneath us; checks will see empty contents.
+#[cfg(not(target_family = "wasm"))]
+fn map_file(path: &Path) -> Option<memmap2::Mmap> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| log::error!("Could not open {}: {}", path.display(), e))
+        .ok()?;
+    // Zero-length files cannot be mapped on all platforms
+    if file.metadata().map(|m| m.len() == 0).unwrap_or(true) {
+        return None;
+    }
+    // SAFETY: the mapping is read-only. If another process truncates the file
+    // while we are checking it, we may crash; fontspector does not modify the
+    // files it checks except through `Testable::save`, which writes from a copy.
+    unsafe { memmap2::Mmap::map(&file) }
+        .map_err(|e| log::error!("Could not map {}: {}", path.display(), e))
+        .ok()
+}
+
+impl Deref for Contents {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.inner {
+            Inner::Memory(bytes) => bytes,
+            #[cfg(not(target_family = "wasm"))]
+            Inner::File { path, map } => map
+                .get_or_init(|| Arc::new(map_file(path)))
+                .as_deref()
+                .unwrap_or(&[]),
+            Inner::Face { collection, .. } => collection,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Contents {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl From<Vec<u8>> for Contents {
+    fn from(bytes: Vec<u8>) -> Self {
+        Inner::Memory(bytes).into()
+    }
+}
+
+impl Default for Contents {
+    fn default() -> Self {
+        Inner::Memory(vec![]).into()
+    }
+}
+
+impl PartialEq for Contents {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other && self.face_index() == other.face_index()
+    }
+}
+
+impl Eq for Contents {}
+
+impl Hash for Contents {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+        self.face_index().hash(state);
+    }
+}
+
+impl std::fmt::Debug for Contents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.inner {
+            Inner::Memory(bytes) => write!(f, "Contents({} bytes)", bytes.len()),
+            #[cfg(not(target_family = "wasm"))]
+            Inner::File { path, .. } => write!(f, "Contents({:?})", path),
+            Inner::Face { collection, index } => write!(f, "{:?}#{}", collection, index),
+        }
+    }
+}
+
+impl serde::Serialize for Contents {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_lazy_file_contents() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = tempdir.path();
+        let path = dir.join("test.bin");
+        std::fs::write(&path, b"hello").unwrap();
+        let contents = Contents::from_file(&path);
+        assert!(!contents.is_loaded());
+        assert_eq!(&contents[..], b"hello");
+        assert!(contents.is_loaded());
+        assert_eq!(contents, Contents::from(b"hello".to_vec()));
+        // Empty files are not mapped
+        let empty = dir.join("empty.bin");
+        std::fs::write(&empty, b"").unwrap();
+        assert!(Contents::from_file(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_face_contents() {
+        let collection = Contents::from(b"ttcf not really a collection".to_vec());
+        let face = collection.face(1);
+        assert_eq!(face.face_index(), Some(1));
+        assert_eq!(collection.face_index(), None);
+        // Faces share the collection's bytes, but are distinct from each other
+        assert_eq!(&face[..], &collection[..]);
+        assert_ne!(face, collection.face(0));
+        assert_ne!(face, collection);
+        // A face which can't be extracted is left as it is
+        assert_eq!(face.sfnt(), &collection[..]);
+    }
+}
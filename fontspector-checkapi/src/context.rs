@@ -1,12 +1,70 @@
 use std::{
+    any::{Any, TypeId},
     collections::HashMap,
-    sync::{Arc, RwLock},
+    convert::Infallible,
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use serde_json::{Map, Value};
 
 use crate::{Check, Profile};
 
+/// A cache of values of any type, keyed by their type and a string
+///
+/// Unlike [Context::cached_question], values are stored as they are, so
+/// nothing needs to be serialized or deserialized to share them.
+///
+/// Some of these values (drawn glyphs, parsed sources) are large, so a runner
+/// which knows how many checks will use the cache can say so with
+/// [TypedCache::expect_checks], and report each one finishing with
+/// [TypedCache::check_finished]; the values are dropped after the last one.
+#[derive(Clone, Default)]
+pub struct TypedCache {
+    /// The cached values
+    values: Arc<RwLock<HashMap<(TypeId, String), Arc<dyn Any + Send + Sync>>>>,
+    /// The number of checks still expected to use the cache
+    remaining_checks: Arc<AtomicUsize>,
+}
+
+impl TypedCache {
+    /// Expect this many more checks to use the cache
+    pub fn expect_checks(&self, count: usize) {
+        self.remaining_checks.fetch_add(count, Ordering::SeqCst);
+    }
+
+    /// Note that one of the expected checks has finished with the cache
+    ///
+    /// Once the last expected check has finished, the cached values are
+    /// dropped. If nothing was expected, this does nothing.
+    pub fn check_finished(&self) {
+        let previous =
+            self.remaining_checks
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
+                    remaining.checked_sub(1)
+                });
+        if previous == Ok(1) {
+            if let Ok(mut values) = self.values.write() {
+                values.clear();
+            }
+        }
+    }
+}
+
+impl Debug for TypedCache {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let entries = self
+            .values
+            .read()
+            .map(|cache| cache.len())
+            .unwrap_or_default();
+        write!(f, "<TypedCache: {} entries>", entries)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 /// The context of a check
 ///
@@ -27,10 +85,12 @@ pub struct Context {
     /// and marshal the results back into Rust"), but need to know which Python function to
     /// call.
     pub check_metadata: Value,
-    /// Whether to return full or abbreviated lists of items in check results
-    pub full_lists: bool,
     /// A cache, specific to this testable
     pub cache: Arc<RwLock<Map<String, Value>>>,
+    /// A cache of typed values, specific to this testable
+    ///
+    /// See [Context::get_or_compute].
+    pub typed_cache: TypedCache,
 }
 
 impl Context {
@@ -41,8 +101,8 @@ impl Context {
             network_timeout: self.network_timeout,
             configuration: self.configuration.clone(),
             check_metadata: self.check_metadata.clone(),
-            full_lists: self.full_lists,
             cache: Arc::new(RwLock::new(Map::new())),
+            typed_cache: TypedCache::default(),
         }
     }
 
@@ -67,6 +127,12 @@ impl Context {
     ) -> Self {
         // Start with the user's configuration.
         let mut our_copy = configuration.clone();
+        // The user may have configured the check using one of its legacy IDs
+        for alias in check.aliases {
+            if let Some(value) = our_copy.remove(*alias) {
+                our_copy.entry(check.id.to_string()).or_insert(value);
+            }
+        }
         // Now fill in any default configuration values for this check
         let check_config_defaults: HashMap<String, Value> = profile.defaults(check.id);
         if !check_config_defaults.is_empty() {
@@ -85,8 +151,8 @@ impl Context {
             network_timeout: self.network_timeout,
             configuration: our_copy,
             check_metadata: check.metadata(),
-            full_lists: self.full_lists,
             cache: self.cache.clone(),
+            typed_cache: self.typed_cache.clone(),
         }
     }
 
@@ -114,4 +180,83 @@ impl Context {
         }
         Ok(answer)
     }
+
+    /// Get a value from the typed cache, computing it if it isn't there
+    ///
+    /// Values are keyed by their type as well as by `key`, so different kinds
+    /// of value can use the same key (a filename, say) without colliding.
+    pub fn get_or_compute<T>(&self, key: &str, compute: impl FnOnce() -> T) -> Arc<T>
+    where
+        T: Any + Send + Sync,
+    {
+        match self.get_or_try_compute(key, || Ok::<T, Infallible>(compute())) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Get a value from the typed cache, computing it if it isn't there
+    ///
+    /// If the computation fails, the error is returned and nothing is cached.
+    pub fn get_or_try_compute<T, E>(
+        &self,
+        key: &str,
+        compute: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Arc<T>, E>
+    where
+        T: Any + Send + Sync,
+    {
+        let cache_key = (TypeId::of::<T>(), key.to_string());
+        if let Ok(cache) = self.typed_cache.values.read() {
+            if let Some(value) = cache.get(&cache_key) {
+                if let Ok(value) = value.clone().downcast::<T>() {
+                    return Ok(value);
+                }
+            }
+        }
+        let value = Arc::new(compute()?);
+        if let Ok(mut cache) = self.typed_cache.values.write() {
+            cache.insert(cache_key, value.clone());
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_cache() {
+        let context = Context::default();
+        let first = context.get_or_compute("key", || vec![1, 2, 3]);
+        let second = context.get_or_compute("key", || -> Vec<i32> { unreachable!() });
+        assert!(Arc::ptr_eq(&first, &second));
+        // The same key with a different type is a different entry
+        let other = context.get_or_compute("key", || "hello".to_string());
+        assert_eq!(other.as_str(), "hello");
+        // Errors are not cached
+        let failed = context.get_or_try_compute::<u32, _>("failing", || Err("nope"));
+        assert_eq!(failed, Err("nope"));
+        let succeeded = context.get_or_try_compute::<u32, &str>("failing", || Ok(42));
+        assert_eq!(succeeded.map(|v| *v), Ok(42));
+        // Copies of the context share the cache
+        let copy = context.clone();
+        assert_eq!(*copy.get_or_compute("failing", || 0u32), 42);
+    }
+
+    #[test]
+    fn test_typed_cache_freed_after_last_check() {
+        let context = Context::default();
+        // Nothing expected, so finishing a check keeps the values
+        context.get_or_compute("key", || 1u32);
+        context.typed_cache.check_finished();
+        assert_eq!(*context.get_or_compute("key", || 2u32), 1);
+
+        context.typed_cache.expect_checks(2);
+        context.typed_cache.check_finished();
+        assert_eq!(*context.get_or_compute("key", || 2u32), 1);
+        context.typed_cache.check_finished();
+        assert_eq!(*context.get_or_compute("key", || 3u32), 3);
+    }
 }
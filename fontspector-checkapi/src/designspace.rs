@@ -0,0 +1,159 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use norad::{
+    designspace::{DesignSpaceDocument, Source},
+    Font, Layer,
+};
+
+use crate::{ufo::load_ufo, Context, FileType, FileTypeConvert, Testable};
+
+/// A file type for designspace documents
+///
+/// This is a separate type from [FileType] so that converting a [Testable]
+/// with it gives you a [Designspace]; register its `filetype` with the registry.
+pub struct DesignspaceType {
+    /// The file type to register
+    pub filetype: FileType<'static>,
+}
+
+impl DesignspaceType {
+    /// Convert a testable to a [Designspace], sharing its loaded UFOs with
+    /// the testable's other checks through the context
+    ///
+    /// Returns `Ok(None)` if the testable is not a designspace, and an error
+    /// if it is one but could not be parsed.
+    pub fn from_testable_cached(
+        &self,
+        t: &Testable,
+        context: &Context,
+    ) -> Result<Option<Designspace>, String> {
+        self.filetype
+            .applies(t)
+            .then(|| Designspace::load(&t.filename, &t.contents, Some(context)))
+            .transpose()
+    }
+}
+
+/// A file type for designspace documents
+pub const DESIGNSPACE: DesignspaceType = DesignspaceType {
+    filetype: FileType {
+        pattern: "*.designspace",
+    },
+};
+
+/// A designspace document to be tested, together with its source UFOs
+pub struct Designspace {
+    /// The path to the designspace file
+    pub filename: PathBuf,
+    /// The parsed designspace document
+    pub document: DesignSpaceDocument,
+    /// The UFO for each of the document's sources, in order, or the reason it could not be loaded
+    ufos: Vec<Result<Arc<Font>, String>>,
+}
+
+/// A source of a designspace: a layer of a UFO at a location in the design space
+pub struct Master<'a> {
+    /// The designspace's description of the source
+    pub source: &'a Source,
+    /// The UFO containing the source
+    pub font: &'a Font,
+    /// The layer of the UFO which the source uses
+    pub layer: &'a Layer,
+}
+
+impl Master<'_> {
+    /// A name for the master, suitable for reporting to the user
+    pub fn name(&self) -> String {
+        match &self.source.layer {
+            Some(layer) => format!("{} (layer {})", self.source.filename, layer),
+            None => self.source.filename.clone(),
+        }
+    }
+}
+
+impl Designspace {
+    /// Parse a designspace document and load the UFOs it refers to
+    ///
+    /// UFOs which cannot be loaded are recorded rather than causing an error;
+    /// see [Designspace::load_errors].
+    pub fn new(filename: &Path, contents: &[u8]) -> Result<Self, String> {
+        Self::load(filename, contents, None)
+    }
+
+    /// Parse a designspace document, using the context's cache (if one is
+    /// given) for its UFOs
+    fn load(filename: &Path, contents: &[u8], context: Option<&Context>) -> Result<Self, String> {
+        let contents = std::str::from_utf8(contents)
+            .map_err(|_| format!("{} is not valid UTF-8", filename.display()))?;
+        let document = quick_xml::de::from_str::<DesignSpaceDocument>(contents)
+            .map_err(|e| format!("Could not parse {}: {}", filename.display(), e))?;
+        let directory = filename.parent().unwrap_or(Path::new(""));
+        let ufos = document
+            .sources
+            .iter()
+            .map(|source| load_ufo(&directory.join(&source.filename), context))
+            .collect();
+        Ok(Designspace {
+            filename: filename.to_path_buf(),
+            document,
+            ufos,
+        })
+    }
+
+    /// The path of a source's UFO, resolved relative to the designspace file
+    pub fn source_path(&self, source: &Source) -> PathBuf {
+        self.filename
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(&source.filename)
+    }
+
+    /// The sources of the designspace which could be loaded, in document order
+    pub fn masters(&self) -> impl Iterator<Item = Master<'_>> {
+        self.document
+            .sources
+            .iter()
+            .zip(self.ufos.iter())
+            .filter_map(|(source, ufo)| {
+                let font = ufo.as_ref().ok()?;
+                let layer = match &source.layer {
+                    Some(name) => font.layers.get(name)?,
+                    None => font.default_layer(),
+                };
+                Some(Master {
+                    source,
+                    font,
+                    layer,
+                })
+            })
+    }
+
+    /// The sources of the designspace which could not be loaded, and why
+    pub fn load_errors(&self) -> impl Iterator<Item = (&Source, String)> {
+        self.document
+            .sources
+            .iter()
+            .zip(self.ufos.iter())
+            .filter_map(|(source, ufo)| match ufo {
+                Err(e) => Some((source, e.clone())),
+                Ok(font) => source
+                    .layer
+                    .as_ref()
+                    .filter(|name| font.layers.get(name).is_none())
+                    .map(|name| (source, format!("{} has no layer {}", source.filename, name))),
+            })
+    }
+}
+
+impl<'a> FileTypeConvert<'a, Designspace> for DesignspaceType {
+    fn from_testable(&self, t: &'a Testable) -> Option<Designspace> {
+        self.filetype
+            .applies(t)
+            .then(|| Designspace::new(&t.filename, &t.contents))
+            .transpose()
+            .unwrap_or(None)
+    }
+}
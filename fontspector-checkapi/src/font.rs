@@ -1,11 +1,15 @@
+#[cfg(feature = "kurbo")]
+use crate::pens::BezGlyph;
 use crate::{
     constants::{OutlineType, RIBBI_STYLE_NAMES, STATIC_STYLE_NAMES},
     filetype::FileTypeConvert,
+    webfont::WebFont,
     CheckError, Context, FileType, Testable,
 };
 use itertools::Either;
 use read_fonts::{
     tables::{
+        avar::AxisValueMap,
         gdef::GlyphClassDef,
         glyf::Glyph,
         gpos::{PairPos, PairPosFormat1, PairPosFormat2, PositionSubtables},
@@ -25,7 +29,10 @@ use skrifa::{
     string::StringId,
     GlyphId, GlyphId16, MetadataProvider, Tag,
 };
+#[cfg(feature = "kurbo")]
+use std::sync::Arc;
 use std::{
+    borrow::Cow,
     cell::RefCell,
     collections::{HashMap, HashSet},
     error::Error,
@@ -37,8 +44,11 @@ use std::{
 pub struct TestFont<'a> {
     /// The path to the font file
     pub filename: PathBuf,
-    /// The font's binary data
-    font_data: &'a [u8],
+    /// The font's binary data; for WOFF and WOFF2 files, the decompressed sfnt,
+    /// and for a face of a font collection, the whole collection
+    font_data: Cow<'a, [u8]>,
+    /// The index of the font within its binary data, for faces of font collections
+    index: u32,
     // Try to avoid caching stuff here unless you really need to, the conversion Testable->TestFont
     // should be cheap as it is run for each check.
     /// The number of glyphs in the font
@@ -54,14 +64,35 @@ impl Debug for TestFont<'_> {
 }
 
 /// A file type for TrueType fonts
+///
+/// This also matches WOFF and WOFF2 files, which are decompressed to their
+/// underlying sfnt data, and the individual faces of font collections, so that
+/// all font checks run on web fonts and collections too.
 pub const TTF: FileType = FileType {
-    pattern: "*.[ot]tf",
+    pattern: "*.{otf,ttf,woff,woff2,ttc#*,otc#*}",
 };
 
+/// A file type for TrueType and OpenType Collections, for checks on the collection as a whole
+pub const TTC: FileType = FileType {
+    pattern: "*.{ttc,otc}",
+};
+
+/// A file type for WOFF and WOFF2 web fonts, for checks on the container itself
+pub const WOFF: FileType = FileType {
+    pattern: "*.{woff,woff2}",
+};
+
+/// A file type for WOFF2 web fonts only
+pub const WOFF2: FileType = FileType { pattern: "*.woff2" };
+
 impl<'a> FileTypeConvert<'a, TestFont<'a>> for FileType<'a> {
     fn from_testable(&self, t: &'a Testable) -> Option<TestFont<'a>> {
         self.applies(t)
-            .then(|| TestFont::new_from_data(&t.filename, &t.contents))
+            .then(|| match t.contents.face_index() {
+                // Read faces in place, rather than extracting a copy of each
+                Some(index) => TestFont::new_from_collection(&t.filename, &t.contents, index),
+                None => TestFont::new_from_data(&t.filename, t.contents.sfnt()),
+            })
             .transpose()
             .unwrap_or(None)
     }
@@ -69,15 +100,43 @@ impl<'a> FileTypeConvert<'a, TestFont<'a>> for FileType<'a> {
 
 impl TestFont<'_> {
     /// Create a new TestFont from a file path and binary data
+    ///
+    /// WOFF and WOFF2 data is decompressed into an sfnt font. When converting a
+    /// [Testable], prefer [Contents::sfnt](crate::Contents::sfnt), which only
+    /// decompresses a web font once.
     pub fn new_from_data<'a>(
         filename: &Path,
         font_data: &'a [u8],
     ) -> Result<TestFont<'a>, Box<dyn Error>> {
-        let font = FontRef::new(font_data)?;
+        let font_data = if WebFont::is_webfont(font_data) {
+            Cow::Owned(WebFont::new(font_data)?.to_sfnt()?)
+        } else {
+            Cow::Borrowed(font_data)
+        };
+        Self::new_from_parts(filename, font_data, 0)
+    }
+
+    /// Create a new TestFont from one face of a font collection
+    pub fn new_from_collection<'a>(
+        filename: &Path,
+        collection_data: &'a [u8],
+        index: u32,
+    ) -> Result<TestFont<'a>, Box<dyn Error>> {
+        Self::new_from_parts(filename, Cow::Borrowed(collection_data), index)
+    }
+
+    /// Create a new TestFont from binary data and the index of the font within it
+    fn new_from_parts<'a>(
+        filename: &Path,
+        font_data: Cow<'a, [u8]>,
+        index: u32,
+    ) -> Result<TestFont<'a>, Box<dyn Error>> {
+        let font = FontRef::from_index(&font_data, index)?;
         let glyph_count = font.maxp()?.num_glyphs().into();
         Ok(TestFont {
             filename: filename.to_path_buf(),
             font_data,
+            index,
             glyph_count,
             _glyphnames: RefCell::new(vec![]),
         })
@@ -86,7 +145,7 @@ impl TestFont<'_> {
     /// A [read-fonts](https://docs.rs/read-fonts/) font object
     pub fn font(&self) -> FontRef {
         #[allow(clippy::expect_used)] // We just tested for it in the initializer
-        FontRef::new(self.font_data).expect("Can't happen")
+        FontRef::from_index(&self.font_data, self.index).expect("Can't happen")
     }
 
     /// Get the font's style name
@@ -300,28 +359,21 @@ impl TestFont<'_> {
     }
 
     /// The set of Unicode codepoints in the font
-    pub fn codepoints(&self, context: Option<&Context>) -> HashSet<u32> {
+    ///
+    /// With a context, the set is computed once and shared between checks.
+    pub fn codepoints(&self, context: Option<&Context>) -> Arc<HashSet<u32>> {
         let get_codepoints = || {
-            Ok(self
-                .font()
+            self.font()
                 .charmap()
                 .mappings()
                 .map(|(u, _gid)| u)
-                .collect::<HashSet<u32>>())
+                .collect::<HashSet<u32>>()
         };
         if let Some(context) = context {
             let key = "codepoints:".to_string() + &self.filename.to_string_lossy();
-            #[allow(clippy::unwrap_used)] // How can it fail?!
-            context
-                .cached_question(
-                    &key,
-                    get_codepoints,
-                    |hashset| serde_json::to_value(hashset).unwrap(),
-                    |value| serde_json::from_value(value.clone()).map_err(|e| e.to_string()),
-                )
-                .unwrap_or_default()
+            context.get_or_compute(&key, get_codepoints)
         } else {
-            get_codepoints().unwrap_or_default()
+            Arc::new(get_codepoints())
         }
     }
 
@@ -357,6 +409,54 @@ impl TestFont<'_> {
         })
     }
 
+    /// Returns the locations of the font's masters in user coordinates.
+    ///
+    /// These are the peaks of the shared tuples in the `gvar` table, mapped back
+    /// through `avar` and denormalized with the `fvar` axis ranges. The default
+    /// master is not included, and fonts without a `gvar` table have no masters.
+    pub fn master_locations(&self) -> Vec<HashMap<String, f32>> {
+        let Ok(tuples) = self.font().gvar().and_then(|gvar| gvar.shared_tuples()) else {
+            return vec![];
+        };
+        let avar_maps = self
+            .font()
+            .avar()
+            .map(|avar| {
+                avar.axis_segment_maps()
+                    .iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let axes = self.font().axes();
+        let mut locations: Vec<HashMap<String, f32>> = vec![];
+        for tuple in tuples.tuples().iter().flatten() {
+            let location = tuple
+                .values()
+                .iter()
+                .zip(axes.iter())
+                .enumerate()
+                .map(|(ix, (peak, axis))| {
+                    let mut coord = peak.get().to_f32();
+                    if let Some(maps) = avar_maps.get(ix) {
+                        coord = unmap_avar(maps.axis_value_maps(), coord);
+                    }
+                    let value = if coord < 0.0 {
+                        axis.default_value() + coord * (axis.default_value() - axis.min_value())
+                    } else {
+                        axis.default_value() + coord * (axis.max_value() - axis.default_value())
+                    };
+                    // Normalized coordinates only have 14 bits of precision
+                    (axis.tag().to_string(), (value * 10.0).round() / 10.0)
+                })
+                .collect();
+            if !locations.contains(&location) {
+                locations.push(location);
+            }
+        }
+        locations
+    }
+
     /// Draw a glyph at the given location using the provided Pen.
     pub fn draw_glyph<I>(
         &self,
@@ -382,6 +482,36 @@ impl TestFont<'_> {
         Ok(())
     }
 
+    /// Draw a glyph at the given location into a [BezGlyph], sharing the result between checks
+    ///
+    /// The outline is cached in the context, so checks which examine the same
+    /// glyphs at the same locations only decode each outline once per font.
+    #[cfg(feature = "kurbo")]
+    pub fn cached_bezglyph<'s>(
+        &self,
+        context: &Context,
+        gid: GlyphId,
+        location: impl IntoIterator<Item = (&'s str, f32)>,
+    ) -> Result<Arc<BezGlyph>, CheckError> {
+        let mut settings = location.into_iter().collect::<Vec<_>>();
+        settings.sort_by(|a, b| a.0.cmp(b.0));
+        let key = format!(
+            "{}:{}:{}",
+            self.filename.display(),
+            gid.to_u32(),
+            settings
+                .iter()
+                .map(|(tag, value)| format!("{}={}", tag, value))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        context.get_or_try_compute(&key, || {
+            let mut pen = BezGlyph::default();
+            self.draw_glyph(gid, &mut pen, settings)?;
+            Ok(pen)
+        })
+    }
+
     /// Returns the font's FeatureRecord and associated Feature tables
     ///
     /// If `gsub_only` is true, only searches in the `GSUB` table.
@@ -433,8 +563,11 @@ impl TestFont<'_> {
     /// An iterator of all glyphs in the font that are CJK
     pub fn cjk_codepoints(&self, context: Option<&Context>) -> impl Iterator<Item = u32> {
         self.codepoints(context)
-            .into_iter()
+            .iter()
+            .copied()
             .filter(|&cp| is_cjk(cp))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 
     /// Is this font a CJK font?
@@ -499,6 +632,11 @@ impl TestFont<'_> {
         ])
     }
 
+    /// Returns the typographic family name of a font, falling back to the legacy family name
+    pub fn typographic_familyname(&self) -> Option<String> {
+        self.get_best_name(&[StringId::TYPOGRAPHIC_FAMILY_NAME, StringId::FAMILY_NAME])
+    }
+
     /// Returns the best English subfamily name for a font
     pub fn best_subfamilyname(&self) -> Option<String> {
         self.get_best_name(&[
@@ -509,6 +647,30 @@ impl TestFont<'_> {
     }
 }
 
+/// Map a normalized coordinate back through an `avar` segment map
+///
+/// The segment maps are monotonic, so we can invert them by interpolating
+/// between their `to` coordinates instead of their `from` coordinates.
+fn unmap_avar(maps: &[AxisValueMap], coord: f32) -> f32 {
+    for pair in maps.windows(2) {
+        let [start, end] = pair else {
+            continue;
+        };
+        let (from_start, to_start) = (
+            start.from_coordinate().to_f32(),
+            start.to_coordinate().to_f32(),
+        );
+        let (from_end, to_end) = (end.from_coordinate().to_f32(), end.to_coordinate().to_f32());
+        if to_start <= coord && coord <= to_end {
+            if to_end == to_start {
+                return from_start;
+            }
+            return from_start + (coord - to_start) * (from_end - from_start) / (to_end - to_start);
+        }
+    }
+    coord
+}
+
 /// Is a codepoint a CJK character?
 fn is_cjk(cp: u32) -> bool {
     crate::constants::CJK_UNICODE_RANGES
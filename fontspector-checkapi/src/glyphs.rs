@@ -0,0 +1,96 @@
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use glyphs_reader::Font;
+
+use crate::{Context, FileType, FileTypeConvert, Testable};
+
+/// Load a Glyphs source from disk, or from the context's cache if the testable has loaded it
+fn load_glyphs(path: &Path, context: Option<&Context>) -> Result<Arc<Font>, String> {
+    let load = || Font::load(path).map_err(|e| format!("{}: {:?}", path.display(), e));
+    match context {
+        Some(context) => context.get_or_try_compute(&path.display().to_string(), load),
+        None => load().map(Arc::new),
+    }
+}
+
+/// A file type for Glyphs font sources, either `.glyphs` files or `.glyphspackage` directories
+///
+/// This is a separate type from [FileType] so that converting a [Testable]
+/// with it gives you a [GlyphsSource]; register its `filetype` with the registry.
+pub struct GlyphsType {
+    /// The file type to register
+    pub filetype: FileType<'static>,
+}
+
+impl GlyphsType {
+    /// Convert a testable to a [GlyphsSource], sharing the loaded source with
+    /// the testable's other checks through the context
+    ///
+    /// Returns `Ok(None)` if the testable is not a Glyphs source, and an error
+    /// if it is one but could not be loaded.
+    pub fn from_testable_cached(
+        &self,
+        t: &Testable,
+        context: &Context,
+    ) -> Result<Option<GlyphsSource>, String> {
+        self.filetype
+            .applies(t)
+            .then(|| GlyphsSource::load(&t.filename, Some(context)))
+            .transpose()
+    }
+}
+
+/// A file type for Glyphs font sources
+pub const GLYPHS: GlyphsType = GlyphsType {
+    filetype: FileType {
+        pattern: "*.{glyphs,glyphspackage}",
+    },
+};
+
+/// A Glyphs font source to be tested
+///
+/// This dereferences to a [glyphs_reader::Font]. Glyphs 2 sources are
+/// converted to the Glyphs 3 data model when they are loaded.
+pub struct GlyphsSource {
+    /// The path to the source file or package
+    pub filename: PathBuf,
+    /// The loaded font
+    font: Arc<Font>,
+}
+
+impl GlyphsSource {
+    /// Load a Glyphs source from a path
+    pub fn new(filename: &Path) -> Result<Self, String> {
+        Self::load(filename, None)
+    }
+
+    /// Load a Glyphs source from a path, using the context's cache if one is given
+    fn load(filename: &Path, context: Option<&Context>) -> Result<Self, String> {
+        Ok(GlyphsSource {
+            filename: filename.to_path_buf(),
+            font: load_glyphs(filename, context)?,
+        })
+    }
+}
+
+impl Deref for GlyphsSource {
+    type Target = Font;
+
+    fn deref(&self) -> &Font {
+        &self.font
+    }
+}
+
+impl<'a> FileTypeConvert<'a, GlyphsSource> for GlyphsType {
+    fn from_testable(&self, t: &'a Testable) -> Option<GlyphsSource> {
+        self.filetype
+            .applies(t)
+            .then(|| GlyphsSource::new(&t.filename))
+            .transpose()
+            .unwrap_or(None)
+    }
+}
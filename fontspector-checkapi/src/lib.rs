@@ -13,20 +13,32 @@
 //!
 //! Check authors should see also [fontspector-checkhelper](../fontspector-checkhelper)
 
+/// Walking the charstrings and DICTs of CFF and CFF2 tables
+mod cff;
 /// Routines and data structures for defining a check
 mod check;
 /// Data structures representing the result of a check
 mod checkresult;
 /// Routines for testing checks
 pub mod codetesting;
+/// Splitting TrueType and OpenType Collections into their faces
+mod collection;
 /// Font-related constants which may be useful to check implementors
 pub mod constants;
+/// The binary contents of a testable, loaded lazily on native targets
+mod contents;
 /// Data structures for managing the context in which a check is run
 mod context;
+/// Represents a designspace document, together with its source UFOs
+#[cfg(feature = "norad")]
+mod designspace;
 /// Managing a registry of file types
 mod filetype;
 /// Represents a TrueType font, together with useful routines for dealing with them
 mod font;
+/// Represents a Glyphs font source
+#[cfg(feature = "glyphs")]
+mod glyphs;
 /// Routines to make dealing with GSUB tables more tractable
 mod gsub;
 /// [OutlinePen](https://docs.rs/skrifa/latest/skrifa/outline/trait.OutlinePen.html) implementations useful for check implementors
@@ -35,22 +47,46 @@ pub mod pens;
 mod profile;
 /// The registry of checks and profiles
 mod registry;
+/// Assembling sfnt font binaries from their tables
+mod sfnt;
 /// Data structures representing the most basic elements of a check's result
 mod status;
 /// Wraps a file or "thing" to be tested
 mod testable;
+/// Represents a UFO font source
+#[cfg(feature = "norad")]
+mod ufo;
 /// Common utility functions for check implementors
 mod utils;
-pub use check::{return_result, Check, CheckFlags, CheckId, CheckImplementation, HotfixFunction};
+/// Decoding WOFF and WOFF2 web font containers
+mod webfont;
+pub use cff::CharstringInfo;
+pub use check::{
+    return_result, Check, CheckFlags, CheckId, CheckImplementation, HotfixFunction,
+    SourceFixFunction,
+};
 pub use checkresult::{CheckResult, FixResult};
-pub use context::Context;
+pub use collection::{collection_face, is_font_collection};
+pub use contents::Contents;
+pub use context::{Context, TypedCache};
+#[cfg(feature = "norad")]
+pub use designspace::{Designspace, DesignspaceType, Master, DESIGNSPACE};
 pub use filetype::{FileType, FileTypeConvert};
-pub use font::{TestFont, DEFAULT_LOCATION, TTF};
+pub use font::{TestFont, DEFAULT_LOCATION, TTC, TTF, WOFF, WOFF2};
+#[cfg(feature = "glyphs")]
+pub use glyphs::{GlyphsSource, GlyphsType, GLYPHS};
+#[cfg(feature = "glyphs")]
+pub use glyphs_reader;
 pub use gsub::{GetSubstitutionMap, SubstitutionMap};
-pub use profile::{Override, Profile, ProfileBuilder};
+#[cfg(feature = "norad")]
+pub use norad;
+pub use profile::{CheckSelection, Override, Profile, ProfileBuilder};
 pub use registry::Registry;
-pub use status::{CheckError, CheckFnResult, Status, StatusCode, StatusList};
+pub use status::{CheckError, CheckFnResult, GlyphImage, Location, Status, StatusCode, StatusList};
 pub use testable::{Testable, TestableCollection, TestableType};
+#[cfg(feature = "norad")]
+pub use ufo::{Ufo, UfoType, UFO};
+pub use webfont::{WebFont, WebFontError, WebFontFormat, WebFontTable};
 
 /// The prelude module contains the most common items you will need when writing checks
 pub mod prelude {
@@ -91,8 +127,8 @@ pub mod prelude {
     pub type FixFnResult = Result<bool, String>;
     pub use crate::{
         return_result, utils::*, Check, CheckError, CheckFlags, CheckFnResult, CheckImplementation,
-        Context, FileType, Profile, ProfileBuilder, Registry, Status, StatusList, Testable,
-        TestableCollection, TestableType, TTF,
+        Context, FileType, Location, Profile, ProfileBuilder, Registry, Status, StatusList,
+        Testable, TestableCollection, TestableType, TTF,
     };
 }
 
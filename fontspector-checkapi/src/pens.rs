@@ -212,8 +212,142 @@ impl OutlinePen for AreaPen {
     }
 }
 
+#[derive(Default)]
+/// A pen which draws an outline as an SVG image, with problem areas highlighted
+///
+/// Draw the glyph into the pen, mark the points or segments which a check
+/// found a problem with, and then call [`SvgPen::svg`] to get the image.
+pub struct SvgPen {
+    /// SVG path data for the outline
+    path: String,
+    /// SVG path data for the highlighted segments
+    segments: String,
+    /// The highlighted points
+    points: Vec<(f32, f32)>,
+    /// The bounds of everything drawn so far, as (min x, min y, max x, max y)
+    bounds: Option<(f32, f32, f32, f32)>,
+}
+
+/// Margin around the drawing, as a proportion of its largest dimension
+const SVG_MARGIN: f32 = 0.1;
+
+impl SvgPen {
+    /// Create a new SvgPen
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Extend the bounds of the drawing to include a point
+    fn update(&mut self, x: f32, y: f32) {
+        self.bounds = Some(match self.bounds {
+            Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            None => (x, y, x, y),
+        });
+    }
+
+    /// Mark a point, such as a misplaced on-curve point
+    pub fn highlight_point(&mut self, x: f32, y: f32) {
+        self.update(x, y);
+        self.points.push((x, y));
+    }
+
+    /// Mark a segment, given as its start point, any off-curve points and its end point
+    ///
+    /// Two points make a line, three a quadratic curve and four a cubic curve;
+    /// anything else is ignored.
+    pub fn highlight_segment(&mut self, points: &[(f32, f32)]) {
+        let data = match points {
+            [(x0, y0), (x1, y1)] => format!("M{} {}L{} {}", x0, y0, x1, y1),
+            [(x0, y0), (x1, y1), (x2, y2)] => {
+                format!("M{} {}Q{} {} {} {}", x0, y0, x1, y1, x2, y2)
+            }
+            [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] => {
+                format!("M{} {}C{} {} {} {} {} {}", x0, y0, x1, y1, x2, y2, x3, y3)
+            }
+            _ => return,
+        };
+        for &(x, y) in points {
+            self.update(x, y);
+        }
+        self.segments.push_str(&data);
+    }
+
+    /// The drawing as a standalone SVG document
+    ///
+    /// The outline is drawn in grey, with highlighted segments stroked and
+    /// highlighted points circled in red. The image is sized to fit what was
+    /// drawn, so it can be shown inline at any size.
+    pub fn svg(&self) -> String {
+        let (x0, y0, x1, y1) = self.bounds.unwrap_or((0.0, 0.0, 0.0, 0.0));
+        let size = (x1 - x0).max(y1 - y0).max(1.0);
+        let margin = size * SVG_MARGIN;
+        let stroke = size / 100.0;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"200\" height=\"200\">",
+            x0 - margin,
+            -y1 - margin,
+            x1 - x0 + 2.0 * margin,
+            y1 - y0 + 2.0 * margin
+        );
+        // Font coordinates are y-up, SVG coordinates are y-down
+        svg.push_str("<g transform=\"scale(1,-1)\">");
+        svg.push_str(&format!(
+            "<path d=\"{}\" fill=\"#ccc\" stroke=\"#000\" stroke-width=\"{}\"/>",
+            self.path, stroke
+        ));
+        if !self.segments.is_empty() {
+            svg.push_str(&format!(
+                "<path d=\"{}\" fill=\"none\" stroke=\"#e00\" stroke-width=\"{}\"/>",
+                self.segments,
+                stroke * 4.0
+            ));
+        }
+        for (x, y) in self.points.iter() {
+            svg.push_str(&format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"none\" stroke=\"#e00\" stroke-width=\"{}\"/>",
+                x,
+                y,
+                stroke * 5.0,
+                stroke * 2.0
+            ));
+        }
+        svg.push_str("</g></svg>");
+        svg
+    }
+}
+
+impl OutlinePen for SvgPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.update(x, y);
+        self.path.push_str(&format!("M{} {}", x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.update(x, y);
+        self.path.push_str(&format!("L{} {}", x, y));
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.update(cx0, cy0);
+        self.update(x, y);
+        self.path.push_str(&format!("Q{} {} {} {}", cx0, cy0, x, y));
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        self.update(cx0, cy0);
+        self.update(cx1, cy1);
+        self.update(x, y);
+        self.path
+            .push_str(&format!("C{} {} {} {} {} {}", cx0, cy0, cx1, cy1, x, y));
+    }
+
+    fn close(&mut self) {
+        self.path.push('Z');
+    }
+}
+
 #[cfg(feature = "kurbo")]
-use kurbo::BezPath;
+use kurbo::{BezPath, PathEl};
 
 #[derive(Default, Debug)]
 #[cfg(feature = "kurbo")]
@@ -245,6 +379,26 @@ impl BezGlyph {
     pub fn iter(&self) -> impl Iterator<Item = &BezPath> {
         self.0.iter()
     }
+
+    /// Draw the glyph's paths again, into another pen
+    pub fn replay(&self, pen: &mut impl OutlinePen) {
+        for element in self.0.iter().flat_map(|path| path.elements()) {
+            match element {
+                PathEl::MoveTo(p) => pen.move_to(p.x as f32, p.y as f32),
+                PathEl::LineTo(p) => pen.line_to(p.x as f32, p.y as f32),
+                PathEl::QuadTo(c, p) => pen.quad_to(c.x as f32, c.y as f32, p.x as f32, p.y as f32),
+                PathEl::CurveTo(c0, c1, p) => pen.curve_to(
+                    c0.x as f32,
+                    c0.y as f32,
+                    c1.x as f32,
+                    c1.y as f32,
+                    p.x as f32,
+                    p.y as f32,
+                ),
+                PathEl::ClosePath => pen.close(),
+            }
+        }
+    }
 }
 
 #[cfg(feature = "kurbo")]
@@ -286,4 +440,21 @@ mod tests {
         pen.close();
         assert_eq!(-104561.0, pen.area().round());
     }
+
+    #[test]
+    fn test_svg() {
+        let mut pen = SvgPen::new();
+        pen.move_to(0.0, 0.0);
+        pen.line_to(100.0, 0.0);
+        pen.quad_to(100.0, 50.0, 50.0, 100.0);
+        pen.close();
+        pen.highlight_point(1.0, 99.0);
+        pen.highlight_segment(&[(0.0, 0.0), (100.0, 0.0)]);
+        let svg = pen.svg();
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-10 -110 120 120\""));
+        assert!(svg.contains("<path d=\"M0 0L100 0Q100 50 50 100Z\""));
+        assert!(svg.contains("<path d=\"M0 0L100 0\" fill=\"none\""));
+        assert!(svg.contains("<circle cx=\"1\" cy=\"99\""));
+    }
 }
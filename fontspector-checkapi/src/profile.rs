@@ -86,6 +86,7 @@ impl Profile {
     /// registry, resolving any included profiles and excluded checks, and that
     /// any filetypes used in checks are known to the registry.
     pub fn validate(&mut self, registry: &Registry) -> Result<(), String> {
+        self.resolve_aliases(registry);
         // Resolve "include_profiles" and "exclude_checks" here
         for included_profile_str in self.include_profiles.iter() {
             if let Some(profile) = registry.profiles.get(included_profile_str) {
@@ -140,15 +141,35 @@ impl Profile {
         Ok(())
     }
 
+    /// Replace any legacy check IDs used in the profile with the checks' current IDs
+    fn resolve_aliases(&mut self, registry: &Registry) {
+        let canonical = |check_id: &String| registry.canonical_check_id(check_id).to_string();
+        for check_ids in self.sections.values_mut() {
+            for check_id in check_ids.iter_mut() {
+                *check_id = canonical(check_id);
+            }
+        }
+        for check_id in self.exclude_checks.iter_mut() {
+            *check_id = canonical(check_id);
+        }
+        self.overrides = std::mem::take(&mut self.overrides)
+            .into_iter()
+            .map(|(check_id, overrides)| (canonical(&check_id), overrides))
+            .collect();
+        self.configuration_defaults = std::mem::take(&mut self.configuration_defaults)
+            .into_iter()
+            .map(|(check_id, defaults)| (canonical(&check_id), defaults))
+            .collect();
+    }
+
     /// Determine a check order based on the profile
     ///
-    /// This applies any user-provided command line configuration (include/exclude checks)
+    /// This applies any user-provided command line configuration (the [CheckSelection])
     /// and works out which checks apply to the set of [TestableType]s provided.
     /// It returns everything needed to run each check, in order.
     pub fn check_order<'t, 'r>(
         &self,
-        include_checks: &Option<Vec<String>>,
-        exclude_checks: &Option<Vec<String>>,
+        selection: &CheckSelection,
         registry: &'r Registry<'r>,
         general_context: Context,
         configuration: Map<String, serde_json::Value>,
@@ -163,11 +184,10 @@ impl Profile {
         let mut sections_and_checks = vec![];
         for (section_name, check_ids) in self.sections.iter() {
             for check_id in check_ids.iter() {
-                if !included_excluded(check_id, include_checks, exclude_checks) {
-                    continue;
-                }
-                if registry.checks.contains_key(check_id) {
-                    sections_and_checks.push((section_name, check_id))
+                if let Some(check) = registry.get_check(check_id) {
+                    if selection.includes(check, section_name) {
+                        sections_and_checks.push((section_name, check_id))
+                    }
                 } else {
                     log::warn!("Unknown check: {}", check_id);
                 }
@@ -175,9 +195,10 @@ impl Profile {
         }
 
         for (testable, context) in testable_and_cache {
+            let mut check_count = 0;
             for (section_name, check_id) in sections_and_checks.iter() {
                 #[allow(clippy::unwrap_used)] // We checked for this above
-                let check = registry.checks.get(check_id.as_str()).unwrap();
+                let check = registry.get_check(check_id).unwrap();
                 if check.applies(testable, registry) {
                     let specialized_context = context.specialize(check, &configuration, self);
                     order.push((
@@ -186,8 +207,12 @@ impl Profile {
                         check,
                         specialized_context,
                     ));
+                    check_count += 1;
                 }
             }
+            // Runners call check_finished after each check, freeing the
+            // testable's cached values once they are no longer needed.
+            context.typed_cache.expect_checks(check_count);
         }
         order
     }
@@ -201,25 +226,64 @@ impl Profile {
     }
 }
 
-/// Apply inclusions and exclusions to a list of checks
+#[derive(Debug, Clone, Default)]
+/// A user's selection of which checks to run
 ///
-/// Returns true if the check should be included, false if it should be excluded.
-fn included_excluded(
-    checkname: &str,
-    include_checks: &Option<Vec<String>>,
-    exclude_checks: &Option<Vec<String>>,
-) -> bool {
-    if let Some(checkids) = &include_checks {
-        if !checkids.iter().any(|id| checkname.contains(id)) {
+/// Checks can be chosen by ID, by tag, or by the name of the profile section
+/// they appear in. A check is run if it matches at least one value of each
+/// kind of inclusion given, and none of the exclusions.
+pub struct CheckSelection {
+    /// Check IDs (or parts of their names) to run
+    pub include_checks: Option<Vec<String>>,
+    /// Check IDs (or parts of their names) not to run
+    pub exclude_checks: Option<Vec<String>>,
+    /// Only run checks carrying one of these tags
+    pub include_tags: Option<Vec<String>>,
+    /// Don't run checks carrying any of these tags
+    pub exclude_tags: Option<Vec<String>>,
+    /// Only run checks in these profile sections
+    pub include_sections: Option<Vec<String>>,
+    /// Don't run checks in these profile sections
+    pub exclude_sections: Option<Vec<String>>,
+    /// Don't run checks which are flagged as experimental
+    pub exclude_experimental: bool,
+}
+
+impl CheckSelection {
+    /// Apply inclusions and exclusions to a check in a given profile section
+    ///
+    /// Returns true if the check should be included, false if it should be excluded.
+    pub fn includes(&self, check: &Check, section: &str) -> bool {
+        if self.exclude_experimental && check.flags.experimental {
             return false;
         }
-    }
-    if let Some(exclude_checkids) = &exclude_checks {
-        if exclude_checkids.iter().any(|id| checkname.contains(id)) {
-            return false;
+        let matches_id = |id: &String| {
+            check.id.contains(id.as_str()) || check.aliases.iter().any(|a| a.contains(id.as_str()))
+        };
+        let matches_tag = |tag: &String| check.has_tag(tag);
+        let matches_section = |name: &String| name.eq_ignore_ascii_case(section);
+        if let Some(checkids) = &self.include_checks {
+            if !checkids.iter().any(matches_id) {
+                return false;
+            }
+        }
+        if let Some(tags) = &self.include_tags {
+            if !tags.iter().any(matches_tag) {
+                return false;
+            }
+        }
+        if let Some(sections) = &self.include_sections {
+            if !sections.iter().any(matches_section) {
+                return false;
+            }
         }
+        let excluded = |list: &Option<Vec<String>>, matches: &dyn Fn(&String) -> bool| {
+            list.as_ref().is_some_and(|l| l.iter().any(matches))
+        };
+        !(excluded(&self.exclude_checks, &matches_id)
+            || excluded(&self.exclude_tags, &matches_tag)
+            || excluded(&self.exclude_sections, &matches_section))
     }
-    true
 }
 
 /// A builder for creating a profile
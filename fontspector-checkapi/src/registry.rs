@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{Check, CheckId, FileType, Profile, Testable, TTF};
+use crate::{Check, CheckId, FileType, Profile, Testable, TTC, TTF, WOFF, WOFF2};
 
 #[derive(Default)]
 /// The Registry object
@@ -13,6 +13,8 @@ pub struct Registry<'a> {
     pub(crate) profiles: HashMap<String, Profile>,
     /// All known filetypes, by name
     pub(crate) filetypes: HashMap<String, FileType<'a>>,
+    /// Legacy check IDs, mapped to the current ID of the check
+    pub(crate) aliases: HashMap<String, CheckId>,
 }
 
 impl<'a> Registry<'a> {
@@ -20,6 +22,9 @@ impl<'a> Registry<'a> {
     pub fn new() -> Registry<'static> {
         let mut reg = Registry::default();
         reg.register_filetype("TTF", TTF);
+        reg.register_filetype("TTC", TTC);
+        reg.register_filetype("WOFF", WOFF);
+        reg.register_filetype("WOFF2", WOFF2);
         reg
     }
 
@@ -44,6 +49,11 @@ impl<'a> Registry<'a> {
         Ok(())
     }
 
+    /// Get an iterator over all profiles, with their names
+    pub fn iter_profiles(&self) -> impl Iterator<Item = (&String, &Profile)> {
+        self.profiles.iter()
+    }
+
     /// Get a profile by name
     pub fn get_profile(&self, name: &str) -> Option<&Profile> {
         self.profiles.get(name)
@@ -56,9 +66,27 @@ impl<'a> Registry<'a> {
 
     /// Register a new check
     pub fn register_check(&mut self, check: Check<'a>) {
+        for alias in check.aliases {
+            self.aliases.insert(alias.to_string(), check.id.to_string());
+        }
         self.checks.insert(check.id.to_string(), check);
     }
 
+    /// Resolve a check ID which may be a legacy alias into the check's current ID
+    ///
+    /// IDs which are not known aliases are returned unchanged.
+    pub fn canonical_check_id<'b>(&'b self, check_id: &'b str) -> &'b str {
+        self.aliases
+            .get(check_id)
+            .map(|id| id.as_str())
+            .unwrap_or(check_id)
+    }
+
+    /// Get a check by its ID or by one of its legacy aliases
+    pub fn get_check(&self, check_id: &str) -> Option<&Check<'a>> {
+        self.checks.get(self.canonical_check_id(check_id))
+    }
+
     /// Register a simple profile with a single section from a list of checks
     pub fn register_simple_profile(
         &mut self,
@@ -78,8 +106,7 @@ impl<'a> Registry<'a> {
 
     /// Returns true if a check has an "experimental" flag
     pub fn is_experimental(&self, check_id: &str) -> bool {
-        self.checks
-            .get(check_id)
+        self.get_check(check_id)
             .is_some_and(|c| c.flags.experimental)
     }
 
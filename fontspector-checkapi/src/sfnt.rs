@@ -0,0 +1,68 @@
+use read_fonts::types::Tag;
+
+/// The OpenType checksum of some data
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Assemble tables (already sorted by tag) into an sfnt binary
+pub(crate) fn build_sfnt(flavor: u32, tables: &[(Tag, Vec<u8>)]) -> Vec<u8> {
+    let num_tables = tables.len() as u16;
+    let entry_selector = if num_tables == 0 {
+        0
+    } else {
+        15 - num_tables.leading_zeros() as u16
+    };
+    let search_range = (1u16 << entry_selector) * 16;
+    let mut out = vec![];
+    out.extend(flavor.to_be_bytes());
+    out.extend(num_tables.to_be_bytes());
+    out.extend(search_range.to_be_bytes());
+    out.extend(entry_selector.to_be_bytes());
+    out.extend((num_tables * 16 - search_range).to_be_bytes());
+
+    let mut offset = 12 + 16 * tables.len();
+    let mut head_offset = None;
+    for (tag, data) in tables {
+        let mut sum = checksum(data);
+        if *tag == Tag::new(b"head") && data.len() >= 12 {
+            // The head checksum is computed with checkSumAdjustment set to zero
+            sum = sum.wrapping_sub(u32::from_be_bytes([data[8], data[9], data[10], data[11]]));
+            head_offset = Some(offset);
+        }
+        out.extend(tag.to_be_bytes());
+        out.extend(sum.to_be_bytes());
+        out.extend((offset as u32).to_be_bytes());
+        out.extend((data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in tables {
+        out.extend(data);
+        out.resize(out.len().next_multiple_of(4), 0);
+    }
+    if let Some(head_offset) = head_offset {
+        out[head_offset + 8..head_offset + 12].copy_from_slice(&[0, 0, 0, 0]);
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&out));
+        out[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_sfnt_checksums() {
+        let tables = vec![(Tag::new(b"abcd"), vec![1, 2, 3])];
+        let sfnt = build_sfnt(0x00010000, &tables);
+        assert_eq!(sfnt.len(), 12 + 16 + 4);
+        assert_eq!(&sfnt[12..16], b"abcd");
+        assert_eq!(&sfnt[16..20], &0x01020300u32.to_be_bytes());
+        assert_eq!(&sfnt[24..28], &3u32.to_be_bytes());
+    }
+}
@@ -1,6 +1,13 @@
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
+use read_fonts::{
+    tables::name::NameRecord,
+    types::{GlyphId, Tag},
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::utils::bullet_list;
 #[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Copy, Clone, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[serde(rename_all = "UPPERCASE")]
@@ -79,7 +86,141 @@ impl std::fmt::Display for StatusCode {
         }
     }
 }
-#[derive(Debug, Clone, Serialize)]
+/// Where in a font a status applies
+///
+/// All fields are optional; a check fills in whatever it knows. For example,
+/// a problem with a glyph's outline at a particular master has a glyph and a
+/// design-space location, while a problem with a name table entry has a table
+/// tag and the name record's IDs. This allows tools to take the user straight
+/// to the problem, without having to parse the status message.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// The name of the glyph
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub glyph_name: Option<String>,
+    /// The ID of the glyph
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub glyph_id: Option<u32>,
+    /// The tag of the table
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub table: Option<String>,
+    /// The name ID of a name table record
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name_id: Option<u16>,
+    /// The platform ID of a name table record
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub platform_id: Option<u16>,
+    /// The encoding ID of a name table record
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub encoding_id: Option<u16>,
+    /// The language ID of a name table record
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub language_id: Option<u16>,
+    /// The tag of a layout feature
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub feature: Option<String>,
+    /// The index of a lookup in the layout table's lookup list
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lookup: Option<u16>,
+    /// A location in the design space, as user coordinates by axis tag
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub design_location: Option<BTreeMap<String, f32>>,
+    /// The Unicode codepoint
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub codepoint: Option<u32>,
+}
+
+impl Location {
+    /// A glyph, by ID and name
+    pub fn glyph(glyph: impl Into<GlyphId>, name: impl Into<String>) -> Self {
+        Self {
+            glyph_id: Some(glyph.into().to_u32()),
+            glyph_name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A table, by tag
+    pub fn table(tag: Tag) -> Self {
+        Self {
+            table: Some(tag.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A record in the name table
+    pub fn name_record(record: &NameRecord) -> Self {
+        Self {
+            table: Some("name".to_string()),
+            name_id: Some(record.name_id().to_u16()),
+            platform_id: Some(record.platform_id()),
+            encoding_id: Some(record.encoding_id()),
+            language_id: Some(record.language_id()),
+            ..Default::default()
+        }
+    }
+
+    /// A layout feature in the given table
+    pub fn feature(table: Tag, feature: Tag) -> Self {
+        Self {
+            feature: Some(feature.to_string()),
+            ..Self::table(table)
+        }
+    }
+
+    /// A lookup in the given table, by index
+    pub fn lookup(table: Tag, lookup: u16) -> Self {
+        Self {
+            lookup: Some(lookup),
+            ..Self::table(table)
+        }
+    }
+
+    /// Add the table this location is in
+    pub fn in_table(self, tag: Tag) -> Self {
+        Self {
+            table: Some(tag.to_string()),
+            ..self
+        }
+    }
+
+    /// Add a Unicode codepoint to this location
+    pub fn with_codepoint(self, codepoint: u32) -> Self {
+        Self {
+            codepoint: Some(codepoint),
+            ..self
+        }
+    }
+
+    /// Add a design-space location, in user coordinates
+    ///
+    /// An empty set of coordinates, as used for static fonts, is ignored.
+    pub fn at<'a>(self, coords: impl IntoIterator<Item = (&'a str, f32)>) -> Self {
+        let coords: BTreeMap<String, f32> = coords
+            .into_iter()
+            .map(|(tag, value)| (tag.to_string(), value))
+            .collect();
+        Self {
+            design_location: (!coords.is_empty()).then_some(coords),
+            ..self
+        }
+    }
+}
+
+/// A picture of a glyph, illustrating a problem with it
+///
+/// Checks draw these with [`crate::pens::SvgPen`], highlighting the points or
+/// segments they found a problem with. Reporters which can show images do so
+/// alongside the status message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlyphImage {
+    /// The glyph which is pictured, with its design-space location if relevant
+    pub glyph: Location,
+    /// The picture, as a standalone SVG document
+    pub svg: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// A status message from a check
 ///
 /// This is a subresult, in the sense that a check may return multiple failures
@@ -94,18 +235,43 @@ pub struct Status {
     /// A code to identify the status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    /// Where in the font the status applies, if the check knows
+    ///
+    /// A status which reports several problems may have a location for each.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub locations: Vec<Location>,
+    /// The items the message refers to, such as glyph names or codepoints
+    ///
+    /// Reporters show these as a list after the message, deciding for
+    /// themselves how much of a long list to show.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub items: Vec<Value>,
+    /// Pictures of the glyphs the status refers to, with the problems highlighted
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub images: Vec<GlyphImage>,
 }
 
+/// Formats the status as Markdown
+///
+/// Long lists of items are abbreviated, unless the alternate flag (`{:#}`) is used.
 impl std::fmt::Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let full_lists = f.alternate();
         write!(f, "**{:}**: ", self.severity)?;
         if let Some(code) = self.code.as_ref() {
             write!(f, "[{}]: ", code)?;
         }
-        if let Some(message) = self.message.as_ref() {
-            write!(f, "{:}", message)?;
-        }
-        Ok(())
+        write!(f, "{:}", self.message_with_items(full_lists))
+    }
+}
+
+/// The text of an item in a status's list of items
+///
+/// Strings are shown as they are, rather than as quoted JSON.
+fn item_text(item: &Value) -> String {
+    match item {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
     }
 }
 
@@ -135,53 +301,95 @@ impl Status {
         Box::new(vec![Status::skip(code, message)].into_iter())
     }
 
-    /// Create a status with a pass severity
-    pub fn pass() -> Self {
+    /// Create a status with no locations, items or images
+    fn new(severity: StatusCode, code: Option<&str>, message: Option<&str>) -> Self {
         Self {
-            message: None,
-            code: None,
-            severity: StatusCode::Pass,
+            message: message.map(|x| x.to_string()),
+            code: code.map(|x| x.to_string()),
+            locations: vec![],
+            items: vec![],
+            images: vec![],
+            severity,
         }
     }
+
+    /// Create a status with a pass severity
+    pub fn pass() -> Self {
+        Self::new(StatusCode::Pass, None, None)
+    }
     /// Create a status with a fail severity
     pub fn fail(code: &str, message: &str) -> Self {
-        Self {
-            message: Some(message.to_string()),
-            code: Some(code.to_string()),
-            severity: StatusCode::Fail,
-        }
+        Self::new(StatusCode::Fail, Some(code), Some(message))
     }
     /// Create a status with a warning severity
     pub fn warn(code: &str, message: &str) -> Self {
-        Self {
-            message: Some(message.to_string()),
-            code: Some(code.to_string()),
-            severity: StatusCode::Warn,
-        }
+        Self::new(StatusCode::Warn, Some(code), Some(message))
     }
-    /// Create a status with an info severity
+    /// Create a status with a skip severity
     pub fn skip(code: &str, message: &str) -> Self {
-        Self {
-            message: Some(message.to_string()),
-            code: Some(code.to_string()),
-            severity: StatusCode::Skip,
-        }
+        Self::new(StatusCode::Skip, Some(code), Some(message))
     }
     /// Create a status with an info severity
     pub fn info(code: &str, message: &str) -> Self {
-        Self {
-            message: Some(message.to_string()),
-            code: Some(code.to_string()),
-            severity: StatusCode::Info,
+        Self::new(StatusCode::Info, Some(code), Some(message))
+    }
+    /// Add a location to the status
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Add several locations to the status
+    pub fn with_locations(mut self, locations: impl IntoIterator<Item = Location>) -> Self {
+        self.locations.extend(locations);
+        self
+    }
+
+    /// Add a list of items, such as glyph names or codepoints, to the status
+    ///
+    /// Rather than formatting a list into the message, checks should put the
+    /// message's subjects here, so that reporters can choose how to show them
+    /// and machine consumers can read them without parsing the message.
+    pub fn with_items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        self.items.extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a picture of a glyph to the status
+    pub fn with_image(mut self, glyph: Location, svg: String) -> Self {
+        self.images.push(GlyphImage { glyph, svg });
+        self
+    }
+
+    /// Add several pictures of glyphs to the status
+    pub fn with_images(mut self, images: impl IntoIterator<Item = GlyphImage>) -> Self {
+        self.images.extend(images);
+        self
+    }
+
+    /// The message, followed by the items as a Markdown bullet list
+    ///
+    /// Long lists are abbreviated unless `full_lists` is set.
+    pub fn message_with_items(&self, full_lists: bool) -> String {
+        let message = self.message.as_deref().unwrap_or_default();
+        if self.items.is_empty() {
+            return message.to_string();
+        }
+        let list = bullet_list(self.items.iter().map(item_text), full_lists);
+        if message.is_empty() {
+            list
+        } else {
+            format!("{}\n\n{}", message, list)
         }
     }
+
     /// Create a status with an error severity
     pub fn error(code: Option<&str>, message: &str) -> Self {
-        Self {
-            message: Some(message.to_string()),
-            code: code.map(|x| x.to_string()),
-            severity: StatusCode::Error,
-        }
+        Self::new(StatusCode::Error, code, Some(message))
     }
 }
 
@@ -227,3 +435,57 @@ impl CheckError {
 pub type StatusList = Box<dyn Iterator<Item = Status>>;
 /// The expected return type of a check implementation function
 pub type CheckFnResult = Result<StatusList, CheckError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_with_items() {
+        let status = Status::warn("some-glyphs", "The following glyphs are bad:")
+            .with_items((0..12).map(|i| format!("glyph{}", i)))
+            .with_items([42]);
+        let abbreviated = status.message_with_items(false);
+        assert!(abbreviated.starts_with("The following glyphs are bad:\n\n* glyph0\n"));
+        assert!(abbreviated.ends_with("* glyph8\n... and 4 others"));
+        let full = status.message_with_items(true);
+        assert!(full.ends_with("* glyph11\n* 42"));
+        assert_eq!(
+            format!("{:#}", status),
+            format!("**WARN**: [some-glyphs]: {}", full)
+        );
+        assert_eq!(
+            Status::pass().with_items(["a"]).message_with_items(false),
+            "* a"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_images() {
+        let glyph = Location {
+            glyph_name: Some("A".to_string()),
+            ..Default::default()
+        };
+        let status =
+            Status::warn("bad-glyph", "A is bad").with_image(glyph.clone(), "<svg/>".to_string());
+        // Pictures are left to reporters which can show them
+        assert_eq!(status.message_with_items(true), "A is bad");
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(
+            json["images"],
+            serde_json::json!([{"glyph": {"glyph_name": "A"}, "svg": "<svg/>"}])
+        );
+        let status: Status = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            status.images,
+            vec![GlyphImage {
+                glyph,
+                svg: "<svg/>".to_string()
+            }]
+        );
+        // Statuses without pictures serialize as they did before
+        let json = serde_json::to_value(Status::pass()).unwrap();
+        assert!(json.get("images").is_none());
+    }
+}
@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
 
+use crate::{collection::expand_font_collections, Contents};
+
 /// A single file to be tested
 ///
 /// At this stage we do not care about the file type; this is sorted out later.
@@ -12,7 +14,10 @@ pub struct Testable {
     /// The filename of the source which generated this binary.
     pub source: Option<PathBuf>,
     /// The binary contents.
-    pub contents: Vec<u8>,
+    ///
+    /// On native targets, files are memory-mapped when their contents are first
+    /// used; see [Contents].
+    pub contents: Contents,
 }
 
 impl std::fmt::Debug for Testable {
@@ -24,9 +29,16 @@ impl std::fmt::Debug for Testable {
 impl Testable {
     /// Create a new Testable from a filename.
     ///
-    /// The contents are resolved from the filesystem.
+    /// The contents are resolved from the filesystem. Directories, such as UFO
+    /// font sources, are read by their file type conversions and have no contents.
+    /// On native targets, the file is only opened here to check that it can be
+    /// read; its contents are loaded when they are first needed.
     pub fn new<P: Into<PathBuf> + AsRef<Path>>(filename: P) -> Result<Self, std::io::Error> {
-        let contents = std::fs::read(&filename)?;
+        let contents = if filename.as_ref().is_dir() {
+            Contents::default()
+        } else {
+            Self::contents_of(filename.as_ref())?
+        };
         Ok(Self {
             filename: filename.into(),
             source: None,
@@ -39,7 +51,7 @@ impl Testable {
         filename: P,
         source: P,
     ) -> Result<Self, std::io::Error> {
-        let contents = std::fs::read(&filename)?;
+        let contents = Self::contents_of(filename.as_ref())?;
         Ok(Self {
             filename: filename.into(),
             source: Some(source.into()),
@@ -57,10 +69,23 @@ impl Testable {
         Self {
             filename: filename.into(),
             source: None,
-            contents,
+            contents: contents.into(),
         }
     }
 
+    /// Prepare to read the contents of a file
+    #[cfg(not(target_family = "wasm"))]
+    fn contents_of(filename: &Path) -> Result<Contents, std::io::Error> {
+        std::fs::File::open(filename)?;
+        Ok(Contents::from_file(filename))
+    }
+
+    /// Read the contents of a file
+    #[cfg(target_family = "wasm")]
+    fn contents_of(filename: &Path) -> Result<Contents, std::io::Error> {
+        std::fs::read(filename).map(Contents::from)
+    }
+
     /// Get the basename of the file.
     pub fn basename(&self) -> Option<String> {
         self.filename
@@ -79,12 +104,13 @@ impl Testable {
 
     /// Set the new contents of a file
     pub fn set(&mut self, new_bytes: Vec<u8>) {
-        self.contents = new_bytes;
+        self.contents = new_bytes.into();
     }
 
     /// Save the contents of a file to disk
     pub fn save(&self) -> Result<(), std::io::Error> {
-        std::fs::write(&self.filename, &self.contents)
+        // Copy first, as the contents may be a mapping of the file we are about to truncate
+        std::fs::write(&self.filename, self.contents.to_vec())
     }
 }
 
@@ -102,6 +128,8 @@ pub struct TestableCollection {
 
 impl TestableCollection {
     /// Create a new TestableCollection from a list of filenames.
+    ///
+    /// Each face of a font collection file is added as a testable of its own.
     pub fn from_filenames<P: Into<PathBuf> + AsRef<Path> + Clone>(
         filenames: &[P],
         identifier: Option<&str>,
@@ -109,7 +137,7 @@ impl TestableCollection {
         let collection: Result<Vec<Testable>, _> =
             filenames.iter().map(|x| Testable::new(x.clone())).collect();
         Ok(Self {
-            testables: collection?,
+            testables: expand_font_collections(collection?),
             directory: identifier
                 .map(|x| x.to_string())
                 .unwrap_or("A collection".to_string()),
@@ -117,9 +145,11 @@ impl TestableCollection {
     }
 
     /// Create a new TestableCollection from a list of [Testable]s.
+    ///
+    /// Each face of a font collection file is added as a testable of its own.
     pub fn from_testables(testables: Vec<Testable>, identifier: Option<String>) -> Self {
         Self {
-            testables,
+            testables: expand_font_collections(testables),
             directory: identifier.unwrap_or("A collection".to_string()),
         }
     }
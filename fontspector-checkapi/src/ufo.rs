@@ -0,0 +1,97 @@
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use norad::Font;
+
+use crate::{Context, FileType, FileTypeConvert, Testable};
+
+/// Load a UFO from disk, or from the context's cache if the testable has already loaded it
+///
+/// Loading a UFO means reading every glyph file, so we only want to do it once
+/// per testable even though each check converts its testable afresh. The
+/// cache goes away with the testable's other cached values.
+pub(crate) fn load_ufo(path: &Path, context: Option<&Context>) -> Result<Arc<Font>, String> {
+    let load = || Font::load(path).map_err(|e| format!("{}: {}", path.display(), e));
+    match context {
+        Some(context) => context.get_or_try_compute(&path.display().to_string(), load),
+        None => load().map(Arc::new),
+    }
+}
+
+/// A file type for UFO font sources
+///
+/// This is a separate type from [FileType] so that converting a [Testable]
+/// with it gives you a [Ufo]; register its `filetype` with the registry.
+pub struct UfoType {
+    /// The file type to register
+    pub filetype: FileType<'static>,
+}
+
+impl UfoType {
+    /// Convert a testable to a [Ufo], sharing the loaded UFO with the
+    /// testable's other checks through the context
+    ///
+    /// Returns `Ok(None)` if the testable is not a UFO, and an error if it is
+    /// one but could not be loaded.
+    pub fn from_testable_cached(
+        &self,
+        t: &Testable,
+        context: &Context,
+    ) -> Result<Option<Ufo>, String> {
+        self.filetype
+            .applies(t)
+            .then(|| Ufo::load(&t.filename, Some(context)))
+            .transpose()
+    }
+}
+
+/// A file type for UFO font sources
+pub const UFO: UfoType = UfoType {
+    filetype: FileType { pattern: "*.ufo" },
+};
+
+/// A UFO font source to be tested
+///
+/// This dereferences to a [norad::Font].
+pub struct Ufo {
+    /// The path to the UFO directory
+    pub filename: PathBuf,
+    /// The loaded font
+    font: Arc<Font>,
+}
+
+impl Ufo {
+    /// Load a UFO from a path
+    pub fn new(filename: &Path) -> Result<Self, String> {
+        Self::load(filename, None)
+    }
+
+    /// Load a UFO from a path, using the context's cache if one is given
+    fn load(filename: &Path, context: Option<&Context>) -> Result<Self, String> {
+        Ok(Ufo {
+            filename: filename.to_path_buf(),
+            font: load_ufo(filename, context)?,
+        })
+    }
+}
+
+impl Deref for Ufo {
+    type Target = Font;
+
+    fn deref(&self) -> &Font {
+        &self.font
+    }
+}
+
+impl<'a> FileTypeConvert<'a, Ufo> for UfoType {
+    fn from_testable(&self, t: &'a Testable) -> Option<Ufo> {
+        self.filetype
+            .applies(t)
+            .then(|| Ufo::new(&t.filename))
+            .transpose()
+            .unwrap_or(None)
+    }
+}
@@ -1,9 +1,14 @@
 use std::fmt::Display;
 
-use crate::{CheckFnResult, Context, Status, StatusCode};
+use crate::{return_result, CheckFnResult, Context, Status, StatusCode};
 
 /// Formats a list of items as a Markdown bullet list.
-pub fn bullet_list<I>(context: &Context, items: I) -> String
+///
+/// Unless `full_lists` is set, only the first nine items are shown. This is
+/// used by reporters to show the items of a [Status]; checks should attach
+/// their items to the status with [Status::with_items] rather than formatting
+/// them into the message.
+pub fn bullet_list<I>(items: I, full_lists: bool) -> String
 where
     I: IntoIterator,
     I::Item: Display,
@@ -14,7 +19,7 @@ where
         .map(|item| format!("* {}", item))
         .collect::<Vec<_>>();
 
-    if context.full_lists {
+    if full_lists {
         list.extend(items.map(|item| format!("* {}", item)));
     } else {
         let remainder = items.count();
@@ -61,18 +66,14 @@ where
     if ok {
         Ok(Status::just_one_pass())
     } else {
-        let message = format!(
-            "{}\n\nThe following values were found:\n\n{}",
-            message_start,
-            bullet_list(
-                _context,
-                values.iter().map(|(_, a, b)| format!("{}: {}", a, b))
-            )
-        );
-        if StatusCode::Fail == severity {
-            Ok(Status::just_one_fail(code, &message))
+        let message = format!("{}\n\nThe following values were found:", message_start);
+        let status = if StatusCode::Fail == severity {
+            Status::fail(code, &message)
         } else {
-            Ok(Status::just_one_warn(code, &message))
-        }
+            Status::warn(code, &message)
+        };
+        return_result(vec![
+            status.with_items(values.iter().map(|(_, a, b)| format!("{}: {}", a, b)))
+        ])
     }
 }
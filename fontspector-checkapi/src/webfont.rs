@@ -0,0 +1,810 @@
+use std::io::Read;
+
+use read_fonts::types::Tag;
+
+use crate::sfnt::build_sfnt;
+
+/// The tags of tables which WOFF2 can refer to by index in its table directory
+const WOFF2_KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+];
+
+/// An error encountered while decoding a WOFF or WOFF2 file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebFontError(pub String);
+
+impl std::fmt::Display for WebFontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for WebFontError {}
+
+/// Shorthand for returning a [WebFontError]
+fn err<T>(message: impl Into<String>) -> Result<T, WebFontError> {
+    Err(WebFontError(message.into()))
+}
+
+/// The container format of a web font
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebFontFormat {
+    /// WOFF 1.0, with zlib-compressed tables
+    Woff,
+    /// WOFF 2.0, with Brotli-compressed and transformed tables
+    Woff2,
+}
+
+/// An entry in the table directory of a WOFF or WOFF2 file
+#[derive(Debug, Clone)]
+pub struct WebFontTable {
+    /// The table tag
+    pub tag: Tag,
+    /// The length of the table once decompressed and untransformed
+    pub orig_length: u32,
+    /// The length of the table as stored: compressed (WOFF) or transformed (WOFF2)
+    pub stored_length: u32,
+    /// The WOFF2 transformation version (always zero for WOFF)
+    pub transform_version: u8,
+    /// The offset of the table data: within the file for WOFF, within the decompressed stream for WOFF2
+    offset: u32,
+}
+
+impl WebFontTable {
+    /// Whether this table has been transformed by the WOFF2 encoder
+    ///
+    /// For `glyf` and `loca`, version 0 is the transform and version 3 is the
+    /// null transform; for all other tables, version 0 is the null transform.
+    pub fn is_transformed(&self) -> bool {
+        if self.tag == Tag::new(b"glyf") || self.tag == Tag::new(b"loca") {
+            self.transform_version != 3
+        } else {
+            self.transform_version != 0
+        }
+    }
+}
+
+/// A WOFF or WOFF2 container
+///
+/// This gives access to the container-level structures (the extended metadata
+/// and private data blocks, and the table directory) and can rebuild the
+/// original sfnt-housed font from the compressed data.
+pub struct WebFont<'a> {
+    /// The container format
+    pub format: WebFontFormat,
+    /// The sfnt version of the font inside the container
+    pub flavor: u32,
+    /// The table directory
+    pub tables: Vec<WebFontTable>,
+    /// The (offset, length, original length) of the extended metadata block
+    metadata: Option<(u32, u32, u32)>,
+    /// The (offset, length) of the private data block
+    private_data: Option<(u32, u32)>,
+    /// Where the Brotli-compressed table data starts and ends (WOFF2 only)
+    compressed_data: (usize, usize),
+    /// The raw file contents
+    data: &'a [u8],
+}
+
+/// A simple big-endian reader over a slice of bytes
+struct Cursor<'a> {
+    /// The data being read
+    data: &'a [u8],
+    /// The current position in the data
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a reader at the start of some data
+    fn new(data: &'a [u8]) -> Self {
+        Cursor { data, pos: 0 }
+    }
+
+    /// Read a number of bytes, failing if the data runs out
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], WebFontError> {
+        let end = self.pos.checked_add(len);
+        match end.and_then(|end| self.data.get(self.pos..end)) {
+            Some(slice) => {
+                self.pos += len;
+                Ok(slice)
+            }
+            None => err("Unexpected end of data"),
+        }
+    }
+
+    /// Read an unsigned byte
+    fn u8(&mut self) -> Result<u8, WebFontError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// Read a big-endian unsigned 16-bit integer
+    fn u16(&mut self) -> Result<u16, WebFontError> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    /// Read a big-endian signed 16-bit integer
+    fn i16(&mut self) -> Result<i16, WebFontError> {
+        Ok(self.u16()? as i16)
+    }
+
+    /// Read a big-endian unsigned 32-bit integer
+    fn u32(&mut self) -> Result<u32, WebFontError> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// Read a WOFF2 variable-length `UIntBase128` value
+    fn uint_base128(&mut self) -> Result<u32, WebFontError> {
+        let mut accum: u32 = 0;
+        for i in 0..5 {
+            let byte = self.u8()?;
+            if i == 0 && byte == 0x80 {
+                return err("UIntBase128 value has leading zeros");
+            }
+            if accum & 0xFE00_0000 != 0 {
+                return err("UIntBase128 value overflows");
+            }
+            accum = (accum << 7) | u32::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                return Ok(accum);
+            }
+        }
+        err("UIntBase128 value is longer than five bytes")
+    }
+
+    /// Read a WOFF2 variable-length `255UInt16` value
+    fn uint_255(&mut self) -> Result<u16, WebFontError> {
+        match self.u8()? {
+            253 => self.u16(),
+            254 => Ok(u16::from(self.u8()?) + 253 * 2),
+            255 => Ok(u16::from(self.u8()?) + 253),
+            code => Ok(u16::from(code)),
+        }
+    }
+}
+
+/// Decompress a zlib stream, checking that it has the length we expected
+fn inflate(data: &[u8], expected_length: usize) -> Result<Vec<u8>, WebFontError> {
+    let mut out = Vec::with_capacity(expected_length);
+    flate2::read::ZlibDecoder::new(data)
+        .read_to_end(&mut out)
+        .map_err(|e| WebFontError(format!("Could not decompress zlib data: {}", e)))?;
+    if out.len() != expected_length {
+        return err(format!(
+            "Decompressed data is {} bytes long, expected {}",
+            out.len(),
+            expected_length
+        ));
+    }
+    Ok(out)
+}
+
+/// Decompress a Brotli stream, checking that it has the length we expected
+fn unbrotli(data: &[u8], expected_length: usize) -> Result<Vec<u8>, WebFontError> {
+    let mut out = Vec::with_capacity(expected_length);
+    brotli_decompressor::Decompressor::new(data, 4096)
+        .read_to_end(&mut out)
+        .map_err(|e| WebFontError(format!("Could not decompress Brotli data: {}", e)))?;
+    if out.len() != expected_length {
+        return err(format!(
+            "Decompressed data is {} bytes long, expected {}",
+            out.len(),
+            expected_length
+        ));
+    }
+    Ok(out)
+}
+
+impl<'a> WebFont<'a> {
+    /// Does this data look like a WOFF or WOFF2 file?
+    pub fn is_webfont(data: &[u8]) -> bool {
+        data.starts_with(b"wOFF") || data.starts_with(b"wOF2")
+    }
+
+    /// Parse the header and table directory of a WOFF or WOFF2 file
+    pub fn new(data: &'a [u8]) -> Result<Self, WebFontError> {
+        let mut cursor = Cursor::new(data);
+        let format = match cursor.bytes(4)? {
+            b"wOFF" => WebFontFormat::Woff,
+            b"wOF2" => WebFontFormat::Woff2,
+            _ => return err("Not a WOFF or WOFF2 file"),
+        };
+        let flavor = cursor.u32()?;
+        let length = cursor.u32()?;
+        if length as usize != data.len() {
+            return err(format!(
+                "Header says the file is {} bytes long, but it is {} bytes",
+                length,
+                data.len()
+            ));
+        }
+        let num_tables = cursor.u16()?;
+        let _reserved = cursor.u16()?;
+        let _total_sfnt_size = cursor.u32()?;
+        let total_compressed_size = if format == WebFontFormat::Woff2 {
+            cursor.u32()?
+        } else {
+            0
+        };
+        let _major_version = cursor.u16()?;
+        let _minor_version = cursor.u16()?;
+        let (meta_offset, meta_length, meta_orig_length) =
+            (cursor.u32()?, cursor.u32()?, cursor.u32()?);
+        let (priv_offset, priv_length) = (cursor.u32()?, cursor.u32()?);
+
+        let mut tables = Vec::with_capacity(num_tables as usize);
+        match format {
+            WebFontFormat::Woff => {
+                for _ in 0..num_tables {
+                    let tag = Tag::new(cursor.bytes(4)?.try_into().unwrap_or(b"    "));
+                    let offset = cursor.u32()?;
+                    let comp_length = cursor.u32()?;
+                    let orig_length = cursor.u32()?;
+                    let _orig_checksum = cursor.u32()?;
+                    tables.push(WebFontTable {
+                        tag,
+                        orig_length,
+                        stored_length: comp_length,
+                        transform_version: 0,
+                        offset,
+                    });
+                }
+            }
+            WebFontFormat::Woff2 => {
+                let mut offset = 0u32;
+                for _ in 0..num_tables {
+                    let flags = cursor.u8()?;
+                    let tag = match flags & 0x3F {
+                        63 => Tag::new(cursor.bytes(4)?.try_into().unwrap_or(b"    ")),
+                        index => Tag::new(WOFF2_KNOWN_TAGS[index as usize]),
+                    };
+                    let transform_version = flags >> 6;
+                    let orig_length = cursor.uint_base128()?;
+                    let mut table = WebFontTable {
+                        tag,
+                        orig_length,
+                        stored_length: orig_length,
+                        transform_version,
+                        offset,
+                    };
+                    if table.is_transformed() {
+                        table.stored_length = cursor.uint_base128()?;
+                    }
+                    offset = offset
+                        .checked_add(table.stored_length)
+                        .ok_or(WebFontError("Table data is too large".to_string()))?;
+                    tables.push(table);
+                }
+                if flavor == u32::from_be_bytes(*b"ttcf") {
+                    return err("WOFF2 font collections are not supported");
+                }
+            }
+        }
+        let compressed_start = cursor.pos;
+        let Some(compressed_end) = compressed_start
+            .checked_add(total_compressed_size as usize)
+            .filter(|end| *end <= data.len())
+        else {
+            return err("Compressed font data extends beyond the end of the file");
+        };
+
+        Ok(WebFont {
+            format,
+            flavor,
+            tables,
+            metadata: (meta_length > 0).then_some((meta_offset, meta_length, meta_orig_length)),
+            private_data: (priv_length > 0).then_some((priv_offset, priv_length)),
+            compressed_data: (compressed_start, compressed_end),
+            data,
+        })
+    }
+
+    /// Find a table in the directory
+    pub fn table(&self, tag: &[u8; 4]) -> Option<&WebFontTable> {
+        self.tables.iter().find(|t| t.tag == Tag::new(tag))
+    }
+
+    /// Get a region of the file, failing if it lies outside the file
+    fn region(&self, offset: u32, length: u32) -> Result<&'a [u8], WebFontError> {
+        let start = offset as usize;
+        start
+            .checked_add(length as usize)
+            .and_then(|end| self.data.get(start..end))
+            .ok_or(WebFontError(format!(
+                "Block at offset {} (length {}) extends beyond the end of the file",
+                offset, length
+            )))
+    }
+
+    /// The decompressed extended metadata block, if there is one
+    ///
+    /// The metadata is supposed to be a UTF-8 encoded XML document; this
+    /// function does not check that it is.
+    pub fn metadata(&self) -> Result<Option<Vec<u8>>, WebFontError> {
+        let Some((offset, length, orig_length)) = self.metadata else {
+            return Ok(None);
+        };
+        let compressed = self.region(offset, length)?;
+        match self.format {
+            WebFontFormat::Woff => inflate(compressed, orig_length as usize),
+            WebFontFormat::Woff2 => unbrotli(compressed, orig_length as usize),
+        }
+        .map(Some)
+    }
+
+    /// The private data block, if there is one
+    pub fn private_data(&self) -> Result<Option<&'a [u8]>, WebFontError> {
+        self.private_data
+            .map(|(offset, length)| self.region(offset, length))
+            .transpose()
+    }
+
+    /// Decompress (and for WOFF2, untransform) the tables of the font
+    pub fn decompress_tables(&self) -> Result<Vec<(Tag, Vec<u8>)>, WebFontError> {
+        match self.format {
+            WebFontFormat::Woff => self
+                .tables
+                .iter()
+                .map(|table| {
+                    let stored = self.region(table.offset, table.stored_length)?;
+                    let data = if table.stored_length < table.orig_length {
+                        inflate(stored, table.orig_length as usize)?
+                    } else if table.stored_length == table.orig_length {
+                        stored.to_vec()
+                    } else {
+                        return err(format!(
+                            "Table {} is larger compressed than uncompressed",
+                            table.tag
+                        ));
+                    };
+                    Ok((table.tag, data))
+                })
+                .collect(),
+            WebFontFormat::Woff2 => self.decompress_woff2_tables(),
+        }
+    }
+
+    /// Decompress the Brotli stream of a WOFF2 file and reverse any table transforms
+    fn decompress_woff2_tables(&self) -> Result<Vec<(Tag, Vec<u8>)>, WebFontError> {
+        let (start, end) = self.compressed_data;
+        let stream_length = self.tables.iter().map(|t| t.stored_length as usize).sum();
+        let stream = unbrotli(&self.data[start..end], stream_length)?;
+        let stored = |table: &WebFontTable| {
+            let start = table.offset as usize;
+            &stream[start..start + table.stored_length as usize]
+        };
+
+        let mut tables: Vec<(Tag, Vec<u8>)> = vec![];
+        for table in self.tables.iter() {
+            if !table.is_transformed() {
+                if table.stored_length != table.orig_length {
+                    return err(format!("Table {} has the wrong length", table.tag));
+                }
+                tables.push((table.tag, stored(table).to_vec()));
+            }
+        }
+        let untransformed = |tag: &[u8; 4]| {
+            tables
+                .iter()
+                .find(|(t, _)| *t == Tag::new(tag))
+                .map(|(_, data)| data.as_slice())
+        };
+
+        let glyf = self.table(b"glyf");
+        let loca = self.table(b"loca");
+        let mut x_mins = None;
+        let mut reconstructed = vec![];
+        match (glyf, loca) {
+            (Some(glyf), Some(loca)) if glyf.is_transformed() && loca.is_transformed() => {
+                if loca.stored_length != 0 {
+                    return err("Transformed loca table must have a zero transform length");
+                }
+                let glyphs = reconstruct_glyf(stored(glyf))?;
+                if let Some(maxp) = untransformed(b"maxp") {
+                    let num_glyphs = Cursor { data: maxp, pos: 4 }.u16()?;
+                    if num_glyphs as usize != glyphs.x_mins.len() {
+                        return err(format!(
+                            "Transformed glyf table has {} glyphs but maxp says {}",
+                            glyphs.x_mins.len(),
+                            num_glyphs
+                        ));
+                    }
+                }
+                if glyphs.loca.len() != loca.orig_length as usize {
+                    return err(format!(
+                        "Reconstructed loca table is {} bytes but the directory says {}",
+                        glyphs.loca.len(),
+                        loca.orig_length
+                    ));
+                }
+                x_mins = Some(glyphs.x_mins);
+                reconstructed.push((glyf.tag, glyphs.glyf));
+                reconstructed.push((loca.tag, glyphs.loca));
+            }
+            (Some(glyf), Some(loca)) if glyf.is_transformed() || loca.is_transformed() => {
+                return err("glyf and loca tables must either both be transformed or neither");
+            }
+            (Some(table), None) | (None, Some(table)) if table.is_transformed() => {
+                return err("A transformed glyf table requires a loca table, and vice versa");
+            }
+            _ => {}
+        }
+
+        for table in self.tables.iter().filter(|t| t.is_transformed()) {
+            if table.tag == Tag::new(b"glyf") || table.tag == Tag::new(b"loca") {
+                continue;
+            }
+            if table.tag != Tag::new(b"hmtx") || table.transform_version != 1 {
+                return err(format!(
+                    "Unknown transform version {} for table {}",
+                    table.transform_version, table.tag
+                ));
+            }
+            let Some(x_mins) = x_mins.as_ref() else {
+                return err("hmtx table is transformed, but glyf table is not");
+            };
+            let num_h_metrics = untransformed(b"hhea")
+                .map(|hhea| {
+                    Cursor {
+                        data: hhea,
+                        pos: 34,
+                    }
+                    .u16()
+                })
+                .ok_or(WebFontError(
+                    "Transformed hmtx requires an hhea table".to_string(),
+                ))??;
+            let hmtx = reconstruct_hmtx(stored(table), num_h_metrics as usize, x_mins)?;
+            if hmtx.len() != table.orig_length as usize {
+                return err(format!(
+                    "Reconstructed hmtx table is {} bytes but the directory says {}",
+                    hmtx.len(),
+                    table.orig_length
+                ));
+            }
+            reconstructed.push((table.tag, hmtx));
+        }
+        tables.extend(reconstructed);
+        Ok(tables)
+    }
+
+    /// Rebuild the original sfnt-housed font
+    pub fn to_sfnt(&self) -> Result<Vec<u8>, WebFontError> {
+        let mut tables = self.decompress_tables()?;
+        tables.sort_by_key(|(tag, _)| *tag);
+        Ok(build_sfnt(self.flavor, &tables))
+    }
+}
+
+/// Pad a buffer with zeros to a four-byte boundary
+fn pad4(data: &mut Vec<u8>) {
+    data.resize(data.len().next_multiple_of(4), 0);
+}
+
+/// The result of reversing the WOFF2 glyf transform
+struct ReconstructedGlyphs {
+    /// The rebuilt glyf table
+    glyf: Vec<u8>,
+    /// The rebuilt loca table
+    loca: Vec<u8>,
+    /// The xMin of each glyph, needed to reconstruct hmtx
+    x_mins: Vec<i16>,
+}
+
+/// Does a bitmap (most significant bit first) have the bit for this index set?
+fn bit_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap
+        .get(index >> 3)
+        .is_some_and(|byte| byte & (0x80 >> (index & 7)) != 0)
+}
+
+/// Decode one point of a WOFF2 transformed simple glyph from its flag and triplet bytes
+fn decode_triplet(flag: u8, glyphs: &mut Cursor) -> Result<(i32, i32), WebFontError> {
+    let with_sign = |flag: u8, value: i32| if flag & 1 != 0 { value } else { -value };
+    let flag = flag & 0x7F;
+    let (dx, dy) = if flag < 10 {
+        let b0 = i32::from(glyphs.u8()?);
+        (0, with_sign(flag, ((i32::from(flag) & 14) << 7) + b0))
+    } else if flag < 20 {
+        let b0 = i32::from(glyphs.u8()?);
+        (
+            with_sign(flag, (((i32::from(flag) - 10) & 14) << 7) + b0),
+            0,
+        )
+    } else if flag < 84 {
+        let b0 = i32::from(flag) - 20;
+        let b1 = i32::from(glyphs.u8()?);
+        (
+            with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+            with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+        )
+    } else if flag < 120 {
+        let b0 = i32::from(flag) - 84;
+        let b1 = i32::from(glyphs.u8()?);
+        let b2 = i32::from(glyphs.u8()?);
+        (
+            with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+            with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+        )
+    } else if flag < 124 {
+        let b1 = i32::from(glyphs.u8()?);
+        let b2 = i32::from(glyphs.u8()?);
+        let b3 = i32::from(glyphs.u8()?);
+        (
+            with_sign(flag, (b1 << 4) + (b2 >> 4)),
+            with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+        )
+    } else {
+        let b1 = i32::from(glyphs.u8()?);
+        let b2 = i32::from(glyphs.u8()?);
+        let b3 = i32::from(glyphs.u8()?);
+        let b4 = i32::from(glyphs.u8()?);
+        (
+            with_sign(flag, (b1 << 8) + b2),
+            with_sign(flag >> 1, (b3 << 8) + b4),
+        )
+    };
+    Ok((dx, dy))
+}
+
+/// Convert a coordinate to the 16-bit value stored in a glyph, failing if it doesn't fit
+fn coordinate(value: i32) -> Result<i16, WebFontError> {
+    i16::try_from(value).map_err(|_| WebFontError(format!("Coordinate {} out of range", value)))
+}
+
+/// Encode the points of a simple glyph as TrueType flags and coordinate arrays
+fn encode_points(points: &[(i32, i32, bool)], overlap: bool) -> Result<Vec<u8>, WebFontError> {
+    let mut flags = vec![];
+    let mut xs = vec![];
+    let mut ys = vec![];
+    let (mut last_x, mut last_y) = (0, 0);
+    for (index, (x, y, on_curve)) in points.iter().enumerate() {
+        let mut flag = if *on_curve { 0x01 } else { 0x00 };
+        if overlap && index == 0 {
+            flag |= 0x40;
+        }
+        let dx = coordinate(x - last_x)?;
+        let dy = coordinate(y - last_y)?;
+        if dx == 0 {
+            flag |= 0x10;
+        } else if dx.unsigned_abs() < 256 {
+            flag |= 0x02 | if dx > 0 { 0x10 } else { 0 };
+            xs.push(dx.unsigned_abs() as u8);
+        } else {
+            xs.extend(dx.to_be_bytes());
+        }
+        if dy == 0 {
+            flag |= 0x20;
+        } else if dy.unsigned_abs() < 256 {
+            flag |= 0x04 | if dy > 0 { 0x20 } else { 0 };
+            ys.push(dy.unsigned_abs() as u8);
+        } else {
+            ys.extend(dy.to_be_bytes());
+        }
+        flags.push(flag);
+        (last_x, last_y) = (*x, *y);
+    }
+    flags.extend(xs);
+    flags.extend(ys);
+    Ok(flags)
+}
+
+/// Walk the components of a composite glyph, returning its length and whether it has instructions
+fn composite_length(composites: &Cursor) -> Result<(usize, bool), WebFontError> {
+    let mut cursor = Cursor::new(&composites.data[composites.pos..]);
+    let mut have_instructions = false;
+    loop {
+        let flags = cursor.u16()?;
+        have_instructions |= flags & 0x0100 != 0;
+        let mut size = 2 + if flags & 0x0001 != 0 { 4 } else { 2 };
+        if flags & 0x0008 != 0 {
+            size += 2;
+        } else if flags & 0x0040 != 0 {
+            size += 4;
+        } else if flags & 0x0080 != 0 {
+            size += 8;
+        }
+        cursor.bytes(size)?;
+        if flags & 0x0020 == 0 {
+            return Ok((cursor.pos, have_instructions));
+        }
+    }
+}
+
+/// Reverse the WOFF2 glyf transform, producing glyf and loca tables
+fn reconstruct_glyf(data: &[u8]) -> Result<ReconstructedGlyphs, WebFontError> {
+    let mut header = Cursor::new(data);
+    let _reserved = header.u16()?;
+    let option_flags = header.u16()?;
+    let num_glyphs = header.u16()? as usize;
+    let index_format = header.u16()?;
+    let mut streams = vec![];
+    for _ in 0..7 {
+        streams.push(header.u32()? as usize);
+    }
+    let mut substream = |len: usize| header.bytes(len).map(Cursor::new);
+    let mut n_contours = substream(streams[0])?;
+    let mut n_points = substream(streams[1])?;
+    let mut flags = substream(streams[2])?;
+    let mut glyphs = substream(streams[3])?;
+    let mut composites = substream(streams[4])?;
+    let mut bboxes = substream(streams[5])?;
+    let mut instructions = substream(streams[6])?;
+    let overlap_bitmap = if option_flags & 1 != 0 {
+        substream(num_glyphs.div_ceil(8))?.data
+    } else {
+        &[]
+    };
+    let bbox_bitmap = bboxes.bytes(num_glyphs.div_ceil(32) * 4)?;
+
+    let mut glyf = vec![];
+    let mut offsets = vec![0];
+    let mut x_mins = vec![];
+    for glyph_id in 0..num_glyphs {
+        let contours = n_contours.i16()?;
+        let explicit_bbox = bit_set(bbox_bitmap, glyph_id);
+        let mut glyph: Vec<u8> = vec![];
+        let mut x_min = 0;
+        if contours == 0 {
+            if explicit_bbox {
+                return err(format!("Empty glyph {} has a bounding box", glyph_id));
+            }
+        } else if contours == -1 {
+            if !explicit_bbox {
+                return err(format!("Composite glyph {} has no bounding box", glyph_id));
+            }
+            glyph.extend(contours.to_be_bytes());
+            let bbox = bboxes.bytes(8)?;
+            x_min = Cursor::new(bbox).i16()?;
+            glyph.extend(bbox);
+            let (length, have_instructions) = composite_length(&composites)?;
+            glyph.extend(composites.bytes(length)?);
+            if have_instructions {
+                let instruction_length = glyphs.uint_255()?;
+                glyph.extend(instruction_length.to_be_bytes());
+                glyph.extend(instructions.bytes(instruction_length as usize)?);
+            }
+        } else if contours > 0 {
+            let mut end_points = vec![];
+            let mut total_points: usize = 0;
+            for _ in 0..contours {
+                total_points += n_points.uint_255()? as usize;
+                let end_point = total_points
+                    .checked_sub(1)
+                    .and_then(|p| u16::try_from(p).ok())
+                    .ok_or(WebFontError(format!("Glyph {} has bad contours", glyph_id)))?;
+                end_points.push(end_point);
+            }
+            let point_flags = flags.bytes(total_points)?;
+            let mut points = Vec::with_capacity(total_points);
+            let (mut x, mut y) = (0i32, 0i32);
+            for flag in point_flags {
+                let (dx, dy) = decode_triplet(*flag, &mut glyphs)?;
+                x += dx;
+                y += dy;
+                points.push((x, y, flag & 0x80 == 0));
+            }
+            let instruction_length = glyphs.uint_255()?;
+
+            let bbox = if explicit_bbox {
+                let mut bbox = Cursor::new(bboxes.bytes(8)?);
+                [bbox.i16()?, bbox.i16()?, bbox.i16()?, bbox.i16()?]
+            } else {
+                let xs = points.iter().map(|p| p.0);
+                let ys = points.iter().map(|p| p.1);
+                [
+                    coordinate(xs.clone().min().unwrap_or(0))?,
+                    coordinate(ys.clone().min().unwrap_or(0))?,
+                    coordinate(xs.max().unwrap_or(0))?,
+                    coordinate(ys.max().unwrap_or(0))?,
+                ]
+            };
+            x_min = bbox[0];
+            glyph.extend(contours.to_be_bytes());
+            for value in bbox {
+                glyph.extend(value.to_be_bytes());
+            }
+            for end_point in end_points {
+                glyph.extend(end_point.to_be_bytes());
+            }
+            glyph.extend(instruction_length.to_be_bytes());
+            glyph.extend(instructions.bytes(instruction_length as usize)?);
+            glyph.extend(encode_points(&points, bit_set(overlap_bitmap, glyph_id))?);
+        } else {
+            return err(format!(
+                "Glyph {} has an invalid contour count {}",
+                glyph_id, contours
+            ));
+        }
+        x_mins.push(x_min);
+        pad4(&mut glyph);
+        glyf.extend(glyph);
+        offsets.push(glyf.len());
+    }
+
+    let mut loca = vec![];
+    for offset in offsets {
+        if index_format == 0 {
+            let offset = u16::try_from(offset / 2)
+                .map_err(|_| WebFontError("glyf table too large for short loca".to_string()))?;
+            loca.extend(offset.to_be_bytes());
+        } else {
+            loca.extend((offset as u32).to_be_bytes());
+        }
+    }
+    Ok(ReconstructedGlyphs { glyf, loca, x_mins })
+}
+
+/// Reverse the WOFF2 hmtx transform, using the glyph bounding boxes to restore the side bearings
+fn reconstruct_hmtx(
+    data: &[u8],
+    num_h_metrics: usize,
+    x_mins: &[i16],
+) -> Result<Vec<u8>, WebFontError> {
+    let mut cursor = Cursor::new(data);
+    let flags = cursor.u8()?;
+    if flags & 0xFC != 0 {
+        return err("Reserved bits set in hmtx transform flags");
+    }
+    if num_h_metrics == 0 || num_h_metrics > x_mins.len() {
+        return err("Invalid numberOfHMetrics for transformed hmtx table");
+    }
+    let mut advances = vec![];
+    for _ in 0..num_h_metrics {
+        advances.push(cursor.u16()?);
+    }
+    let mut lsbs = vec![];
+    for (glyph_id, x_min) in x_mins.iter().enumerate() {
+        let explicit = if glyph_id < num_h_metrics {
+            flags & 1 == 0
+        } else {
+            flags & 2 == 0
+        };
+        lsbs.push(if explicit { cursor.i16()? } else { *x_min });
+    }
+    let mut hmtx = vec![];
+    for (glyph_id, lsb) in lsbs.into_iter().enumerate() {
+        if let Some(advance) = advances.get(glyph_id) {
+            hmtx.extend(advance.to_be_bytes());
+        }
+        hmtx.extend(lsb.to_be_bytes());
+    }
+    Ok(hmtx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variable_length_integers() {
+        let mut cursor = Cursor::new(&[0x3F, 0x81, 0x00, 253, 0x01, 0x02, 254, 3, 255, 3]);
+        assert_eq!(cursor.uint_base128(), Ok(63));
+        assert_eq!(cursor.uint_base128(), Ok(128));
+        assert_eq!(cursor.uint_255(), Ok(258));
+        assert_eq!(cursor.uint_255(), Ok(509));
+        assert_eq!(cursor.uint_255(), Ok(256));
+        assert!(Cursor::new(&[0x80, 0x01]).uint_base128().is_err());
+    }
+
+    #[test]
+    fn test_triplets() {
+        // Flag 0..9: dy only, sign from the low bit
+        assert_eq!(decode_triplet(1, &mut Cursor::new(&[5])), Ok((0, 5)));
+        assert_eq!(decode_triplet(0, &mut Cursor::new(&[5])), Ok((0, -5)));
+        // Flag 10..19: dx only
+        assert_eq!(decode_triplet(11, &mut Cursor::new(&[5])), Ok((5, 0)));
+        // Flag 124..127: two full 16-bit deltas; the high bit is the on-curve flag
+        assert_eq!(
+            decode_triplet(0x80 | 127, &mut Cursor::new(&[0x01, 0x00, 0x00, 0x10])),
+            Ok((256, 16))
+        );
+    }
+}
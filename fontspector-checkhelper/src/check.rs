@@ -68,6 +68,16 @@ fn dedent_and_unwrap_rationale(rationale: &str) -> String {
     new_rationale
 }
 
+// Lists such as tags are given as a comma-separated string, e.g. `tags = "outline, slow"`
+fn comma_separated_literals(list: Option<String>) -> Vec<syn::LitStr> {
+    list.unwrap_or_default()
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| syn::LitStr::new(item, Span::call_site()))
+        .collect()
+}
+
 #[derive(FromMeta)]
 struct CheckParams {
     id: String,
@@ -80,6 +90,12 @@ struct CheckParams {
     hotfix: Option<Ident>,
     fix_source: Option<Ident>,
     metadata: Option<String>,
+    tags: Option<String>,
+    aliases: Option<String>,
+    #[darling(default)]
+    experimental: bool,
+    #[darling(default)]
+    version: u32,
 }
 
 pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -146,6 +162,10 @@ pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         Some(metadata) => quote!(Some(&#metadata)),
         None => quote!(None),
     };
+    let tags = comma_separated_literals(params.tags);
+    let aliases = comma_separated_literals(params.aliases);
+    let experimental = params.experimental;
+    let version = params.version;
     let doc_string = format!(
         "`{}`: {}\n\n{}\n\n## Proposal\n\n{}",
         id.value(),
@@ -170,8 +190,14 @@ pub(crate) fn check_impl(args: TokenStream, input: TokenStream) -> TokenStream {
             implementation: #implementation,
             hotfix: #hotfix,
             fix_source: #fix_source,
-            flags: CheckFlags::default(),
+            flags: CheckFlags {
+                experimental: #experimental,
+                ..CheckFlags::default()
+            },
+            tags: &[#(#tags),*],
+            aliases: &[#(#aliases),*],
             _metadata: #metadata,
+            version: #version,
         };
     )
     .into()
@@ -1,6 +1,8 @@
 use clap::{ArgAction, Parser};
 use fontspector_checkapi::StatusCode;
 
+use crate::corpus::Shard;
+
 /// Quality control for OpenType fonts
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -17,9 +19,18 @@ pub struct Args {
     #[clap(short = 'L', long)]
     pub list_checks: bool,
 
+    /// List the checks available in the selected profile, with full details, as JSON
     #[clap(long)]
     pub list_checks_json: bool,
 
+    /// Explain a check: show its rationale, proposal, flags and configuration
+    #[clap(long, value_name = "CHECK_ID")]
+    pub explain: Option<String>,
+
+    /// Write a browsable documentation site for all profiles and checks to the given directory
+    #[clap(long, value_name = "DIRECTORY")]
+    pub docs_site: Option<String>,
+
     #[cfg(not(debug_assertions))]
     /// Number of worker processes. Defaults to the number of logical CPUs.
     #[clap(short = 'J', long)]
@@ -37,6 +48,30 @@ pub struct Args {
     #[clap(short = 'x', long)]
     pub exclude_checkid: Option<Vec<String>>,
 
+    /// Only run checks carrying one of these tags (e.g. outline, metadata, network)
+    #[clap(long, value_delimiter = ',')]
+    pub tag: Option<Vec<String>>,
+
+    /// Exclude checks carrying any of these tags (e.g. network,slow)
+    #[clap(long, value_delimiter = ',')]
+    pub exclude_tag: Option<Vec<String>>,
+
+    /// Only run checks in the named profile section
+    #[clap(long)]
+    pub section: Option<Vec<String>>,
+
+    /// Exclude checks in the named profile section
+    #[clap(long)]
+    pub exclude_section: Option<Vec<String>>,
+
+    /// Run experimental checks, and let their results affect the exit code
+    #[clap(long, conflicts_with = "no_experimental")]
+    pub experimental: bool,
+
+    /// Don't run experimental checks at all
+    #[clap(long)]
+    pub no_experimental: bool,
+
     /// Report full lists of items instead of abbreviated lists
     #[clap(long)]
     pub full_lists: bool,
@@ -69,6 +104,19 @@ pub struct Args {
     #[clap(long, help_heading = "Network")]
     pub skip_network: bool,
 
+    /// Reuse the results of checks whose inputs haven't changed, storing them in this directory
+    #[clap(long, value_name = "DIRECTORY", help_heading = "Caching")]
+    pub cache_dir: Option<String>,
+
+    /// Also reuse the results of network checks, if they are less than this many seconds old
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        requires = "cache_dir",
+        help_heading = "Caching"
+    )]
+    pub cache_network_ttl: Option<u64>,
+
     /// Write a JSON formatted report to the given filename
     #[clap(long, help_heading = "Reports")]
     pub json: Option<String>,
@@ -89,6 +137,37 @@ pub struct Args {
     #[clap(long, help_heading = "Fix problems")]
     pub fix_sources: bool,
 
-    /// Input files
+    /// A JSON or YAML file mapping font files to the sources they were built from
+    #[clap(long, value_name = "FILE")]
+    pub source_map: Option<String>,
+
+    /// Group fonts into collections by their typographic family name, rather than by directory
+    #[clap(long)]
+    pub group_by_family: bool,
+
+    /// Check every family in this directory tree, one family at a time
+    #[clap(
+        long,
+        value_name = "DIRECTORY",
+        conflicts_with_all = ["inputs", "hotfix", "fix_sources", "group_by_family"],
+        help_heading = "Corpus"
+    )]
+    pub corpus: Option<String>,
+
+    /// Only check one share of the corpus, e.g. 2/8 for the second of eight shares
+    #[clap(long, value_name = "N/M", requires = "corpus", help_heading = "Corpus")]
+    pub shard: Option<Shard>,
+
+    /// Merge the JSON reports of several runs, writing the result to the --json file
+    #[clap(
+        long,
+        value_name = "REPORT",
+        num_args = 1..,
+        conflicts_with_all = ["inputs", "corpus"],
+        help_heading = "Corpus"
+    )]
+    pub merge_reports: Option<Vec<String>>,
+
+    /// Input files, directories or zip files
     pub inputs: Vec<String>,
 }
@@ -0,0 +1,270 @@
+//! A persistent cache of check results
+//!
+//! Results are stored on disk, keyed by a hash of everything which can affect
+//! them: the contents of the files checked, the check and its version, and the
+//! check's effective configuration. A check whose inputs haven't changed since
+//! the last run reuses its stored result instead of running again.
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fontspector_checkapi::{
+    collection_face, Check, CheckResult, Context, Status, Testable, TestableType,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// A stored result
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// When the result was stored, in seconds since the Unix epoch
+    created: u64,
+    /// The statuses returned by the check
+    subresults: Vec<Status>,
+}
+
+/// The on-disk result cache
+pub struct ResultCache {
+    /// The directory holding the cache entries
+    directory: PathBuf,
+    /// How long results of network checks stay valid; if None, they are not cached
+    network_ttl: Option<Duration>,
+    /// The content hash of each file which can be cached, by filename
+    digests: HashMap<PathBuf, String>,
+    /// The number of results taken from the cache
+    hits: AtomicUsize,
+}
+
+/// The current time, in seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Whether a file's results depend only on its own contents
+///
+/// Directories (UFOs) have no contents of their own, and designspace documents
+/// refer to sources in other files, so we can't tell when they have changed.
+/// Likewise a font whose source is known may be checked against that source,
+/// which can change without the font changing.
+fn is_cacheable(testable: &Testable) -> bool {
+    testable.source.is_none()
+        && !testable.filename.is_dir()
+        && testable.extension().as_deref() != Some("designspace")
+}
+
+impl ResultCache {
+    /// Open a cache in the given directory
+    pub fn new(directory: &Path, network_ttl: Option<u64>) -> Self {
+        ResultCache {
+            directory: directory.to_path_buf(),
+            network_ttl: network_ttl.map(Duration::from_secs),
+            digests: HashMap::new(),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// Hash the contents of the files about to be tested
+    ///
+    /// This replaces the hashes of any files tested before, so that a run over
+    /// a large corpus only keeps the hashes of the family being checked.
+    pub fn hash_testables(&mut self, testables: &[TestableType]) {
+        // The faces of a font collection share its contents, so only hash those once
+        let mut file_digests: HashMap<PathBuf, String> = HashMap::new();
+        self.digests = testables
+            .iter()
+            .flat_map(|t| match t {
+                TestableType::Single(t) => Some(*t),
+                TestableType::Collection(_) => None,
+            })
+            .filter(|t| is_cacheable(t))
+            .map(|t| {
+                let file = collection_face(&t.filename)
+                    .map(|(collection, _)| collection)
+                    .unwrap_or(t.filename.clone());
+                let digest = file_digests
+                    .entry(file)
+                    .or_insert_with(|| format!("{:x}", Sha256::digest(&t.contents)));
+                (t.filename.clone(), digest.clone())
+            })
+            .collect();
+    }
+
+    /// The number of results which were taken from the cache
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Compute the cache key for running a check on a testable
+    ///
+    /// Returns None if the result should not be cached.
+    fn key(&self, check: &Check, testable: &TestableType, context: &Context) -> Option<String> {
+        if check.has_tag("network") && !context.skip_network && self.network_ttl.is_none() {
+            return None;
+        }
+        let files: Vec<&Testable> = match testable {
+            TestableType::Single(t) => vec![*t],
+            TestableType::Collection(c) => c.iter().collect(),
+        };
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(check.id);
+        hasher.update(check.version.to_le_bytes());
+        hasher.update(serde_json::to_string(&context.configuration).ok()?);
+        hasher.update(context.check_metadata.to_string());
+        hasher.update([context.skip_network as u8]);
+        if let TestableType::Collection(c) = testable {
+            hasher.update(&c.directory);
+        }
+        for file in files {
+            hasher.update(file.filename.to_str()?);
+            hasher.update(self.digests.get(&file.filename)?);
+        }
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    /// The file where the entry with the given key is stored
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(&key[..2]).join(format!("{}.json", key))
+    }
+
+    /// Load a stored result, if it is still valid
+    fn load(&self, key: &str, check: &Check) -> Option<Vec<Status>> {
+        let contents = std::fs::read(self.path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&contents)
+            .map_err(|e| log::warn!("Ignoring corrupt cache entry {}: {}", key, e))
+            .ok()?;
+        if let Some(ttl) = self.network_ttl.filter(|_| check.has_tag("network")) {
+            if now().saturating_sub(entry.created) > ttl.as_secs() {
+                return None;
+            }
+        }
+        Some(entry.subresults)
+    }
+
+    /// Store a result
+    ///
+    /// The entry is written to a temporary file and moved into place, so that
+    /// other fontspector processes sharing the cache never see a partial entry.
+    fn store(&self, key: &str, subresults: &[Status]) {
+        let path = self.path(key);
+        let temporary = path.with_extension(format!("{}.tmp", std::process::id()));
+        let entry = CacheEntry {
+            created: now(),
+            subresults: subresults.to_vec(),
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| serde_json::to_vec(&entry).map_err(std::io::Error::from))
+            .and_then(|json| std::fs::write(&temporary, json))
+            .and_then(|_| std::fs::rename(&temporary, &path));
+        if let Err(e) = result {
+            log::warn!("Could not write cache entry {}: {}", path.display(), e);
+        }
+    }
+
+    /// Run a check, reusing its stored result if its inputs haven't changed
+    pub fn run<'a>(
+        &self,
+        check: &'a Check<'a>,
+        testable: &'a TestableType<'a>,
+        context: &Context,
+        section: &str,
+    ) -> Option<CheckResult> {
+        let Some(key) = self.key(check, testable, context) else {
+            return check.run(testable, context, Some(section));
+        };
+        if let Some(subresults) = self.load(&key, check) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            let (filename, source) = match testable {
+                TestableType::Single(t) => (
+                    t.filename.to_str(),
+                    t.source.as_ref().and_then(|s| s.to_str()),
+                ),
+                TestableType::Collection(c) => (Some(c.directory.as_str()), None),
+            };
+            return Some(CheckResult::new(
+                check,
+                filename,
+                source,
+                Some(section),
+                subresults,
+                Duration::ZERO,
+            ));
+        }
+        let result = check.run(testable, context, Some(section))?;
+        // Errors may be transient, so try again next time
+        if !result.is_error() {
+            self.store(&key, &result.subresults);
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use fontspector_checkapi::{CheckFlags, CheckFnResult, CheckImplementation};
+
+    /// The number of times [counted] has run
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    fn counted(_t: &Testable, _context: &Context) -> CheckFnResult {
+        RUNS.fetch_add(1, Ordering::Relaxed);
+        Ok(Status::just_one_warn("counted", "The check ran"))
+    }
+
+    fn check() -> Check<'static> {
+        Check {
+            id: "counted",
+            title: "counted",
+            rationale: "",
+            proposal: "",
+            implementation: CheckImplementation::CheckOne(&counted),
+            hotfix: None,
+            fix_source: None,
+            applies_to: "TTF",
+            flags: CheckFlags::default(),
+            tags: &[],
+            aliases: &[],
+            _metadata: None,
+            version: 0,
+        }
+    }
+
+    #[test]
+    fn test_unchanged_results_are_reused() {
+        let dir = tempfile::tempdir().unwrap();
+        let check = check();
+        let context = Context::default();
+        let font = Testable::new_with_contents("Foo-Regular.ttf", b"font".to_vec());
+        let changed = Testable::new_with_contents("Foo-Regular.ttf", b"changed".to_vec());
+        let mut sourced = Testable::new_with_contents("Foo-Regular.ttf", b"font".to_vec());
+        sourced.source = Some(PathBuf::from("Foo.glyphs"));
+        for (testable, runs, hit) in [
+            (&font, 1, false),
+            (&font, 1, true),
+            (&changed, 2, false),
+            (&changed, 2, true),
+            // The source may change without the font changing
+            (&sourced, 3, false),
+            (&sourced, 4, false),
+        ] {
+            // Each run of fontspector opens the cache afresh
+            let mut cache = ResultCache::new(dir.path(), None);
+            let testable = TestableType::Single(testable);
+            cache.hash_testables(std::slice::from_ref(&testable));
+            let result = cache.run(&check, &testable, &context, "Tests").unwrap();
+            assert_eq!(result.subresults[0].code.as_deref(), Some("counted"));
+            assert_eq!(RUNS.load(Ordering::Relaxed), runs);
+            assert_eq!(cache.hits(), hit as usize);
+        }
+    }
+}
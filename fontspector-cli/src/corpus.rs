@@ -0,0 +1,319 @@
+//! Checking a large corpus of font families, such as the google/fonts repository
+//!
+//! In corpus mode, the families in a directory tree are discovered up front, but
+//! each family's files are only loaded while that family is being checked, so
+//! memory use depends on the largest family rather than the size of the corpus.
+//! The families can be split into shards to spread a run across several jobs,
+//! and the JSON reports of the shards merged afterwards.
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use fontspector_checkapi::{Profile, Registry, StatusCode, TestableCollection, TestableType};
+use indicatif::ProgressIterator;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    cache::ResultCache,
+    check_selection, general_context,
+    inputs::{google_fonts_family, group_directory, walk_directory, GOOGLE_FONTS_METADATA},
+    reporters::{terminal::TerminalReporter, RunResults},
+    run_checks, sources, Args,
+};
+
+/// One of a number of equal parts of a corpus, given on the command line as `N/M`
+#[derive(Debug, Clone, Copy)]
+pub struct Shard {
+    /// Which shard this is, counting from 1
+    index: usize,
+    /// The total number of shards
+    count: usize,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, count) = s
+            .split_once('/')
+            .ok_or_else(|| format!("Expected a shard of the form N/M, got {}", s))?;
+        let index: usize = index
+            .trim()
+            .parse()
+            .map_err(|e| format!("Bad shard number {}: {}", index, e))?;
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|e| format!("Bad shard count {}: {}", count, e))?;
+        if index == 0 || index > count {
+            return Err(format!("Shard number must be between 1 and {}", count));
+        }
+        Ok(Shard { index, count })
+    }
+}
+
+impl Shard {
+    /// Does the family at this position in the corpus belong to this shard?
+    ///
+    /// Families are dealt out in turn, so that shards get a similar mix of
+    /// large and small families.
+    fn includes(&self, position: usize) -> bool {
+        position % self.count == self.index - 1
+    }
+}
+
+/// Find the families in a corpus
+///
+/// Files are grouped by directory, in the same way as directories given on the
+/// command line. The families are sorted by directory, so that every job sharing
+/// out the same corpus sees them in the same order.
+fn discover_families(corpus: &Path, registry: &Registry) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let mut families: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+    for file in walk_directory(corpus, registry) {
+        families
+            .entry(group_directory(&file))
+            .or_default()
+            .push(file);
+    }
+    families
+}
+
+/// Load the files of one family
+fn load_family(
+    directory: &Path,
+    files: &[PathBuf],
+    registry: &Registry,
+) -> Result<TestableCollection, std::io::Error> {
+    let metadata = directory.join(GOOGLE_FONTS_METADATA);
+    let files = if files.contains(&metadata) {
+        google_fonts_family(&metadata, registry)
+    } else {
+        files.to_vec()
+    };
+    TestableCollection::from_filenames(&files, directory.to_str())
+}
+
+/// Check each family in a corpus in turn
+pub(crate) fn check_corpus(
+    corpus: &Path,
+    args: &Args,
+    registry: &Registry,
+    profile: &Profile,
+    configuration: &Map<String, Value>,
+    mut cache: Option<&mut ResultCache>,
+) -> (RunResults, usize) {
+    let families = discover_families(corpus, registry);
+    let total = families.len();
+    let families: Vec<_> = families
+        .into_iter()
+        .enumerate()
+        .filter(|(position, _)| args.shard.iter().all(|shard| shard.includes(*position)))
+        .map(|(_, family)| family)
+        .collect();
+    if !args.quiet {
+        match args.shard {
+            Some(shard) => println!(
+                "Checking {} of {} families in {} (shard {}/{})",
+                families.len(),
+                total,
+                corpus.display(),
+                shard.index,
+                shard.count
+            ),
+            None => println!("Checking {} families in {}", total, corpus.display()),
+        }
+    }
+
+    let selection = check_selection(args);
+    // A corpus has far too many results to keep them all, so only the problems are kept
+    let mut results = RunResults::default();
+    let mut count_of_checks = 0;
+    let progress = if args.quiet {
+        indicatif::ProgressBar::hidden()
+    } else {
+        indicatif::ProgressBar::new(families.len() as u64)
+    };
+    for (directory, files) in families.iter().progress_with(progress) {
+        let collection = match load_family(directory, files, registry) {
+            Ok(collection) => collection,
+            Err(e) => {
+                log::error!("Could not load files from {}: {}", directory.display(), e);
+                continue;
+            }
+        };
+        let mut collections = vec![collection];
+        sources::assign_sources(args, &mut collections);
+        let testables: Vec<TestableType> = collections
+            .iter()
+            .flat_map(|x| x.collection_and_files())
+            .collect();
+        if let Some(cache) = cache.as_deref_mut() {
+            cache.hash_testables(&testables);
+        }
+        let checkorder = profile.check_order(
+            &selection,
+            registry,
+            general_context(args),
+            configuration.clone(),
+            &testables,
+        );
+        log::info!(
+            "Running {} checks on {}",
+            checkorder.len(),
+            directory.display()
+        );
+        count_of_checks += checkorder.len();
+        results.extend_trimmed(run_checks(&checkorder, cache.as_deref(), false));
+    }
+    (results, count_of_checks)
+}
+
+/// Merge the JSON reports of several shards into one
+///
+/// The summaries are added together and the results of each file combined.
+/// The merged report is written to the `--json` file if one is given, and the
+/// summary is shown on the terminal. Returns the worst status found.
+pub(crate) fn merge_reports(reports: &[String], args: &Args, registry: &Registry) -> StatusCode {
+    let mut summary: HashMap<StatusCode, i32> = HashMap::new();
+    let mut results = Map::new();
+    let mut worst_status = StatusCode::Pass;
+    for filename in reports {
+        let report: Value = std::fs::read(filename)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| serde_json::from_slice(&contents).map_err(|e| e.to_string()))
+            .unwrap_or_else(|e| {
+                log::error!("Could not read report {}: {}", filename, e);
+                std::process::exit(1)
+            });
+        for (code, count) in report["summary"].as_object().into_iter().flatten() {
+            let Ok(code) = serde_json::from_value::<StatusCode>(json!(code)) else {
+                log::warn!("Unknown status {} in {}", code, filename);
+                continue;
+            };
+            *summary.entry(code).or_default() += count.as_i64().unwrap_or_default() as i32;
+        }
+        for (testable, sections) in report["results"].as_object().into_iter().flatten() {
+            let merged = results
+                .entry(testable.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+            for (section, checkresults) in sections.as_object().into_iter().flatten() {
+                let checkresults = checkresults.as_array().cloned().unwrap_or_default();
+                // Unless asked otherwise, experimental checks shouldn't break anyone's CI
+                for checkresult in checkresults.iter() {
+                    let experimental = checkresult["check_id"]
+                        .as_str()
+                        .is_some_and(|id| registry.is_experimental(id));
+                    if let Ok(status) =
+                        serde_json::from_value::<StatusCode>(checkresult["worst_status"].clone())
+                    {
+                        if args.experimental || !experimental {
+                            worst_status = worst_status.max(status);
+                        }
+                    }
+                }
+                if let Some(Value::Array(existing)) = merged.get_mut(section) {
+                    existing.extend(checkresults);
+                } else if let Some(merged) = merged.as_object_mut() {
+                    merged.insert(section.clone(), Value::Array(checkresults));
+                }
+            }
+        }
+    }
+
+    if let Some(jsonfile) = args.json.as_ref() {
+        let output = json!({
+            "summary": summary,
+            "results": results,
+        });
+        let report = serde_json::to_string_pretty(&output).unwrap_or_else(|e| {
+            log::error!("Error serializing JSON report: {:}", e);
+            std::process::exit(1);
+        });
+        std::fs::write(jsonfile, report).unwrap_or_else(|e| {
+            log::error!("Error writing JSON report to {:}: {:}", jsonfile, e);
+            std::process::exit(1);
+        });
+    }
+    println!(
+        "Merged {} report{} covering {} file{}",
+        reports.len(),
+        if reports.len() == 1 { "" } else { "s" },
+        results.len(),
+        if results.len() == 1 { "" } else { "s" }
+    );
+    TerminalReporter::summary_report(summary);
+    worst_status
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shard() {
+        let shard: Shard = "2/3".parse().unwrap();
+        assert_eq!((shard.index, shard.count), (2, 3));
+        let shard: Shard = " 1 / 1 ".parse().unwrap();
+        assert_eq!((shard.index, shard.count), (1, 1));
+        for bad in ["0/3", "4/3", "3", "a/3", "1/b", "1/0"] {
+            assert!(bad.parse::<Shard>().is_err(), "{} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_shards_share_out_every_family_once() {
+        let shards = (1..=3)
+            .map(|index| Shard { index, count: 3 })
+            .collect::<Vec<_>>();
+        for position in 0..10 {
+            assert_eq!(
+                shards.iter().filter(|s| s.includes(position)).count(),
+                1,
+                "family {} should be in exactly one shard",
+                position
+            );
+        }
+        // Families are dealt out in turn
+        assert_eq!(
+            (0..7)
+                .filter(|p| shards[0].includes(*p))
+                .collect::<Vec<_>>(),
+            vec![0, 3, 6]
+        );
+        assert_eq!(
+            (0..7)
+                .filter(|p| shards[2].includes(*p))
+                .collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+    }
+
+    #[test]
+    fn test_discover_families() {
+        let dir = tempfile::tempdir().unwrap();
+        let corpus = dir.path();
+        for file in [
+            "ofl/mada/Mada-Regular.ttf",
+            "ofl/mada/Mada-Bold.ttf",
+            "ofl/abeezee/ABeeZee-Regular.ttf",
+            "apache/roboto/Roboto[wght].ttf",
+        ] {
+            let path = corpus.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"").unwrap();
+        }
+        let families = discover_families(corpus, &Registry::new());
+        assert_eq!(
+            families.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                corpus.join("apache/roboto"),
+                corpus.join("ofl/abeezee"),
+                corpus.join("ofl/mada"),
+            ]
+        );
+        assert_eq!(families[&corpus.join("ofl/mada")].len(), 2);
+    }
+}
@@ -0,0 +1,150 @@
+//! Generate a static documentation site from the check registry
+use std::{collections::HashMap, path::Path};
+
+use fontspector_checkapi::Registry;
+use itertools::Itertools;
+use serde_json::{json, Value};
+use tera::{Context, Tera};
+
+/// Turn a check ID into something we can use as a filename
+fn slug(v: &Value, _options: &HashMap<String, Value>) -> tera::Result<Value> {
+    let v = v.as_str().unwrap_or("");
+    Ok(v.replace(['/', ' '], "_").into())
+}
+
+/// Render Markdown text (such as a check rationale) into HTML
+fn markdown(v: &Value, _options: &HashMap<String, Value>) -> tera::Result<Value> {
+    let v = v.as_str().unwrap_or("");
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(v));
+    Ok(html.into())
+}
+
+/// Render a template and write it to a file, exiting on failure
+fn write_page(tera: &Tera, template: &str, value: Value, path: &Path) {
+    let context = Context::from_serialize(value).unwrap_or_else(|e| {
+        log::error!("Error creating documentation context: {:}", e);
+        std::process::exit(1);
+    });
+    let rendered = tera.render(template, &context).unwrap_or_else(|e| {
+        log::error!("Error rendering {:}: {:?}", template, e);
+        std::process::exit(1);
+    });
+    std::fs::write(path, rendered).unwrap_or_else(|e| {
+        log::error!("Error writing {:}: {:}", path.display(), e);
+        std::process::exit(1);
+    });
+}
+
+/// Write a browsable catalogue of all known profiles and checks into a directory
+///
+/// One page is written per profile, listing its sections and checks, and one
+/// page per check, giving its rationale, the profiles which include it, and
+/// the configuration defaults and fixes available.
+pub(crate) fn write_docs_site(directory: &str, registry: &Registry) {
+    let mut tera = Tera::new("templates/docs/*").unwrap_or_else(|e| {
+        log::error!("Error parsing documentation templates: {:?}", e);
+        std::process::exit(1);
+    });
+    tera.register_filter("slug", slug);
+    tera.register_filter("markdown", markdown);
+
+    let root = Path::new(directory);
+    let check_dir = root.join("checks");
+    std::fs::create_dir_all(&check_dir).unwrap_or_else(|e| {
+        log::error!(
+            "Could not create directory {:}: {:}",
+            check_dir.display(),
+            e
+        );
+        std::process::exit(1);
+    });
+
+    let profiles = registry
+        .iter_profiles()
+        .sorted_by_key(|(name, _)| name.to_string())
+        .collect::<Vec<_>>();
+
+    // Which profiles (and sections of those profiles) include each check?
+    let mut appearances: HashMap<&str, Vec<Value>> = HashMap::new();
+    for (profile_name, profile) in profiles.iter() {
+        for (section, check_ids) in profile.sections.iter() {
+            for check_id in check_ids {
+                appearances
+                    .entry(check_id.as_str())
+                    .or_default()
+                    .push(json!({
+                        "profile": profile_name,
+                        "section": section,
+                        "configuration_defaults": profile.defaults(check_id),
+                    }));
+            }
+        }
+    }
+
+    let version = env!("CARGO_PKG_VERSION");
+    for (profile_name, profile) in profiles.iter() {
+        let sections = profile
+            .sections
+            .iter()
+            .map(|(section, check_ids)| {
+                let checks = check_ids
+                    .iter()
+                    .flat_map(|id| registry.checks.get(id))
+                    .map(|check| json!({"id": check.id, "title": check.title}))
+                    .collect::<Vec<_>>();
+                json!({"name": section, "checks": checks})
+            })
+            .collect::<Vec<_>>();
+        write_page(
+            &tera,
+            "profile.html",
+            json!({"version": version, "name": profile_name, "sections": sections}),
+            &root.join(format!("profile-{}.html", profile_name)),
+        );
+    }
+
+    let checks = registry.iter().sorted_by_key(|c| c.id).collect::<Vec<_>>();
+    for check in checks.iter() {
+        write_page(
+            &tera,
+            "check.html",
+            json!({
+                "version": version,
+                "root": "../",
+                "id": check.id,
+                "aliases": check.aliases,
+                "title": check.title,
+                "rationale": check.rationale,
+                "proposal": check.proposal,
+                "applies_to": check.applies_to,
+                "experimental": check.flags.experimental,
+                "tags": check.tags,
+                "hotfix": check.hotfix.is_some(),
+                "fix_source": check.fix_source.is_some(),
+                "profiles": appearances.get(check.id).cloned().unwrap_or_default(),
+            }),
+            &check_dir.join(format!("{}.html", check.id.replace(['/', ' '], "_"))),
+        );
+    }
+
+    write_page(
+        &tera,
+        "index.html",
+        json!({
+            "version": version,
+            "profiles": profiles.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            "checks": checks
+                .iter()
+                .map(|check| json!({"id": check.id, "title": check.title}))
+                .collect::<Vec<_>>(),
+        }),
+        &root.join("index.html"),
+    );
+    println!(
+        "Wrote documentation for {} profiles and {} checks to {}",
+        profiles.len(),
+        checks.len(),
+        root.display()
+    );
+}
@@ -0,0 +1,126 @@
+//! Listing and explaining the checks in a profile
+use std::collections::HashMap;
+
+use fontspector_checkapi::{Check, Profile, Registry};
+use serde_json::{json, Value};
+
+use crate::Args;
+
+/// Describe a check, as it is configured in the given profile, as a JSON object
+pub(crate) fn describe_check(check: &Check, profile: &Profile) -> Value {
+    json!({
+        "id": check.id,
+        "aliases": check.aliases,
+        "title": check.title,
+        "rationale": check.rationale,
+        "proposal": check.proposal,
+        "applies_to": check.applies_to,
+        "experimental": check.flags.experimental,
+        "tags": check.tags,
+        "hotfix": check.hotfix.is_some(),
+        "fix_source": check.fix_source.is_some(),
+        "configuration_defaults": profile.defaults(check.id),
+    })
+}
+
+/// List the checks in a profile, either as a table or as JSON
+pub(crate) fn list_checks(args: &Args, registry: &Registry, profile: &Profile) {
+    let mut checks_per_section = HashMap::new();
+    for (section, checks) in profile.sections.iter() {
+        let checks: Vec<_> = checks
+            .iter()
+            .flat_map(|check| registry.checks.get(check))
+            .map(|check| describe_check(check, profile))
+            .collect();
+        if checks.is_empty() {
+            continue;
+        }
+        checks_per_section.insert(section.clone(), checks);
+    }
+    if args.list_checks_json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&checks_per_section).unwrap_or("{}".to_string())
+        );
+    } else {
+        for (section, checks) in checks_per_section.iter() {
+            termimad::print_text(&format!("\n# {:}\n\n", section));
+            let mut table = "|Check ID|Title|\n|---|---|\n".to_string();
+            for check in checks {
+                #[allow(clippy::unwrap_used)] // We know these keys are present, we made them
+                table.push_str(&format!(
+                    "|{}|{}|\n",
+                    check.get("id").unwrap().as_str().unwrap(),
+                    check.get("title").unwrap().as_str().unwrap()
+                ));
+            }
+            termimad::print_text(&table);
+        }
+    }
+}
+
+/// Print everything we know about a single check
+pub(crate) fn explain_check(check_id: &str, registry: &Registry, profile: &Profile) {
+    let check = registry.get_check(check_id).unwrap_or_else(|| {
+        log::error!("Could not find check {:}", check_id);
+        std::process::exit(1);
+    });
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut text = format!("# {:}\n\n**{:}**\n\n", check.id, check.title);
+    text.push_str(check.rationale);
+    text.push_str("\n\n|||\n|---|---|\n");
+    if !check.aliases.is_empty() {
+        text.push_str(&format!("|Legacy IDs|{}|\n", check.aliases.join(", ")));
+    }
+    text.push_str(&format!("|Proposal|{}|\n", check.proposal));
+    text.push_str(&format!("|Applies to|{}|\n", check.applies_to));
+    text.push_str(&format!(
+        "|Runs on|{}|\n",
+        if check.runs_on_collection() {
+            "collection of files"
+        } else {
+            "single file"
+        }
+    ));
+    text.push_str(&format!(
+        "|Experimental|{}|\n",
+        yes_no(check.flags.experimental)
+    ));
+    if !check.tags.is_empty() {
+        text.push_str(&format!("|Tags|{}|\n", check.tags.join(", ")));
+    }
+    text.push_str(&format!("|Hotfix|{}|\n", yes_no(check.hotfix.is_some())));
+    text.push_str(&format!(
+        "|Source fix|{}|\n",
+        yes_no(check.fix_source.is_some())
+    ));
+    let sections: Vec<&String> = profile
+        .sections
+        .iter()
+        .filter(|(_, checks)| checks.iter().any(|c| c == check.id))
+        .map(|(section, _)| section)
+        .collect();
+    text.push_str(&format!(
+        "|Profile sections|{}|\n",
+        if sections.is_empty() {
+            "(not in this profile)".to_string()
+        } else {
+            sections
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    ));
+
+    let defaults = profile.defaults(check.id);
+    if !defaults.is_empty() {
+        text.push_str("\n## Configuration defaults\n\n|Key|Value|\n|---|---|\n");
+        let mut keys: Vec<&String> = defaults.keys().collect();
+        keys.sort();
+        for key in keys {
+            text.push_str(&format!("|{}|{}|\n", key, defaults[key]));
+        }
+    }
+    termimad::print_text(&text);
+}
@@ -0,0 +1,439 @@
+//! Turning command line inputs into collections of testables
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use fontspector_checkapi::{
+    collection_face, FileTypeConvert, Registry, Testable, TestableCollection, TTF,
+};
+use profile_googlefonts::declared_font_filenames;
+
+use crate::Args;
+
+// As a special case for Google fonts, all files in an article/
+// directory are associated with the parent's group.
+const COLLAPSED_SUBDIRECTORIES: [&str; 1] = ["article"];
+
+/// The metadata file which marks a Google Fonts family directory
+pub(crate) const GOOGLE_FONTS_METADATA: &str = "METADATA.pb";
+
+/// Licence and description files which accompany the fonts in a Google Fonts family directory
+const GOOGLE_FONTS_FAMILY_FILES: [&str; 4] = [
+    "OFL.txt",
+    "LICENSE.txt",
+    "UFL.txt",
+    "DESCRIPTION.en_us.html",
+];
+
+/// Separates the path of an archive from the path of a file inside it
+const ARCHIVE_SEPARATOR: &str = "!/";
+
+/// Is this file inside an archive, rather than on disk?
+pub(crate) fn is_archive_member(filename: &str) -> bool {
+    filename.contains(ARCHIVE_SEPARATOR)
+}
+
+/// The directory whose collection a file belongs to
+pub(crate) fn group_directory(file: &Path) -> PathBuf {
+    let parent = file.parent().unwrap_or(Path::new(""));
+    if COLLAPSED_SUBDIRECTORIES
+        .iter()
+        .any(|subdir| parent.ends_with(subdir))
+    {
+        parent.parent().unwrap_or(Path::new("")).to_owned()
+    } else {
+        parent.to_owned()
+    }
+}
+
+/// Expand a zip archive into testables, grouped by their directory within the archive
+///
+/// Files are named relative to the archive, e.g. `family.zip!/fonts/ttf/Foo-Regular.ttf`.
+fn expand_zip(archive_path: &Path) -> Result<HashMap<PathBuf, Vec<Testable>>, String> {
+    let file = std::fs::File::open(archive_path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let mut groups: HashMap<PathBuf, Vec<Testable>> = HashMap::new();
+    for index in 0..archive.len() {
+        let mut member = archive.by_index(index).map_err(|e| e.to_string())?;
+        if member.is_dir() {
+            continue;
+        }
+        let Some(inner_path) = member.enclosed_name() else {
+            log::warn!(
+                "Skipping unsafe path {} in {}",
+                member.name(),
+                archive_path.display()
+            );
+            continue;
+        };
+        if inner_path.starts_with("__MACOSX") {
+            continue;
+        }
+        let mut contents = Vec::with_capacity(member.size() as usize);
+        member
+            .read_to_end(&mut contents)
+            .map_err(|e| format!("Could not read {}: {}", member.name(), e))?;
+        let filename = PathBuf::from(format!(
+            "{}{}{}",
+            archive_path.display(),
+            ARCHIVE_SEPARATOR,
+            inner_path.display()
+        ));
+        groups
+            .entry(group_directory(&filename))
+            .or_default()
+            .push(Testable::new_with_contents(filename, contents));
+    }
+    Ok(groups)
+}
+
+/// Is this path something fontspector knows how to test?
+///
+/// As well as files, this can be a directory such as a UFO font source.
+fn is_known_path(path: &Path, registry: &Registry) -> bool {
+    registry.is_known_file(&Testable::new_with_contents(path, vec![]))
+}
+
+/// Find the files in a directory which fontspector knows how to test
+///
+/// Hidden files and anything matched by a `.gitignore` file are skipped.
+/// Directories which are testable in themselves, such as UFOs, are included.
+pub(crate) fn walk_directory(directory: &Path, registry: &Registry) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = ignore::WalkBuilder::new(directory)
+        .require_git(false)
+        .build()
+        .filter_map(|entry| {
+            entry
+                .map_err(|e| log::warn!("Could not read {}: {}", directory.display(), e))
+                .ok()
+        })
+        .filter(|entry| entry.depth() > 0)
+        .map(|entry| entry.into_path())
+        .filter(|path| is_known_path(path, registry))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Find the files making up a Google Fonts family directory
+///
+/// These are the METADATA.pb file, the fonts it declares, the licence and
+/// description files and everything in the `article` subdirectory. Fonts which
+/// are in the directory but not declared in the metadata are included too, so
+/// that `googlefonts/metadata/consistent_with_fonts` can report them.
+pub(crate) fn google_fonts_family(metadata: &Path, registry: &Registry) -> Vec<PathBuf> {
+    let directory = metadata.parent().unwrap_or(Path::new(""));
+    let declared = match std::fs::read_to_string(metadata)
+        .map_err(|e| e.to_string())
+        .and_then(|mdpb| declared_font_filenames(&mdpb))
+    {
+        Ok(declared) => declared,
+        Err(e) => {
+            log::warn!("Could not read {}: {}", metadata.display(), e);
+            return walk_directory(directory, registry);
+        }
+    };
+    let mut files = vec![metadata.to_path_buf()];
+    // Declared fonts which are missing are reported by googlefonts/metadata/consistent_with_fonts
+    files.extend(
+        declared
+            .iter()
+            .map(String::as_str)
+            .chain(GOOGLE_FONTS_FAMILY_FILES)
+            .map(|f| directory.join(f))
+            .filter(|path| path.is_file()),
+    );
+    files.extend(
+        ignore::WalkBuilder::new(directory.join("article"))
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+            .map(|entry| entry.into_path()),
+    );
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return files;
+    };
+    let mut undeclared = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| TTF.applies(&Testable::new_with_contents(path, vec![])))
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| !declared.iter().any(|f| name == f.as_str()))
+        })
+        .collect::<Vec<_>>();
+    undeclared.sort();
+    for font in undeclared {
+        log::warn!(
+            "{} is not declared in {}",
+            font.display(),
+            metadata.display()
+        );
+        files.push(font);
+    }
+    files
+}
+
+/// Regroup the fonts in a set of collections by their typographic family name
+///
+/// Files which are not fonts stay in a collection for their original directory.
+fn group_by_family(collections: Vec<TestableCollection>) -> Vec<TestableCollection> {
+    let mut families: BTreeMap<String, Vec<Testable>> = BTreeMap::new();
+    let mut others = vec![];
+    for collection in collections {
+        let mut rest = vec![];
+        for testable in collection.testables {
+            // Faces of font collections are recreated along with their new collection
+            if collection_face(&testable.filename).is_some() {
+                continue;
+            }
+            let family = TTF
+                .from_testable(&testable)
+                .and_then(|font| font.typographic_familyname());
+            match family {
+                Some(family) => families.entry(family).or_default().push(testable),
+                None => rest.push(testable),
+            }
+        }
+        if !rest.is_empty() {
+            others.push(TestableCollection::from_testables(
+                rest,
+                Some(collection.directory),
+            ));
+        }
+    }
+    families
+        .into_iter()
+        .map(|(family, testables)| TestableCollection::from_testables(testables, Some(family)))
+        .chain(others)
+        .collect()
+}
+
+/// Group the input files into collections
+///
+/// We create one collection for each directory of input files; directories
+/// given on the command line are searched for files we know how to test, and
+/// zip archives are expanded in memory and create one collection for each
+/// directory inside the archive. A Google Fonts family directory, or its
+/// METADATA.pb file, stands for the files which the metadata describes. With
+/// `--group-by-family`, fonts are instead grouped by their family name.
+///
+/// Files on disk are not read here, except to find family names when grouping
+/// by family; otherwise their contents are loaded when a check first needs them.
+pub(crate) fn group_inputs(args: &Args, registry: &Registry) -> Vec<TestableCollection> {
+    let mut collections = vec![];
+    let mut on_disk: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+    for file in args
+        .inputs
+        .iter()
+        .map(PathBuf::from)
+        .flat_map(|x| {
+            if x.is_dir() && is_known_path(&x, registry) {
+                vec![x]
+            } else if x.is_dir() && x.join(GOOGLE_FONTS_METADATA).is_file() {
+                google_fonts_family(&x.join(GOOGLE_FONTS_METADATA), registry)
+            } else if x.is_dir() {
+                walk_directory(&x, registry)
+            } else if x.file_name().is_some_and(|n| n == GOOGLE_FONTS_METADATA) {
+                google_fonts_family(&x, registry)
+            } else {
+                vec![x]
+            }
+        })
+        .filter(|x| x.exists())
+        .filter(|x| x.parent().is_some())
+    {
+        if file
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+        {
+            let groups = expand_zip(&file).unwrap_or_else(|e| {
+                log::error!("Could not read zip file {:?}: {:}", file, e);
+                std::process::exit(1)
+            });
+            collections.extend(groups.into_iter().map(|(directory, testables)| {
+                TestableCollection::from_testables(
+                    testables,
+                    Some(directory.to_string_lossy().to_string()),
+                )
+            }));
+        } else {
+            // A family directory may have been given along with files inside it
+            let group = on_disk.entry(group_directory(&file)).or_default();
+            if !group.contains(&file) {
+                group.push(file);
+            }
+        }
+    }
+    collections.extend(on_disk.into_iter().map(|(directory, group)| {
+        TestableCollection::from_filenames(&group, directory.to_str()).unwrap_or_else(|e| {
+            log::error!("Could not load files from {:?}: {:}", group[0].parent(), e);
+            std::process::exit(1)
+        })
+    }));
+    if args.group_by_family {
+        group_by_family(collections)
+    } else {
+        collections
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::io::Write;
+
+    /// A file from the Python test suite's data
+    fn test_file(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../fontspector-py/data/test")
+            .join(name)
+    }
+
+    #[test]
+    fn test_expand_zip() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = tempdir.path();
+        let archive = dir.join("family.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        zip.add_directory("fonts/ttf/", options).unwrap();
+        for (name, contents) in [
+            ("fonts/ttf/Foo-Regular.ttf", "regular"),
+            ("fonts/ttf/Foo-Bold.ttf", "bold"),
+            ("OFL.txt", "licence"),
+            ("__MACOSX/fonts/ttf/._Foo-Regular.ttf", "resource fork"),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let groups = expand_zip(&archive).unwrap();
+        let archive_name = archive.display().to_string();
+        assert_eq!(groups.len(), 2);
+        let fonts = &groups[&PathBuf::from(format!("{}!/fonts/ttf", archive_name))];
+        assert_eq!(
+            fonts.iter().map(|t| t.filename.clone()).collect::<Vec<_>>(),
+            vec![
+                PathBuf::from(format!("{}!/fonts/ttf/Foo-Regular.ttf", archive_name)),
+                PathBuf::from(format!("{}!/fonts/ttf/Foo-Bold.ttf", archive_name)),
+            ]
+        );
+        assert_eq!(&fonts[1].contents[..], b"bold");
+        assert!(is_archive_member(&fonts[0].filename.to_string_lossy()));
+        let top = &groups[&PathBuf::from(format!("{}!", archive_name))];
+        assert_eq!(top.len(), 1);
+        assert_eq!(&top[0].contents[..], b"licence");
+    }
+
+    #[test]
+    fn test_walk_directory() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = tempdir.path();
+        for name in [
+            "Foo-Regular.ttf",
+            "notes.txt",
+            ".hidden/Foo-Regular.ttf",
+            "build/Foo-Regular.ttf",
+            "sub/Foo-Italic.ttf",
+        ] {
+            let path = dir.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"").unwrap();
+        }
+        std::fs::write(dir.join(".gitignore"), "build/\n").unwrap();
+        assert_eq!(
+            walk_directory(dir, &Registry::new()),
+            vec![dir.join("Foo-Regular.ttf"), dir.join("sub/Foo-Italic.ttf")]
+        );
+    }
+
+    #[test]
+    fn test_group_by_family() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = tempdir.path();
+        for font in [
+            "mada/Mada-Regular.ttf",
+            "mada/Mada-Bold.ttf",
+            "abeezee/ABeeZee-Regular.ttf",
+        ] {
+            let path = test_file(font);
+            std::fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+        }
+        std::fs::write(dir.join("Broken.ttf"), b"not a font").unwrap();
+        let registry = Registry::new();
+        let input = dir.display().to_string();
+
+        let args = Args::parse_from(["fontspector", input.as_str()]);
+        let collections = group_inputs(&args, &registry);
+        assert_eq!(collections.len(), 1);
+        assert_eq!(collections[0].testables.len(), 4);
+
+        let args = Args::parse_from(["fontspector", "--group-by-family", input.as_str()]);
+        let collections = group_inputs(&args, &registry);
+        let families = collections
+            .iter()
+            .map(|c| (c.directory.as_str(), c.testables.len()))
+            .collect::<Vec<_>>();
+        // Files which aren't fonts stay with their directory
+        assert_eq!(
+            families,
+            vec![("ABeeZee", 1), ("Mada", 2), (input.as_str(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_google_fonts_family() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = tempdir.path();
+        for file in [
+            "abeezee/METADATA.pb",
+            "abeezee/ABeeZee-Italic.ttf",
+            "abeezee/ABeeZee-Regular.ttf",
+            "abeezee/DESCRIPTION.en_us.html",
+            "abeezee/FONTLOG.txt",
+            "abeezee/OFL.txt",
+            "mada/Mada-Regular.ttf",
+        ] {
+            let path = test_file(file);
+            std::fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+        }
+        std::fs::create_dir(dir.join("article")).unwrap();
+        std::fs::write(dir.join("article/ARTICLE.en_us.html"), b"").unwrap();
+        let registry = Registry::new();
+
+        let expected = [
+            "METADATA.pb",
+            "ABeeZee-Regular.ttf",
+            "ABeeZee-Italic.ttf",
+            "OFL.txt",
+            "DESCRIPTION.en_us.html",
+            "article/ARTICLE.en_us.html",
+            // Undeclared fonts are included, to be reported by the metadata checks
+            "Mada-Regular.ttf",
+        ]
+        .map(|file| dir.join(file));
+        let metadata = dir.join(GOOGLE_FONTS_METADATA);
+        assert_eq!(google_fonts_family(&metadata, &registry), expected);
+
+        // The directory and the metadata file both stand for the whole family
+        for input in [dir, metadata.as_path()] {
+            let args = Args::parse_from(["fontspector".to_string(), input.display().to_string()]);
+            let collections = group_inputs(&args, &registry);
+            assert_eq!(collections.len(), 1);
+            assert_eq!(
+                collections[0]
+                    .testables
+                    .iter()
+                    .map(|t| t.filename.clone())
+                    .collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+}
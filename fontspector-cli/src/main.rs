@@ -2,22 +2,30 @@
 //! Quality control for OpenType fonts
 
 mod args;
+mod cache;
+mod corpus;
+mod docsite;
+mod explain;
+mod inputs;
 mod reporters;
+mod sources;
 
 use std::{
     collections::HashMap,
-    path::PathBuf,
+    path::Path,
     time::{Duration, Instant},
 };
 
 use args::Args;
+use cache::ResultCache;
 use clap::Parser;
 use fontbakery_bridge::FontbakeryBridge;
 use fontspector_checkapi::{
-    Check, CheckResult, Context, FixResult, HotfixFunction, Plugin, Registry, StatusCode, Testable,
-    TestableCollection, TestableType,
+    collection_face, Check, CheckResult, CheckSelection, Context, FixResult, HotfixFunction,
+    Plugin, Registry, SourceFixFunction, StatusCode, Testable, TestableType, WebFont,
 };
 use itertools::Either;
+use profile_designspace::DesignspaceProfile;
 use profile_googlefonts::GoogleFonts;
 use profile_opentype::OpenType;
 use profile_universal::Universal;
@@ -25,7 +33,7 @@ use reporters::{
     json::JsonReporter, markdown::MarkdownReporter, terminal::TerminalReporter, Reporter,
     RunResults,
 };
-use serde_json::{json, Map};
+use serde_json::Map;
 
 #[cfg(not(debug_assertions))]
 use indicatif::ParallelProgressIterator;
@@ -34,10 +42,6 @@ use indicatif::ProgressIterator;
 #[cfg(not(debug_assertions))]
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-// As a special case for Google fonts, all files in an article/
-// directory are associated with the parent's group.
-const COLLAPSED_SUBDIRECTORIES: [&str; 1] = ["article"];
-
 fn main() {
     let start_time = Instant::now();
 
@@ -86,6 +90,11 @@ fn main() {
     GoogleFonts
         .register(&mut registry)
         .expect("Couldn't register googlefonts profile, fontspector bug");
+
+    #[allow(clippy::expect_used)] // If this fails, I *want* to panic
+    DesignspaceProfile
+        .register(&mut registry)
+        .expect("Couldn't register designspace profile, fontspector bug");
     for plugin_path in args.plugins.iter() {
         if let Err(err) = registry.load_plugin(plugin_path) {
             log::error!("Could not load plugin {:}: {:}", plugin_path, err);
@@ -99,128 +108,105 @@ fn main() {
     });
 
     if args.list_checks || args.list_checks_json {
-        let mut checks_per_section = HashMap::new();
-        for (section, checks) in profile.sections.iter() {
-            let checks: Vec<_> = checks
-                .iter()
-                .flat_map(|check| registry.checks.get(check))
-                .map(|check| json!({ "id": check.id, "title": check.title }))
-                .collect();
-            if checks.is_empty() {
-                continue;
-            }
-            checks_per_section.insert(section.clone(), checks);
-        }
-        if args.list_checks_json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&checks_per_section).unwrap_or("{}".to_string())
-            );
-        } else {
-            for (section, checks) in checks_per_section.iter() {
-                termimad::print_text(&format!("\n# {:}\n\n", section));
-                let mut table = "|Check ID|Title|\n|---|---|\n".to_string();
-                for check in checks {
-                    #[allow(clippy::unwrap_used)] // We know these keys are present, we made them
-                    table.push_str(&format!(
-                        "|{}|{}|\n",
-                        check.get("id").unwrap().as_str().unwrap(),
-                        check.get("title").unwrap().as_str().unwrap()
-                    ));
-                }
-                termimad::print_text(&table);
-            }
-        }
+        explain::list_checks(&args, &registry, profile);
         std::process::exit(0);
     }
-    // We create one collection for each set of testable files in a directory.
-    // So let's group the inputs per directory, and then map them into a FontCollection
-    let grouped_inputs = group_inputs(&args);
 
-    if grouped_inputs.is_empty() {
-        log::error!("No input files");
-        std::process::exit(1);
+    if let Some(check_id) = args.explain.as_ref() {
+        explain::explain_check(check_id, &registry, profile);
+        std::process::exit(0);
     }
 
-    let testables: Vec<TestableType> = grouped_inputs
-        .iter()
-        .flat_map(|x| x.collection_and_files())
-        .collect();
+    if let Some(directory) = args.docs_site.as_ref() {
+        docsite::write_docs_site(directory, &registry);
+        std::process::exit(0);
+    }
 
-    if testables.is_empty() {
-        log::error!("No input files");
-        std::process::exit(1);
+    if let Some(reports) = args.merge_reports.as_ref() {
+        let worst_status = corpus::merge_reports(reports, &args, &registry);
+        if worst_status >= args.error_code_on {
+            std::process::exit(1);
+        }
+        std::process::exit(0);
     }
 
     // Load configuration
     let configuration: Map<String, serde_json::Value> = load_configuration(&args);
 
-    // Establish a check order
-    let checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
-        &args.checkid,
-        &args.exclude_checkid,
-        &registry,
-        Context {
-            skip_network: args.skip_network,
-            network_timeout: Some(10), // XXX
-            configuration: Map::new(),
-            check_metadata: serde_json::Value::Null,
-            full_lists: args.full_lists,
-            cache: Default::default(),
-        },
-        configuration,
-        &testables,
-    );
-
-    // The testables are the collection object plus the files; only count the files.
-    let count_of_files = testables.iter().filter(|x| x.is_single()).count();
-    let count_of_families = testables.len() - count_of_files;
-
-    // if !args.quiet {
-    println!(
-        "Running {:} check{} on {} file{} in {} famil{}",
-        checkorder.len(),
-        if checkorder.len() == 1 { "" } else { "s" },
-        count_of_files,
-        if count_of_files == 1 { "" } else { "s" },
-        count_of_families,
-        if count_of_families == 1 { "y" } else { "ies" }
-    );
-    // }
-
-    // Run all the things! Check all the fonts!
-
-    // Do this in parallel for release, serial for debug
-    #[cfg(debug_assertions)]
-    let checkorder_iterator = if args.quiet {
-        Either::Left(checkorder.iter())
-    } else {
-        Either::Right(checkorder.iter().progress())
-    };
-    #[cfg(not(debug_assertions))]
-    let checkorder_iterator = if checkorder.clone().len() > 100_000 && !args.quiet {
-        Either::Left(checkorder.par_iter().progress())
-    } else {
-        Either::Right(checkorder.par_iter())
-    };
-
-    let mut results: RunResults = checkorder_iterator
-        .map(|(sectionname, testable, check, context)| {
-            (
-                testable,
-                check,
-                check.run(testable, context, Some(sectionname)),
+    let mut cache = args
+        .cache_dir
+        .as_ref()
+        .map(|dir| ResultCache::new(Path::new(dir), args.cache_network_ttl));
+
+    let (mut results, count_of_checks): (RunResults, usize) =
+        if let Some(corpus) = args.corpus.as_ref() {
+            corpus::check_corpus(
+                Path::new(corpus),
+                &args,
+                &registry,
+                profile,
+                &configuration,
+                cache.as_mut(),
             )
-        })
-        .filter_map(|(_, _, result)| result)
-        .collect::<Vec<CheckResult>>()
-        .into();
+        } else {
+            // We create one collection for each set of testable files in a directory.
+            // So let's group the inputs per directory, and then map them into a FontCollection
+            let mut grouped_inputs = inputs::group_inputs(&args, &registry);
+            sources::assign_sources(&args, &mut grouped_inputs);
+
+            if grouped_inputs.is_empty() {
+                log::error!("No input files");
+                std::process::exit(1);
+            }
+
+            let testables: Vec<TestableType> = grouped_inputs
+                .iter()
+                .flat_map(|x| x.collection_and_files())
+                .collect();
+
+            if testables.is_empty() {
+                log::error!("No input files");
+                std::process::exit(1);
+            }
+
+            if let Some(cache) = cache.as_mut() {
+                cache.hash_testables(&testables);
+            }
+
+            // Establish a check order
+            let checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
+                &check_selection(&args),
+                &registry,
+                general_context(&args),
+                configuration,
+                &testables,
+            );
+
+            // The testables are the collection object plus the files; only count the files.
+            let count_of_files = testables.iter().filter(|x| x.is_single()).count();
+            let count_of_families = testables.len() - count_of_files;
+
+            // if !args.quiet {
+            println!(
+                "Running {:} check{} on {} file{} in {} famil{}",
+                checkorder.len(),
+                if checkorder.len() == 1 { "" } else { "s" },
+                count_of_files,
+                if count_of_files == 1 { "" } else { "s" },
+                count_of_families,
+                if count_of_families == 1 { "y" } else { "ies" }
+            );
+            // }
+
+            let results = run_checks(&checkorder, cache.as_ref(), !args.quiet);
+            (results.into(), checkorder.len())
+        };
 
     if args.hotfix || args.fix_sources {
         try_fixing_stuff(&mut results, &args, &registry);
     }
 
-    let worst_status = results.worst_status();
+    let worst_status = results.exit_status(&registry, args.experimental);
 
     let mut reporters: Vec<Box<dyn Reporter>> = vec![];
     if !args.quiet {
@@ -240,9 +226,12 @@ fn main() {
     if !args.quiet {
         println!(
             "Ran {} checks in {:.3}s",
-            checkorder.len(),
+            count_of_checks,
             start_time.elapsed().as_secs_f32()
         );
+        if let Some(cache) = cache.as_ref() {
+            println!("Reused {} cached results", cache.hits());
+        }
     }
     TerminalReporter::summary_report(results.summary());
 
@@ -267,42 +256,62 @@ fn main() {
     }
 }
 
-// Group each file into a set per directory, and wrap that in a TestableCollection.
-// It feels like this takes an inordinately long time, but remember that this also
-// reads the input files.
-fn group_inputs(args: &Args) -> Vec<TestableCollection> {
-    let inputs = args
-        .inputs
-        .iter()
-        .map(PathBuf::from)
-        .filter(|x| x.is_file())
-        .filter(|x| x.parent().is_some());
-    inputs
-        .map(|file| {
-            #[allow(clippy::unwrap_used)] // We tested for parent
-            if COLLAPSED_SUBDIRECTORIES
-                .iter()
-                .any(|subdir| file.parent().unwrap().ends_with(subdir))
-            {
-                (file.parent().unwrap().parent().unwrap().to_owned(), file)
-            } else {
-                (file.parent().unwrap().to_owned(), file)
-            }
-        })
-        .fold(
-            HashMap::new(),
-            |mut acc: HashMap<PathBuf, Vec<PathBuf>>, (directory, file)| {
-                acc.entry(directory).or_default().push(file);
-                acc
-            },
-        )
-        .into_iter()
-        .map(|(directory, group)| {
-            TestableCollection::from_filenames(&group, directory.to_str()).unwrap_or_else(|e| {
-                log::error!("Could not load files from {:?}: {:}", group[0].parent(), e);
-                std::process::exit(1)
-            })
+/// The checks chosen on the command line
+fn check_selection(args: &Args) -> CheckSelection {
+    CheckSelection {
+        include_checks: args.checkid.clone(),
+        exclude_checks: args.exclude_checkid.clone(),
+        include_tags: args.tag.clone(),
+        exclude_tags: args.exclude_tag.clone(),
+        include_sections: args.section.clone(),
+        exclude_sections: args.exclude_section.clone(),
+        exclude_experimental: args.no_experimental,
+    }
+}
+
+/// The context shared by every check, before it is specialized for each check
+fn general_context(args: &Args) -> Context {
+    Context {
+        skip_network: args.skip_network,
+        network_timeout: Some(10), // XXX
+        configuration: Map::new(),
+        check_metadata: serde_json::Value::Null,
+        ..Default::default()
+    }
+}
+
+/// Run all the things! Check all the fonts!
+///
+/// Checks are run in parallel for release builds, and serially for debug builds.
+fn run_checks(
+    checkorder: &[(String, &TestableType, &Check, Context)],
+    cache: Option<&ResultCache>,
+    show_progress: bool,
+) -> Vec<CheckResult> {
+    #[cfg(debug_assertions)]
+    let checkorder_iterator = if show_progress {
+        Either::Right(checkorder.iter().progress())
+    } else {
+        Either::Left(checkorder.iter())
+    };
+    #[cfg(not(debug_assertions))]
+    let checkorder_iterator = if checkorder.len() > 100_000 && show_progress {
+        Either::Left(checkorder.par_iter().progress())
+    } else {
+        Either::Right(checkorder.par_iter())
+    };
+
+    checkorder_iterator
+        .map(|(sectionname, testable, check, context)| {
+            let result = match cache {
+                Some(cache) => cache.run(check, testable, context, sectionname),
+                None => check.run(testable, context, Some(sectionname)),
+            };
+            // Let the testable's cached values go once its last check is done
+            context.typed_cache.check_finished();
+            (testable, check, result)
         })
+        .filter_map(|(_, _, result)| result)
         .collect()
 }
 
@@ -338,9 +347,9 @@ fn try_fixing_stuff(results: &mut RunResults, args: &Args, registry: &Registry)
         .filter(|x| x.worst_status() >= StatusCode::Fail)
         .collect::<Vec<_>>();
     // Group the fixes by filename because we want to provide testables
-    // // let mut fix_sources = HashMap::new();
     let mut fix_binaries: HashMap<String, Vec<(&HotfixFunction, &mut CheckResult)>> =
         HashMap::new();
+    let mut fix_sources: Vec<(&SourceFixFunction, &mut CheckResult)> = vec![];
     for result in failed_checks.into_iter() {
         let Some(check) = registry.checks.get(&result.check_id) else {
             log::warn!(
@@ -349,6 +358,15 @@ fn try_fixing_stuff(results: &mut RunResults, args: &Args, registry: &Registry)
             );
             continue;
         };
+        // Fixing the source is preferred to patching up the binary
+        if args.fix_sources {
+            if let Some(fix_source) = check.fix_source {
+                if result.filename.is_some() {
+                    fix_sources.push((fix_source, result));
+                    continue;
+                }
+            }
+        }
         if args.hotfix && result.filename.is_some() && check.hotfix.is_some() {
             #[allow(clippy::unwrap_used)] // We know this is Some
             fix_binaries
@@ -358,11 +376,41 @@ fn try_fixing_stuff(results: &mut RunResults, args: &Args, registry: &Registry)
         }
     }
 
+    for (fix, result) in fix_sources.into_iter() {
+        result.sourcefix_result = Some(try_fixing_source(fix, result));
+    }
+
     for (file, fixes) in fix_binaries.into_iter() {
+        if collection_face(Path::new(&file)).is_some() {
+            for (_, result) in fixes.into_iter() {
+                result.hotfix_result = Some(FixResult::FixError(
+                    "Faces of font collections can't be hotfixed individually".to_string(),
+                ));
+            }
+            continue;
+        }
+        if inputs::is_archive_member(&file) {
+            for (_, result) in fixes.into_iter() {
+                result.hotfix_result = Some(FixResult::FixError(
+                    "Files inside archives can't be hotfixed".to_string(),
+                ));
+            }
+            continue;
+        }
         let mut testable = Testable::new(&file).unwrap_or_else(|e| {
             log::error!("Could not load files from {:?}: {:}", file, e);
             std::process::exit(1)
         });
+        if WebFont::is_webfont(&testable.contents) {
+            // Hotfixes work on the decompressed font, and we can't compress it again
+            for (_, result) in fixes.into_iter() {
+                result.hotfix_result = Some(FixResult::FixError(
+                    "Web fonts can't be hotfixed; fix the font they were compressed from"
+                        .to_string(),
+                ));
+            }
+            continue;
+        }
         let mut modified = false;
         for (fix, result) in fixes.into_iter() {
             result.hotfix_result = match fix(&mut testable) {
@@ -382,3 +430,20 @@ fn try_fixing_stuff(results: &mut RunResults, args: &Args, registry: &Registry)
         }
     }
 }
+
+fn try_fixing_source(fix: &SourceFixFunction, result: &CheckResult) -> FixResult {
+    let (Some(file), Some(source)) = (&result.filename, &result.source_filename) else {
+        return FixResult::FixError(
+            "Could not work out which source this font was built from".to_string(),
+        );
+    };
+    // The fix is handed the binary, with its source, so it knows which file to edit
+    let testable = match Testable::new_with_source(file, source) {
+        Ok(testable) => testable,
+        Err(e) => return FixResult::FixError(format!("Could not load {}: {}", file, e)),
+    };
+    match fix(&testable) {
+        Ok(_) => FixResult::Fixed,
+        Err(e) => FixResult::FixError(e),
+    }
+}
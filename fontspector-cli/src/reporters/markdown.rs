@@ -1,18 +1,32 @@
-use std::collections::HashMap;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
 
 use crate::{
     reporters::{Reporter, RunResults},
     Args,
 };
-use fontspector_checkapi::Registry;
+use fontspector_checkapi::{prelude::bullet_list, CheckResult, Registry};
 use serde_json::json;
 use tera::{Context, Tera, Value};
 
 pub(crate) struct MarkdownReporter {
     filename: String,
+    /// Where glyph pictures are written, next to the report
+    images_dir: PathBuf,
     tera: Tera,
 }
 
+/// The file a glyph picture is written to, named after its contents so that
+/// identical pictures are only written once
+fn image_filename(svg: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    svg.hash(&mut hasher);
+    format!("{:016x}.svg", hasher.finish())
+}
+
 fn percent_of(v: &Value, options: &HashMap<String, Value>) -> tera::Result<Value> {
     let v = v.as_f64().unwrap_or(0.0);
     let total = options
@@ -45,8 +59,30 @@ fn emoticon(v: &Value, _options: &HashMap<String, Value>) -> tera::Result<Value>
     Ok(v.into())
 }
 
+/// Render a status's items as a bullet list nested under the status
+fn items(v: &Value, options: &HashMap<String, Value>) -> tera::Result<Value> {
+    let full = options
+        .get("full")
+        .and_then(|full| full.as_bool())
+        .unwrap_or(false);
+    let items = v.as_array().into_iter().flatten().map(|item| match item {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    });
+    Ok(bullet_list(items, full).replace('\n', "\n  ").into())
+}
+
 impl MarkdownReporter {
     pub fn new(filename: &str) -> Self {
+        // GitHub strips inline SVG from Markdown, so pictures are linked as files instead
+        let images_dirname = format!(
+            "{}-images",
+            Path::new(filename)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy())
+                .unwrap_or("report".into())
+        );
+        let images_dir = Path::new(filename).with_file_name(&images_dirname);
         let mut tera = Tera::new("templates/markdown/*").unwrap_or_else(|e| {
             log::error!("Error parsing Markdown templates: {:?}", e);
             std::process::exit(1);
@@ -54,6 +90,14 @@ impl MarkdownReporter {
         tera.register_filter("percent", percent_of);
         tera.register_filter("unindent", unindent);
         tera.register_filter("emoticon", emoticon);
+        tera.register_filter("items", items);
+        tera.register_filter(
+            "image_file",
+            move |v: &Value, _options: &HashMap<String, Value>| -> tera::Result<Value> {
+                let svg = v.as_str().unwrap_or("");
+                Ok(format!("{}/{}", images_dirname, image_filename(svg)).into())
+            },
+        );
 
         tera.register_tester("omitted", |_value: Option<&Value>, _params: &[Value]| {
             // XXX
@@ -62,6 +106,28 @@ impl MarkdownReporter {
         Self {
             tera,
             filename: filename.to_string(),
+            images_dir,
+        }
+    }
+
+    /// Write out the glyph pictures of the reported checks, for the report to link to
+    fn write_images<'a>(&self, results: impl Iterator<Item = &'a CheckResult>) {
+        for image in results
+            .flat_map(|result| result.subresults.iter())
+            .flat_map(|subresult| subresult.images.iter())
+        {
+            std::fs::create_dir_all(&self.images_dir)
+                .and_then(|_| {
+                    std::fs::write(self.images_dir.join(image_filename(&image.svg)), &image.svg)
+                })
+                .unwrap_or_else(|e| {
+                    log::error!(
+                        "Error writing glyph pictures to {:}: {:}",
+                        self.images_dir.display(),
+                        e
+                    );
+                    std::process::exit(1);
+                });
         }
     }
 }
@@ -70,9 +136,10 @@ impl Reporter for MarkdownReporter {
         let mut fatal_checks = HashMap::new();
         let mut experimental_checks = HashMap::new();
         let mut other_checks = HashMap::new();
-        let all_fonts = "All fonts".to_string();
         for result in results.iter() {
-            let filename = result.filename.as_ref().unwrap_or(&all_fonts).as_str();
+            let filename = result
+                .filename_with_source()
+                .unwrap_or("All fonts".to_string());
             if result.worst_status() < args.loglevel {
                 continue;
             }
@@ -93,6 +160,14 @@ impl Reporter for MarkdownReporter {
                     .push(result);
             }
         }
+        self.write_images(
+            fatal_checks
+                .values()
+                .chain(other_checks.values())
+                .chain(experimental_checks.values())
+                .flatten()
+                .copied(),
+        );
         let summary = results.summary();
 
         let proposals: HashMap<String, String> = registry
@@ -110,6 +185,7 @@ impl Reporter for MarkdownReporter {
             "other_checks": other_checks,
             "experimental_checks": experimental_checks,
             "succinct": args.succinct,
+            "full_lists": args.full_lists,
             "total": results.len(),
             "proposal": proposals,
         });
@@ -7,11 +7,35 @@ pub(crate) mod markdown;
 pub(crate) mod terminal;
 
 /// The results of all checks in a check run
+#[derive(Default)]
 pub struct RunResults {
     results: Vec<CheckResult>,
+    /// Subresults which were dropped to save memory, counted by status
+    dropped: HashMap<StatusCode, i32>,
 }
 
 impl RunResults {
+    /// Add results, keeping only the problems
+    ///
+    /// Passing and skipped subresults, and the images of the others, are
+    /// counted in the summary and then dropped, as are checks left with
+    /// nothing to report. This keeps the memory use of a long run down.
+    pub fn extend_trimmed(&mut self, results: impl IntoIterator<Item = CheckResult>) {
+        for mut result in results {
+            result.subresults.retain_mut(|subresult| {
+                subresult.images.clear();
+                let keep = !matches!(subresult.severity, StatusCode::Pass | StatusCode::Skip);
+                if !keep {
+                    *self.dropped.entry(subresult.severity).or_insert(0) += 1;
+                }
+                keep
+            });
+            if !result.subresults.is_empty() {
+                self.results.push(result);
+            }
+        }
+    }
+
     /// Iterate over each check
     pub fn iter(&self) -> impl Iterator<Item = &CheckResult> {
         self.results.iter()
@@ -30,9 +54,30 @@ impl RunResults {
             .unwrap_or(StatusCode::Pass)
     }
 
+    /// Get the worst status of all checks which are not flagged as experimental
+    pub fn worst_status_excluding_experimental(&self, registry: &Registry) -> StatusCode {
+        self.results
+            .iter()
+            .filter(|r| !registry.is_experimental(&r.check_id))
+            .map(|r| r.worst_status())
+            .max()
+            .unwrap_or(StatusCode::Pass)
+    }
+
+    /// Get the worst status which should decide the exit code
+    ///
+    /// Unless asked otherwise, experimental checks shouldn't break anyone's CI.
+    pub fn exit_status(&self, registry: &Registry, include_experimental: bool) -> StatusCode {
+        if include_experimental {
+            self.worst_status()
+        } else {
+            self.worst_status_excluding_experimental(registry)
+        }
+    }
+
     /// Get a summary of the results by status code
     pub fn summary(&self) -> HashMap<StatusCode, i32> {
-        let mut summary = HashMap::new();
+        let mut summary = self.dropped.clone();
         for result in self.results.iter() {
             for subresult in result.subresults.iter() {
                 let entry = summary.entry(subresult.severity).or_insert(0);
@@ -43,6 +88,9 @@ impl RunResults {
     }
 
     /// Organize the results by testable and section
+    ///
+    /// Testables are keyed by their plain filename; reporters which show
+    /// people where a font was built from use [CheckResult::filename_with_source].
     pub fn organize(&self) -> OrganisedResults {
         let mut organised_results: OrganisedResults = HashMap::new();
         for checkresult in self.iter() {
@@ -74,7 +122,10 @@ impl RunResults {
 
 impl From<Vec<CheckResult>> for RunResults {
     fn from(val: Vec<CheckResult>) -> Self {
-        RunResults { results: val }
+        RunResults {
+            results: val,
+            dropped: HashMap::new(),
+        }
     }
 }
 
@@ -83,3 +134,103 @@ pub type OrganisedResults<'a> = HashMap<String, HashMap<String, Vec<CheckResult>
 pub trait Reporter {
     fn report(&self, organised_results: &RunResults, args: &Args, registry: &Registry);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fontspector_checkapi::{
+        Check, CheckFlags, CheckFnResult, CheckImplementation, Context, Status, Testable,
+    };
+    use std::time::Duration;
+
+    fn failing(_t: &Testable, _context: &Context) -> CheckFnResult {
+        Ok(Status::just_one_fail("bad", "Always fails"))
+    }
+
+    fn check(id: &'static str, experimental: bool) -> Check<'static> {
+        Check {
+            id,
+            title: id,
+            rationale: "",
+            proposal: "",
+            implementation: CheckImplementation::CheckOne(&failing),
+            hotfix: None,
+            fix_source: None,
+            applies_to: "TTF",
+            flags: CheckFlags { experimental },
+            tags: &[],
+            aliases: &[],
+            _metadata: None,
+            version: 0,
+        }
+    }
+
+    fn result(check: &Check, severity: Status) -> CheckResult {
+        CheckResult::new(
+            check,
+            Some("Foo-Regular.ttf"),
+            None,
+            None,
+            vec![severity],
+            Duration::default(),
+        )
+    }
+
+    #[test]
+    fn test_experimental_fail_does_not_change_exit_status() {
+        let mut registry = Registry::new();
+        let stable = check("stable", false);
+        let experimental = check("experimental", true);
+        registry.register_check(stable.clone());
+        registry.register_check(experimental.clone());
+        let results: RunResults = vec![
+            result(&stable, Status::pass()),
+            result(&experimental, Status::fail("bad", "Always fails")),
+        ]
+        .into();
+        assert_eq!(results.exit_status(&registry, false), StatusCode::Pass);
+        assert_eq!(results.exit_status(&registry, true), StatusCode::Fail);
+
+        let results: RunResults = vec![
+            result(&stable, Status::fail("bad", "Always fails")),
+            result(&experimental, Status::fail("bad", "Always fails")),
+        ]
+        .into();
+        assert_eq!(results.exit_status(&registry, false), StatusCode::Fail);
+    }
+
+    #[test]
+    fn test_trimmed_results_are_still_counted() {
+        let stable = check("stable", false);
+        let mut results = RunResults::default();
+        results.extend_trimmed(vec![
+            result(&stable, Status::pass()),
+            result(&stable, Status::skip("no", "Not applicable")),
+            result(&stable, Status::fail("bad", "Always fails")),
+        ]);
+        assert_eq!(results.len(), 1);
+        let summary = results.summary();
+        assert_eq!(summary.get(&StatusCode::Pass), Some(&1));
+        assert_eq!(summary.get(&StatusCode::Skip), Some(&1));
+        assert_eq!(summary.get(&StatusCode::Fail), Some(&1));
+    }
+
+    #[test]
+    fn test_organize_keys_by_plain_filename() {
+        let stable = check("stable", false);
+        let results: RunResults = vec![CheckResult::new(
+            &stable,
+            Some("Foo-Regular.ttf"),
+            Some("sources/Foo.designspace"),
+            Some("Section"),
+            vec![Status::pass()],
+            Duration::default(),
+        )]
+        .into();
+        let organised = results.organize();
+        assert_eq!(
+            organised.keys().collect::<Vec<_>>(),
+            vec!["Foo-Regular.ttf"]
+        );
+    }
+}
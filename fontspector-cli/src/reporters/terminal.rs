@@ -56,7 +56,7 @@ impl Reporter for TerminalReporter {
                     if self.succinct {
                         println!(
                             "{:}: {:} {:} [{}]",
-                            Path::new(filename)
+                            Path::new(result.filename.as_deref().unwrap_or(filename))
                                 .file_name()
                                 .unwrap_or_default()
                                 .to_string_lossy(),
@@ -71,7 +71,12 @@ impl Reporter for TerminalReporter {
                     }
 
                     if !fileheading_done {
-                        println!("Testing: {:}", filename);
+                        println!(
+                            "Testing: {:}",
+                            result
+                                .filename_with_source()
+                                .unwrap_or(filename.to_string())
+                        );
                         fileheading_done = true;
                     }
                     if !sectionheading_done {
@@ -84,7 +89,12 @@ impl Reporter for TerminalReporter {
                         println!("\nRationale:\n{}", skin.term_text(&result.check_rationale));
                     }
                     for subresult in subresults {
-                        println!("{}\n", skin.term_text(&subresult.to_string()));
+                        let text = if args.full_lists {
+                            format!("{:#}", subresult)
+                        } else {
+                            subresult.to_string()
+                        };
+                        println!("{}\n", skin.term_text(&text));
                     }
                     match &result.hotfix_result {
                         Some(FixResult::Available) => {
@@ -0,0 +1,226 @@
+//! Working out which source file each binary font was built from
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use fontspector_checkapi::{collection_face, TestableCollection};
+use quick_xml::events::Event;
+use serde::Deserialize;
+
+use crate::{inputs::is_archive_member, Args};
+
+/// The parts of a gftools-builder configuration file we care about
+#[derive(Deserialize)]
+struct BuilderConfig {
+    /// The sources the family is built from, relative to the configuration file
+    #[serde(default)]
+    sources: Vec<String>,
+}
+
+/// What we know about the sources of a font project
+#[derive(Default)]
+struct ProjectSources {
+    /// Font file stems named by designspace instances, mapped to the designspace
+    instances: HashMap<String, PathBuf>,
+    /// All sources named in gftools-builder configuration files
+    builder_sources: Vec<PathBuf>,
+}
+
+/// Find the file stems of the instances in a designspace file
+fn designspace_instances(designspace: &Path) -> Vec<String> {
+    let Ok(contents) = std::fs::read_to_string(designspace) else {
+        return vec![];
+    };
+    let mut reader = quick_xml::Reader::from_str(&contents);
+    let mut stems = vec![];
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element)) | Ok(Event::Empty(element))
+                if element.name().as_ref() == b"instance" =>
+            {
+                if let Ok(Some(filename)) = element.try_get_attribute("filename") {
+                    let filename = String::from_utf8_lossy(&filename.value).to_string();
+                    if let Some(stem) = Path::new(&filename).file_stem() {
+                        stems.push(stem.to_string_lossy().to_string());
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                log::warn!("Could not parse {}: {}", designspace.display(), e);
+                break;
+            }
+            _ => {}
+        }
+    }
+    stems
+}
+
+impl ProjectSources {
+    /// Read the builder configuration files and designspaces in a `sources` directory
+    fn from_directory(directory: &Path) -> Self {
+        let mut project = ProjectSources::default();
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            return project;
+        };
+        let mut designspaces = vec![];
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.starts_with("config") && (name.ends_with(".yaml") || name.ends_with(".yml")) {
+                let config: Option<BuilderConfig> = std::fs::File::open(&path)
+                    .ok()
+                    .and_then(|file| serde_yaml::from_reader(file).ok());
+                if let Some(config) = config {
+                    project
+                        .builder_sources
+                        .extend(config.sources.iter().map(|s| directory.join(s)));
+                } else {
+                    log::warn!("Could not read builder configuration {}", path.display());
+                }
+            } else if name.ends_with(".designspace") {
+                designspaces.push(path);
+            }
+        }
+        designspaces.extend(
+            project
+                .builder_sources
+                .iter()
+                .filter(|s| s.extension().is_some_and(|ext| ext == "designspace"))
+                .cloned(),
+        );
+        for designspace in designspaces {
+            for stem in designspace_instances(&designspace) {
+                project.instances.insert(stem, designspace.clone());
+            }
+        }
+        project
+    }
+
+    /// Guess which of the builder's sources a font was built from
+    ///
+    /// If there is only one source, that's it. Otherwise we prefer sources which
+    /// agree with the font on whether it is italic, and then the source whose
+    /// name shares the longest prefix with the font's name.
+    fn builder_source(&self, font_stem: &str) -> Option<&PathBuf> {
+        if self.builder_sources.len() == 1 {
+            return self.builder_sources.first();
+        }
+        let normalize = |s: &str| {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        };
+        let font_stem = normalize(font_stem);
+        let italic = font_stem.contains("italic");
+        self.builder_sources
+            .iter()
+            .filter_map(|source| {
+                let stem = normalize(&source.file_stem()?.to_string_lossy());
+                (stem.contains("italic") == italic).then(|| {
+                    let common = stem
+                        .chars()
+                        .zip(font_stem.chars())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    (common, source)
+                })
+            })
+            .max_by_key(|(common, _)| *common)
+            .map(|(_, source)| source)
+    }
+}
+
+/// Read a `--source-map` file: a JSON or YAML object mapping fonts to sources
+///
+/// Relative source paths are taken to be relative to the map file.
+fn load_source_map(filename: &str) -> Vec<(PathBuf, PathBuf)> {
+    let file = std::fs::File::open(filename).unwrap_or_else(|e| {
+        log::error!("Could not open source map {}: {:}", filename, e);
+        std::process::exit(1)
+    });
+    let map: HashMap<String, String> = serde_yaml::from_reader(file).unwrap_or_else(|e| {
+        log::error!("Could not parse source map {}: {:}", filename, e);
+        std::process::exit(1)
+    });
+    let base = Path::new(filename).parent().unwrap_or(Path::new(""));
+    map.into_iter()
+        .map(|(font, source)| (PathBuf::from(font), base.join(source)))
+        .collect()
+}
+
+/// Find the `sources` directory of the project a font belongs to
+///
+/// We look next to the font and then in its parent directories, but stop at the
+/// root of the project (a directory containing `.git` or a `config.yaml`) or at
+/// the directory fontspector was run from, so we don't wander off into
+/// unrelated projects.
+fn find_sources_directory(font: &Path, working_dir: Option<&Path>) -> Option<PathBuf> {
+    for dir in font.parent()?.ancestors() {
+        let sources_dir = dir.join("sources");
+        if sources_dir.is_dir() {
+            return Some(sources_dir);
+        }
+        // Relative inputs are relative to the working directory, which is the empty path
+        if dir.as_os_str().is_empty()
+            || Some(dir) == working_dir
+            || dir.join(".git").exists()
+            || dir.join("config.yaml").is_file()
+        {
+            break;
+        }
+    }
+    None
+}
+
+/// Record the source of each font in the collections, where we can find one
+///
+/// Sources are taken from the `--source-map` file if one was given; otherwise
+/// we look for a `sources` directory next to the font or in one of its parent
+/// directories within the project, and use the instance filenames of designspace files there, or
+/// the sources listed in a gftools-builder `config.yaml`.
+pub(crate) fn assign_sources(args: &Args, collections: &mut [TestableCollection]) {
+    let explicit = args
+        .source_map
+        .as_deref()
+        .map(load_source_map)
+        .unwrap_or_default();
+    let working_dir = std::env::current_dir().ok();
+    let mut projects: HashMap<PathBuf, ProjectSources> = HashMap::new();
+    for testable in collections.iter_mut().flat_map(|c| c.testables.iter_mut()) {
+        if testable.source.is_some() {
+            continue;
+        }
+        // The faces of a font collection come from the collection's source
+        let font = collection_face(&testable.filename)
+            .map(|(collection, _)| collection)
+            .unwrap_or(testable.filename.clone());
+        if let Some((_, source)) = explicit.iter().find(|(key, _)| font.ends_with(key)) {
+            testable.source = Some(source.clone());
+            continue;
+        }
+        if is_archive_member(&font.to_string_lossy()) {
+            continue;
+        }
+        let Some(stem) = font.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+            continue;
+        };
+        let Some(sources_dir) = find_sources_directory(&font, working_dir.as_deref()) else {
+            continue;
+        };
+        let project = projects
+            .entry(sources_dir.clone())
+            .or_insert_with(|| ProjectSources::from_directory(&sources_dir));
+        testable.source = project
+            .instances
+            .get(&stem)
+            .or_else(|| project.builder_source(&stem))
+            .cloned();
+        if let Some(source) = testable.source.as_ref() {
+            log::debug!("{} was built from {}", font.display(), source.display());
+        }
+    }
+}
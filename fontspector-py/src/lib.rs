@@ -6,6 +6,7 @@ use fontspector_checkapi::{
     CheckImplementation, Context, Plugin, Registry, StatusCode, Testable, TestableCollection,
     TestableType,
 };
+use profile_designspace::DesignspaceProfile;
 use profile_googlefonts::GoogleFonts;
 use profile_opentype::OpenType;
 use profile_universal::Universal;
@@ -46,9 +47,10 @@ fn obj_to_testable(py: Python, arg: &Bound<'_, PyAny>) -> PyResult<Testable> {
         .to_str()
         .ok_or_else(|| PyValueError::new_err("Couldn't convert tempfile path to string"))?;
     arg.call_method1("save", (tempfile,))?;
-    let testable = Testable::new(tempfile)
+    // Read the contents now, as the temporary file is overwritten by the next font we save
+    let contents = std::fs::read(tempfile)
         .map_err(|e| PyValueError::new_err(format!("Couldn't create testable object: {}", e)))?;
-    Ok(testable)
+    Ok(Testable::new_with_contents(tempfile, contents))
 }
 
 #[pymethods]
@@ -77,10 +79,12 @@ impl CheckTester {
         GoogleFonts.register(&mut registry).map_err(|_| {
             PyValueError::new_err("Couldn't register Google Fonts profile, fontspector bug")
         })?;
+        DesignspaceProfile.register(&mut registry).map_err(|_| {
+            PyValueError::new_err("Couldn't register designspace profile, fontspector bug")
+        })?;
 
         let check = registry
-            .checks
-            .get(&self.check_id)
+            .get_check(&self.check_id)
             .ok_or_else(|| PyValueError::new_err("Check not found"))?;
 
         // We have almost certainly been handed a TTFont object. Turn it into a testable
@@ -120,7 +124,6 @@ impl CheckTester {
 
         let mut context = Context {
             configuration: fontspector_config,
-            full_lists: true,
             skip_network,
             ..Default::default()
         };
@@ -149,10 +152,14 @@ impl CheckTester {
                 StatusCode::Fail => status_module.getattr("FAIL")?,
                 StatusCode::Error => status_module.getattr("ERROR")?,
             };
-            let message = message_class.call1((
-                subresult.code.unwrap_or("None".to_string()),
-                subresult.message.unwrap_or("No message".to_string()),
-            ))?;
+            // The fontbakery tests look for items in the message, so give them all of them
+            let text = if subresult.message.is_some() || !subresult.items.is_empty() {
+                subresult.message_with_items(true)
+            } else {
+                "No message".to_string()
+            };
+            let message =
+                message_class.call1((subresult.code.unwrap_or("None".to_string()), text))?;
             py_subresults.push(
                 subresult_module
                     .getattr("Subresult")?
@@ -175,6 +182,9 @@ fn registered_checks() -> PyResult<Vec<String>> {
     GoogleFonts.register(&mut registry).map_err(|_| {
         PyValueError::new_err("Couldn't register Google Fonts profile, fontspector bug")
     })?;
+    DesignspaceProfile.register(&mut registry).map_err(|_| {
+        PyValueError::new_err("Couldn't register designspace profile, fontspector bug")
+    })?;
     Ok(registry.checks.keys().cloned().collect())
 }
 
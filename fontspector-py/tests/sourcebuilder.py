@@ -0,0 +1,235 @@
+"""Write small UFO and designspace sources for the source checks to test."""
+
+import plistlib
+from xml.sax.saxutils import quoteattr
+
+SQUARE = [[(100, 0, "line"), (100, 700, "line"), (400, 700, "line"), (400, 0, "line")]]
+
+DEFAULT_GLYPHS = {
+    ".notdef": {},
+    "A": {"unicodes": [0x41], "contours": SQUARE, "anchors": [("top", 250, 700)]},
+    "B": {"unicodes": [0x42], "contours": SQUARE},
+}
+
+DEFAULT_INFO = {
+    "familyName": "Test",
+    "styleName": "Regular",
+    "unitsPerEm": 1000,
+    "versionMajor": 1,
+    "versionMinor": 0,
+    "copyright": "Copyright 2024 The Test Project Authors",
+}
+
+
+def glif(name, glyph):
+    """Write a glyph in GLIF format 2."""
+    lines = [
+        '<?xml version="1.0" encoding="UTF-8"?>',
+        f'<glyph name={quoteattr(name)} format="2">',
+        '  <advance width="500"/>',
+    ]
+    for codepoint in glyph.get("unicodes", []):
+        lines.append(f'  <unicode hex="{codepoint:04X}"/>')
+    for anchor, x, y in glyph.get("anchors", []):
+        lines.append(f'  <anchor x="{x}" y="{y}" name={quoteattr(anchor)}/>')
+    lines.append("  <outline>")
+    for contour in glyph.get("contours", []):
+        lines.append("    <contour>")
+        for x, y, kind in contour:
+            kind = f' type="{kind}"' if kind else ""
+            lines.append(f'      <point x="{x}" y="{y}"{kind}/>')
+        lines.append("    </contour>")
+    for base in glyph.get("components", []):
+        lines.append(f"    <component base={quoteattr(base)}/>")
+    lines.append("  </outline>")
+    lines.append("</glyph>")
+    return "\n".join(lines) + "\n"
+
+
+def ufo(directory, name, glyphs=None, info=None, lib=None, groups=None, kerning=None):
+    """Write a UFO 3 font source, returning its path as a string.
+
+    Glyphs are given as a dictionary of glyph names to dictionaries with
+    optional "unicodes", "contours", "anchors" and "components" keys.
+    """
+    glyphs = DEFAULT_GLYPHS if glyphs is None else glyphs
+    path = directory / name
+    (path / "glyphs").mkdir(parents=True)
+
+    def write_plist(filename, value):
+        with open(path / filename, "wb") as f:
+            plistlib.dump(value, f)
+
+    write_plist(
+        "metainfo.plist", {"creator": "org.fontspector.tests", "formatVersion": 3}
+    )
+    write_plist("fontinfo.plist", DEFAULT_INFO if info is None else info)
+    write_plist("layercontents.plist", [["public.default", "glyphs"]])
+    contents = {}
+    for ix, (glyph_name, glyph) in enumerate(glyphs.items()):
+        contents[glyph_name] = f"glyph{ix}.glif"
+        (path / "glyphs" / contents[glyph_name]).write_text(glif(glyph_name, glyph))
+    write_plist("glyphs/contents.plist", contents)
+    if lib is not None:
+        write_plist("lib.plist", lib)
+    if groups is not None:
+        write_plist("groups.plist", groups)
+    if kerning is not None:
+        write_plist("kerning.plist", kerning)
+    return str(path)
+
+
+def location(axis, value):
+    """A designspace location on a single axis."""
+    return f'      <location><dimension name="{axis}" xvalue="{value}"/></location>'
+
+
+def designspace(
+    directory, masters, axes=None, instances=None, groups=None, ufo_directory=None
+):
+    """Write a designspace document and its source UFOs, returning its path.
+
+    Masters are given as a list of (style name, weight, glyphs) tuples; a
+    glyphs value of None gives the default glyphs. Instances are a list of
+    (style name, weight) tuples. Groups are a dictionary of style names to
+    the groups of that master. The UFOs are written next to the designspace,
+    or in the given subdirectory.
+    """
+    groups = groups or {}
+    axes = axes or [("wght", "Weight", 100, 400, 900)]
+    if instances is None:
+        instances = [(style, weight) for style, weight, _ in masters]
+    lines = [
+        '<?xml version="1.0" encoding="UTF-8"?>',
+        '<designspace format="5.0">',
+        "  <axes>",
+    ]
+    for tag, name, minimum, default, maximum in axes:
+        lines.append(
+            f'    <axis tag="{tag}" name="{name}" minimum="{minimum}"'
+            f' maximum="{maximum}" default="{default}"/>'
+        )
+    lines.append("  </axes>")
+    lines.append("  <sources>")
+    for style, weight, glyphs in masters:
+        filename = f"Test-{style}.ufo"
+        if ufo_directory:
+            filename = f"{ufo_directory}/{filename}"
+        ufo(
+            directory,
+            filename,
+            glyphs,
+            info=dict(DEFAULT_INFO, styleName=style),
+            groups=groups.get(style),
+        )
+        lines.append(f'    <source filename="{filename}" name="{style}">')
+        lines.append(location(axes[0][1], weight))
+        lines.append("    </source>")
+    lines.append("  </sources>")
+    lines.append("  <instances>")
+    for style, weight in instances:
+        lines.append(
+            f'    <instance name="Test {style}" familyname="Test" stylename="{style}">'
+        )
+        lines.append(location(axes[0][1], weight))
+        lines.append("    </instance>")
+    lines.append("  </instances>")
+    lines.append("</designspace>")
+    path = directory / "Test.designspace"
+    path.write_text("\n".join(lines) + "\n")
+    return str(path)
+
+
+
+GLYPHS_NODE_TYPES = {"line": "l", "curve": "c", "qcurve": "q", None: "o"}
+
+
+def glyphs_array(items):
+    """Write a list of values as a Glyphs (old-style plist) array."""
+    if not items:
+        return "()"
+    return "(\n" + ",\n".join(items) + "\n)"
+
+
+def glyphs_layer(master_id, glyph):
+    """Write one master's layer of a glyph in Glyphs 3 format."""
+    shapes = []
+    for contour in glyph.get("contours", []):
+        nodes = [f"({x},{y},{GLYPHS_NODE_TYPES[kind]})" for x, y, kind in contour]
+        shapes.append(f"{{\nclosed = 1;\nnodes = {glyphs_array(nodes)};\n}}")
+    for base in glyph.get("components", []):
+        shapes.append(f'{{\nref = "{base}";\n}}')
+    anchors = [
+        f'{{\nname = "{name}";\npos = ({x},{y});\n}}'
+        for name, x, y in glyph.get("anchors", [])
+    ]
+    return (
+        f"{{\nanchors = {glyphs_array(anchors)};\nlayerId = {master_id};\n"
+        f"shapes = {glyphs_array(shapes)};\nwidth = 500;\n}}"
+    )
+
+
+def glyphs_source(directory, masters, axes=None, instances=None):
+    """Write a Glyphs 3 source, returning its path.
+
+    Masters, axes and instances are given as for `designspace`; a glyph missing
+    from a master's glyphs has no layer for that master.
+    """
+    axes = axes or [("wght", "Weight", 100, 400, 900)]
+    if instances is None:
+        instances = [(style, weight) for style, weight, _ in masters]
+    masters = [
+        (f"m{ix:02}", style, weight, DEFAULT_GLYPHS if glyphs is None else glyphs)
+        for ix, (style, weight, glyphs) in enumerate(masters)
+    ]
+    glyph_names = []
+    for *_, glyphs in masters:
+        glyph_names.extend(name for name in glyphs if name not in glyph_names)
+    glyph_entries = []
+    for name in glyph_names:
+        layers = [
+            glyphs_layer(master_id, glyphs[name])
+            for master_id, *_, glyphs in masters
+            if name in glyphs
+        ]
+        entry = f'{{\nglyphname = "{name}";\nlayers = {glyphs_array(layers)};\n'
+        # Glyphs encodes a glyph the same way in every master
+        unicodes = [
+            glyphs[name]["unicodes"][0]
+            for *_, glyphs in masters
+            if glyphs.get(name, {}).get("unicodes")
+        ]
+        if unicodes:
+            entry += f"unicode = {unicodes[0]};\n"
+        glyph_entries.append(entry + "}")
+    axis_entries = [
+        f'{{\nname = "{name}";\ntag = "{tag}";\n}}' for tag, name, *_ in axes
+    ]
+    master_entries = [
+        f'{{\naxesValues = ({weight});\nid = {master_id};\nname = "{style}";\n}}'
+        for master_id, style, weight, _ in masters
+    ]
+    instance_entries = [
+        f'{{\naxesValues = ({weight});\nname = "{style}";\n}}'
+        for style, weight in instances
+    ]
+    source = "\n".join(
+        [
+            "{",
+            '.appVersion = "3260";',
+            ".formatVersion = 3;",
+            f"axes = {glyphs_array(axis_entries)};",
+            'familyName = "Test";',
+            f"fontMaster = {glyphs_array(master_entries)};",
+            f"glyphs = {glyphs_array(glyph_entries)};",
+            f"instances = {glyphs_array(instance_entries)};",
+            "unitsPerEm = 1000;",
+            "versionMajor = 1;",
+            "versionMinor = 0;",
+            "}",
+        ]
+    )
+    directory.mkdir(parents=True, exist_ok=True)
+    path = directory / "Test.glyphs"
+    path.write_text(source + "\n")
+    return str(path)
@@ -0,0 +1,63 @@
+import io
+
+from fontTools.ttLib import TTFont
+
+from fontbakery.status import FAIL, SKIP
+from fontbakery.codetesting import (
+    assert_PASS,
+    assert_results_contain,
+    TEST_FILE,
+)
+from conftest import check_id
+
+
+@check_id("opentype/CFF_call_depth")
+def test_check_cff_call_depth(check):
+    """Is the CFF subr/gsubr call depth > 10?"""
+
+    ttFont = TTFont(TEST_FILE("source-sans-pro/OTF/SourceSansPro-Bold.otf"))
+    assert_PASS(check(ttFont))
+
+    ttFont = TTFont(TEST_FILE("subr_test_fonts/subr_test_font_infinite_recursion.otf"))
+    assert_results_contain(check(ttFont), FAIL, "max-depth")
+
+    ttFont = TTFont(TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf"))
+    assert_results_contain(check(ttFont), SKIP, "no-cff")
+
+
+@check_id("opentype/CFF2_call_depth")
+def test_check_cff2_call_depth(check):
+    """Is the CFF2 subr/gsubr call depth > 10?"""
+
+    ttFont = TTFont(TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf"))
+    assert_PASS(check(ttFont))
+
+    ttFont = TTFont(
+        TEST_FILE("subr_test_fonts/var_subr_test_font_infinite_recursion.otf")
+    )
+    assert_results_contain(check(ttFont), FAIL, "max-depth")
+
+
+@check_id("opentype/CFF_deprecated_operators")
+def test_check_cff_deprecated_operators(check):
+    """Does the font use deprecated CFF operators or operations?"""
+
+    ttFont = TTFont(TEST_FILE("source-sans-pro/OTF/SourceSansPro-Bold.otf"))
+    assert_PASS(check(ttFont))
+
+
+@check_id("opentype/CFF_ascii_strings")
+def test_check_cff_ascii_strings(check):
+    """Does the font's CFF table top dict strings fit into the ASCII range?"""
+
+    font = TEST_FILE("source-sans-pro/OTF/SourceSansPro-Bold.otf")
+    ttFont = TTFont(font)
+    assert_PASS(check(ttFont))
+
+    ttFont = TTFont(font)
+    ttFont["CFF "].cff.topDictIndex[0].Notice = "© Adobe"
+    _file = io.BytesIO()
+    ttFont.save(_file)
+    ttFont = TTFont(_file)
+    ttFont.reader.file.name = font
+    assert_results_contain(check(ttFont), FAIL, "cff-string-not-in-ascii")
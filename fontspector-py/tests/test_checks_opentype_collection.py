@@ -0,0 +1,112 @@
+import struct
+
+from fontTools.ttLib import TTCollection, TTFont
+
+from conftest import check_id
+from fontbakery.codetesting import (
+    TEST_FILE,
+    assert_PASS,
+    assert_results_contain,
+)
+from fontbakery.status import FAIL, WARN
+
+
+def collection(tmp_path, fonts, share_tables=True):
+    """Save some fonts as a font collection, returning its path."""
+    ttc = TTCollection()
+    ttc.fonts = fonts
+    path = tmp_path / "Collection.ttc"
+    ttc.save(path, shareTables=share_tables)
+    return str(path)
+
+
+def table_record(data, face, tag):
+    """Find where a face's table record is in a font collection."""
+    (face_offset,) = struct.unpack(">L", data[12 + 4 * face : 16 + 4 * face])
+    (num_tables,) = struct.unpack(">H", data[face_offset + 4 : face_offset + 6])
+    for i in range(num_tables):
+        record = face_offset + 12 + 16 * i
+        if data[record : record + 4] == tag:
+            return record
+    raise KeyError(tag)
+
+
+def table_offset(data, face, tag):
+    """Find the offset of a face's table in a font collection."""
+    record = table_record(data, face, tag)
+    (offset,) = struct.unpack(">L", data[record + 8 : record + 12])
+    return offset
+
+
+@check_id("opentype/collection/shared_tables")
+def test_check_collection_shared_tables(check, tmp_path):
+    """Are the tables of a font collection shared sensibly?"""
+
+    font = TEST_FILE("mada/Mada-Regular.ttf")
+    ttc = collection(tmp_path, [TTFont(font), TTFont(font)])
+    assert_PASS(check(ttc), "with identical faces sharing all their tables...")
+
+    ttc = collection(
+        tmp_path,
+        [TTFont(font), TTFont(TEST_FILE("mada/Mada-Medium.ttf"))],
+    )
+    assert_PASS(check(ttc), "with two different faces...")
+
+    ttc = collection(tmp_path, [TTFont(font), TTFont(font)], share_tables=False)
+    assert_results_contain(
+        check(ttc),
+        WARN,
+        "duplicate-tables",
+        "with identical tables stored once for each face...",
+    )
+
+    # Make the second face's glyf table claim to be longer than the first's
+    ttc = collection(tmp_path, [TTFont(font), TTFont(font)])
+    with open(ttc, "rb") as f:
+        data = bytearray(f.read())
+    record = table_record(data, 1, b"glyf")
+    (length,) = struct.unpack(">L", data[record + 12 : record + 16])
+    data[record + 12 : record + 16] = struct.pack(">L", length + 4)
+    with open(ttc, "wb") as f:
+        f.write(data)
+    assert_results_contain(
+        check(ttc),
+        FAIL,
+        "inconsistent-shared-table",
+        "with faces disagreeing on the length of a shared table...",
+    )
+
+
+@check_id("opentype/collection/consistent_glyph_count")
+def test_check_collection_consistent_glyph_count(check, tmp_path):
+    """Do faces sharing glyph data agree on the number of glyphs?"""
+
+    font = TEST_FILE("mada/Mada-Regular.ttf")
+    ttc = collection(tmp_path, [TTFont(font), TTFont(font)])
+    assert_PASS(check(ttc), "with identical faces...")
+
+    ttc = collection(
+        tmp_path,
+        [TTFont(font), TTFont(TEST_FILE("mada/Mada-Medium.ttf"))],
+    )
+    assert_PASS(check(ttc), "with faces which don't share glyph data...")
+
+    # Give the second face a maxp table of its own, sharing everything else,
+    # then make it declare one glyph fewer than the shared glyf table has.
+    second = TTFont(font)
+    second["maxp"].maxZones = 3 - second["maxp"].maxZones
+    ttc = collection(tmp_path, [TTFont(font), second])
+    with open(ttc, "rb") as f:
+        data = bytearray(f.read())
+    maxp = table_offset(data, 1, b"maxp")
+    assert maxp != table_offset(data, 0, b"maxp")
+    (num_glyphs,) = struct.unpack(">H", data[maxp + 4 : maxp + 6])
+    data[maxp + 4 : maxp + 6] = struct.pack(">H", num_glyphs - 1)
+    with open(ttc, "wb") as f:
+        f.write(data)
+    assert_results_contain(
+        check(ttc),
+        FAIL,
+        "inconsistent-glyph-count",
+        "with faces sharing a glyf table but not agreeing on the glyph count...",
+    )
@@ -1,4 +1,8 @@
+from fontTools.fontBuilder import FontBuilder
+from fontTools.pens.ttGlyphPen import TTGlyphPen
 from fontTools.ttLib import TTFont
+from fontTools.ttLib.tables._f_v_a_r import NamedInstance
+from fontTools.ttLib.tables.TupleVariation import TupleVariation
 
 from conftest import check_id
 from fontbakery.status import WARN, SKIP
@@ -56,6 +60,59 @@ def test_check_outline_alignment_os2_old(check):
     assert_results_contain(check(ttFont), WARN, "skip-cap-x-height-alignment")
 
 
+TRIANGLE = [(100, 0), (250, 700), (400, 0)]
+
+
+def variable_font(points, bold_deltas):
+    """Build a weight-axis variable font with a single glyph, A.
+
+    The glyph is a closed contour through the given points. At the Bold
+    instance, the points are moved by the given deltas, a dictionary of point
+    indices to (x, y) offsets.
+    """
+    fb = FontBuilder(1000, isTTF=True)
+    fb.setupGlyphOrder([".notdef", "A"])
+    fb.setupCharacterMap({0x41: "A"})
+    pen = TTGlyphPen(None)
+    pen.moveTo(points[0])
+    for point in points[1:]:
+        pen.lineTo(point)
+    pen.closePath()
+    fb.setupGlyf({".notdef": TTGlyphPen(None).glyph(), "A": pen.glyph()})
+    fb.setupHorizontalMetrics({".notdef": (500, 0), "A": (500, 100)})
+    fb.setupHorizontalHeader(ascent=800, descent=-200)
+    fb.setupNameTable({"familyName": "Test", "styleName": "Regular"})
+    fb.setupOS2(sTypoAscender=800, sTypoDescender=-200, sxHeight=500, sCapHeight=700)
+    fb.setupPost()
+    fb.setupFvar([("wght", 400, 400, 900, "Weight")], [])
+    for name, weight in [("Regular", 400), ("Bold", 900)]:
+        instance = NamedInstance()
+        instance.subfamilyNameID = fb.font["name"].addMultilingualName(
+            {"en": name}, mac=False
+        )
+        instance.coordinates = {"wght": weight}
+        fb.font["fvar"].instances.append(instance)
+    # The outline's points, then the four phantom points
+    deltas = [bold_deltas.get(ix, (0, 0)) for ix in range(len(points) + 4)]
+    fb.setupGvar({"A": [TupleVariation({"wght": (0, 1.0, 1.0)}, deltas)]})
+    return fb.font
+
+
+@check_id("outline_alignment_miss")
+def test_check_outline_alignment_miss_variable(check):
+    """Misalignments away from the default location of a variable font
+    are found, and the locations where they occur are reported."""
+
+    font = variable_font(TRIANGLE, {})
+    assert_PASS(check(font), "with aligned points at every instance...")
+
+    results = check(variable_font(TRIANGLE, {0: (0, 1)}))
+    assert_results_contain(results, WARN, "found-misalignments")
+    messages = "".join([m.message.message for m in results])
+    assert "A (U+0041): X=100,Y=1 (should be at baseline 0?) (at Bold)" in messages
+    assert "Regular" not in messages
+
+
 @check_id("outline_short_segments")
 def test_check_outline_short_segments(check):
     """Check for short segments."""
@@ -71,7 +128,7 @@ def test_check_outline_short_segments(check):
     # TODO: PASS
 
     font = TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf")
-    msg = assert_results_contain(check(font), SKIP, "variable-font")
+    assert not any(r.status == SKIP for r in check(font))
 
 
 @check_id("outline_colinear_vectors")
@@ -90,7 +147,28 @@ def test_check_outline_colinear_vectors(check):
     # TODO: PASS
 
     font = TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf")
-    msg = assert_results_contain(check(font), SKIP, "variable-font")
+    assert not any(r.status == SKIP for r in check(font))
+
+
+@check_id("outline_colinear_vectors")
+def test_check_outline_colinear_vectors_variable(check):
+    """In a variable font, only points which are redundant at every location
+    are reported as colinear."""
+
+    # An extra point halfway along the left-hand side of the triangle
+    points = [(100, 0), (175, 350), (250, 700), (400, 0)]
+    msg = assert_results_contain(
+        check(variable_font(points, {})),
+        WARN,
+        "found-colinear-vectors",
+        "with a point which is colinear at every location...",
+    )
+    assert "A (U+0041)" in msg
+
+    assert_PASS(
+        check(variable_font(points, {1: (-50, 0)})),
+        "with a point which is only colinear at the default location...",
+    )
 
 
 @check_id("outline_jaggy_segments")
@@ -110,7 +188,7 @@ def test_check_outline_jaggy_segments(check):
     assert_PASS(check(filename))
 
     font = TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf")
-    msg = assert_results_contain(check(font), SKIP, "variable-font")
+    assert not any(r.status == SKIP for r in check(font))
 
 
 @check_id("outline_semi_vertical")
@@ -127,7 +205,7 @@ def test_check_outline_semi_vertical(check):
     # TODO: PASS
 
     font = TEST_FILE("source-sans-pro/VAR/SourceSansVariable-Roman.otf")
-    msg = assert_results_contain(check(font), SKIP, "variable-font")
+    assert not any(r.status == SKIP for r in check(font))
     font = TEST_FILE("source-sans-pro/OTF/SourceSansPro-Italic.otf")
     msg = assert_results_contain(check(font), SKIP, "italic")
 
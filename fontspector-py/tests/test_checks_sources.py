@@ -0,0 +1,413 @@
+import copy
+import shutil
+
+from conftest import check_id
+from fontbakery.codetesting import assert_PASS, assert_results_contain
+from fontbakery.status import ERROR, FAIL, WARN
+
+from sourcebuilder import DEFAULT_GLYPHS, SQUARE, designspace, glyphs_source
+
+
+def glyphs_with(**changes):
+    """The default glyphs, with some glyphs replaced or (given None) removed."""
+    glyphs = copy.deepcopy(DEFAULT_GLYPHS)
+    for name, glyph in changes.items():
+        if glyph is None:
+            del glyphs[name]
+        else:
+            glyphs[name] = glyph
+    return glyphs
+
+
+def two_masters(tmp_path, label, bold_glyphs=None, **kwargs):
+    """Write a designspace with a Light and a Bold master, returning its path."""
+    return designspace(
+        tmp_path / label,
+        [("Light", 100, None), ("Bold", 900, bold_glyphs)],
+        **kwargs,
+    )
+
+
+@check_id("sources/compatible_outlines")
+def test_check_sources_compatible_outlines(check, tmp_path):
+    """Are the outlines of each glyph compatible across masters?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    extra_point = [SQUARE[0] + [(400, -10, "line")]]
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": extra_point})
+    assert_results_contain(
+        check(two_masters(tmp_path, "points", bold)),
+        FAIL,
+        "point-count",
+        "with an extra point in one master...",
+    )
+
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": SQUARE + SQUARE})
+    assert_results_contain(
+        check(two_masters(tmp_path, "contours", bold)),
+        FAIL,
+        "contour-count",
+        "with an extra contour in one master...",
+    )
+
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": SQUARE, "components": ["A"]})
+    assert_results_contain(
+        check(two_masters(tmp_path, "components", bold)),
+        FAIL,
+        "component-count",
+        "with an extra component in one master...",
+    )
+
+
+@check_id("sources/start_points")
+def test_check_sources_start_points(check, tmp_path):
+    """Do contours start at the same point in all masters?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    # Move the start of the contour along by one point, so it starts at the
+    # first off-curve point instead of the on-curve point before it
+    curve = [
+        (100, 0, "line"),
+        (100, 300, None),
+        (100, 700, None),
+        (400, 700, "curve"),
+    ]
+    light = glyphs_with(B={"unicodes": [0x42], "contours": [curve]})
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": [curve[1:] + curve[:1]]})
+    path = designspace(
+        tmp_path / "rotated",
+        [("Light", 100, light), ("Bold", 900, bold)],
+    )
+    message = assert_results_contain(
+        check(path), FAIL, "start-point", "with a contour starting elsewhere..."
+    )
+    assert "Glyph B in Bold" in message
+
+
+@check_id("sources/path_direction")
+def test_check_sources_path_direction(check, tmp_path):
+    """Do paths run in the same direction in all masters?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": [SQUARE[0][::-1]]})
+    assert_results_contain(
+        check(two_masters(tmp_path, "reversed", bold)),
+        FAIL,
+        "path-direction",
+        "with a contour reversed in one master...",
+    )
+
+
+@check_id("sources/consistent_anchors")
+def test_check_sources_consistent_anchors(check, tmp_path):
+    """Does each glyph have the same anchors in all masters?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    bold = glyphs_with(A={"unicodes": [0x41], "contours": SQUARE})
+    assert_results_contain(
+        check(two_masters(tmp_path, "missing", bold)),
+        FAIL,
+        "missing-anchors",
+        "with an anchor missing from one master...",
+    )
+
+    bold = glyphs_with(
+        B={"unicodes": [0x42], "contours": SQUARE, "anchors": [("top", 250, 700)]}
+    )
+    assert_results_contain(
+        check(two_masters(tmp_path, "extra", bold)),
+        FAIL,
+        "extra-anchors",
+        "with an extra anchor in one master...",
+    )
+
+
+@check_id("sources/consistent_codepoints")
+def test_check_sources_consistent_codepoints(check, tmp_path):
+    """Is each glyph encoded the same way in all masters?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    bold = glyphs_with(B={"unicodes": [0x43], "contours": SQUARE})
+    assert_results_contain(
+        check(two_masters(tmp_path, "different", bold)),
+        FAIL,
+        "different-codepoints",
+        "with a glyph encoded differently in one master...",
+    )
+
+
+@check_id("sources/consistent_glyphset")
+def test_check_sources_consistent_glyphset(check, tmp_path):
+    """Do all masters have the same glyphs?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with identical masters...")
+
+    message = assert_results_contain(
+        check(two_masters(tmp_path, "missing", glyphs_with(B=None))),
+        FAIL,
+        "missing-glyphs",
+        "with a glyph missing from one master...",
+    )
+    assert "B" in message
+
+    bold = glyphs_with(C={"unicodes": [0x43], "contours": SQUARE})
+    assert_results_contain(
+        check(two_masters(tmp_path, "extra", bold)),
+        FAIL,
+        "extra-glyphs",
+        "with an extra glyph in one master...",
+    )
+
+    # Sources are found relative to the designspace, not the working directory
+    path = two_masters(
+        tmp_path, "subdirectory", glyphs_with(B=None), ufo_directory="masters"
+    )
+    assert_results_contain(
+        check(path),
+        FAIL,
+        "missing-glyphs",
+        "with the sources in a subdirectory...",
+    )
+
+
+@check_id("sources/axes")
+def test_check_sources_axes(check, tmp_path):
+    """Are the axes and master locations well defined?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with a weight axis...")
+
+    path = two_masters(tmp_path, "tag", axes=[("WGHT", "Weight", 100, 400, 900)])
+    assert_PASS(check(path), "with an uppercase axis tag...")
+
+    path = two_masters(tmp_path, "lowercase", axes=[("wdth", "Weight", 100, 400, 900)])
+    assert_PASS(check(path), "with a registered lowercase axis tag...")
+
+    path = two_masters(
+        tmp_path, "unregistered", axes=[("abcd", "Weight", 100, 400, 900)]
+    )
+    assert_results_contain(
+        check(path),
+        WARN,
+        "unregistered-lowercase-axis-tag",
+        "with an unregistered lowercase axis tag...",
+    )
+
+    path = two_masters(tmp_path, "invalid", axes=[("wg", "Weight", 100, 400, 900)])
+    assert_results_contain(
+        check(path), FAIL, "invalid-axis-tag", "with a two-letter axis tag..."
+    )
+
+    path = designspace(
+        tmp_path / "duplicate",
+        [("Light", 100, None), ("Bold", 100, None)],
+        instances=[],
+    )
+    assert_results_contain(
+        check(path),
+        FAIL,
+        "duplicate-master-location",
+        "with two masters at the same location...",
+    )
+
+
+@check_id("sources/instances")
+def test_check_sources_instances(check, tmp_path):
+    """Are the instances uniquely named and placed within the design space?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with an instance per master...")
+
+    path = two_masters(tmp_path, "outside", instances=[("Black", 1000)])
+    assert_results_contain(
+        check(path),
+        FAIL,
+        "instance-outside-design-space",
+        "with an instance beyond the heaviest master...",
+    )
+
+    path = two_masters(tmp_path, "names", instances=[("Bold", 700), ("Bold", 900)])
+    assert_results_contain(
+        check(path),
+        FAIL,
+        "duplicate-instance-name",
+        "with two instances with the same name...",
+    )
+
+    path = two_masters(tmp_path, "locations", instances=[("Bold", 900), ("Heavy", 900)])
+    assert_results_contain(
+        check(path),
+        WARN,
+        "duplicate-instance-location",
+        "with two instances at the same location...",
+    )
+
+
+@check_id("sources/missing_unicodes")
+def test_check_sources_missing_unicodes(check, tmp_path):
+    """Are glyphs named after a codepoint encoded with it?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with encoded glyphs...")
+
+    glyphs = glyphs_with(uni0411={"contours": SQUARE})
+    path = designspace(tmp_path / "missing", [("Light", 100, glyphs)])
+    message = assert_results_contain(
+        check(path), WARN, "missing-unicode", "with an unencoded uni0411..."
+    )
+    assert "uni0411 (U+0411)" in message
+
+    glyphs = glyphs_with(B={"unicodes": [0x62], "contours": SQUARE})
+    path = designspace(tmp_path / "mismatched", [("Light", 100, glyphs)])
+    assert_results_contain(
+        check(path), WARN, "mismatched-unicode", "with B encoded as U+0062..."
+    )
+
+
+@check_id("designspace/consistent_kerning_groups")
+def test_check_designspace_consistent_kerning_groups(check, tmp_path):
+    """Are glyphs in the same kerning groups in all masters?"""
+
+    groups = {"public.kern1.O": ["A", "B"]}
+    path = designspace(
+        tmp_path / "good",
+        [("Light", 100, None), ("Bold", 900, None)],
+        groups={"Light": groups, "Bold": groups},
+    )
+    assert_PASS(check(path), "with the same groups in each master...")
+
+    path = designspace(
+        tmp_path / "missing",
+        [("Light", 100, None), ("Bold", 900, None)],
+        groups={"Light": groups, "Bold": {"public.kern1.O": ["A"]}},
+    )
+    message = assert_results_contain(
+        check(path),
+        FAIL,
+        "missing-from-group",
+        "with a glyph left out of a group in one master...",
+    )
+    assert "B in public.kern1.O" in message
+
+
+@check_id("designspace/consistent_kerning_groups")
+def test_check_designspace_unparseable(check, tmp_path):
+    """Is the reason a designspace could not be read reported?"""
+
+    path = tmp_path / "Broken.designspace"
+    path.write_text("<designspace><sources>")
+    results = list(check(str(path)))
+    assert results[0].status == ERROR
+    assert "Could not parse" in results[0].message.message
+
+
+@check_id("designspace/loadable_sources")
+def test_check_designspace_loadable_sources(check, tmp_path):
+    """Can all the sources of a designspace be loaded?"""
+
+    assert_PASS(check(two_masters(tmp_path, "good")), "with both UFOs present...")
+
+    path = two_masters(tmp_path, "missing")
+    shutil.rmtree(tmp_path / "missing" / "Test-Bold.ufo")
+    message = assert_results_contain(
+        check(path), FAIL, "unloadable-source", "with a UFO which is not there..."
+    )
+    assert "Test-Bold.ufo" in message
+
+
+def two_glyphs_masters(tmp_path, label, bold_glyphs=None, **kwargs):
+    """Write a Glyphs source with a Light and a Bold master, returning its path."""
+    return glyphs_source(
+        tmp_path / label,
+        [("Light", 100, None), ("Bold", 900, bold_glyphs)],
+        **kwargs,
+    )
+
+
+@check_id("sources/compatible_outlines")
+def test_check_sources_compatible_outlines_glyphs(check, tmp_path):
+    """Are the outlines of each glyph compatible across Glyphs masters?"""
+
+    path = two_glyphs_masters(tmp_path, "good")
+    assert_PASS(check(path), "with identical masters...")
+
+    extra_point = [SQUARE[0] + [(400, -10, "line")]]
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": extra_point})
+    message = assert_results_contain(
+        check(two_glyphs_masters(tmp_path, "points", bold)),
+        FAIL,
+        "point-count",
+        "with an extra point in one master...",
+    )
+    assert "Glyph B in Bold" in message
+
+
+@check_id("sources/path_direction")
+def test_check_sources_path_direction_glyphs(check, tmp_path):
+    """Do paths run in the same direction in all Glyphs masters?"""
+
+    bold = glyphs_with(B={"unicodes": [0x42], "contours": [SQUARE[0][::-1]]})
+    assert_results_contain(
+        check(two_glyphs_masters(tmp_path, "reversed", bold)),
+        FAIL,
+        "path-direction",
+        "with a contour reversed in one master...",
+    )
+
+
+@check_id("sources/consistent_glyphset")
+def test_check_sources_consistent_glyphset_glyphs(check, tmp_path):
+    """Does every glyph have a layer for each Glyphs master?"""
+
+    assert_results_contain(
+        check(two_glyphs_masters(tmp_path, "missing", glyphs_with(B=None))),
+        FAIL,
+        "missing-glyphs",
+        "with a glyph which has no layer for one master...",
+    )
+
+
+@check_id("sources/consistent_anchors")
+def test_check_sources_consistent_anchors_glyphs(check, tmp_path):
+    """Does each glyph have the same anchors in all Glyphs masters?"""
+
+    bold = glyphs_with(A={"unicodes": [0x41], "contours": SQUARE})
+    assert_results_contain(
+        check(two_glyphs_masters(tmp_path, "missing", bold)),
+        FAIL,
+        "missing-anchors",
+        "with an anchor missing from one master...",
+    )
+
+
+@check_id("sources/missing_unicodes")
+def test_check_sources_missing_unicodes_glyphs(check, tmp_path):
+    """Are glyphs in a Glyphs source named after a codepoint encoded with it?"""
+
+    assert_PASS(check(two_glyphs_masters(tmp_path, "good")), "with encoded glyphs...")
+
+    glyphs = glyphs_with(uni0411={"contours": SQUARE})
+    path = glyphs_source(tmp_path / "missing", [("Regular", 400, glyphs)])
+    assert_results_contain(
+        check(path), WARN, "missing-unicode", "with an unencoded uni0411..."
+    )
+
+
+@check_id("sources/instances")
+def test_check_sources_instances_glyphs(check, tmp_path):
+    """Are the instances of a Glyphs source within its design space?"""
+
+    assert_PASS(
+        check(two_glyphs_masters(tmp_path, "good")), "with an instance per master..."
+    )
+
+    path = two_glyphs_masters(tmp_path, "outside", instances=[("Black", 1000)])
+    assert_results_contain(
+        check(path),
+        FAIL,
+        "instance-outside-design-space",
+        "with an instance beyond the heaviest master...",
+    )
+
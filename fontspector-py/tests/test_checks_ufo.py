@@ -0,0 +1,189 @@
+import copy
+
+from conftest import check_id
+from fontbakery.codetesting import assert_PASS, assert_results_contain
+from fontbakery.status import FAIL, WARN
+
+from sourcebuilder import DEFAULT_GLYPHS, DEFAULT_INFO, SQUARE, ufo
+
+
+def glyphs_with(**extra):
+    """The default glyphs, with some more added."""
+    glyphs = copy.deepcopy(DEFAULT_GLYPHS)
+    glyphs.update(extra)
+    return glyphs
+
+
+@check_id("ufo/required_fields")
+def test_check_ufo_required_fields(check, tmp_path):
+    """Are the required fontinfo.plist fields set?"""
+
+    assert_PASS(check(ufo(tmp_path, "Good.ufo")), "with all fields set...")
+
+    for field in DEFAULT_INFO:
+        info = {k: v for k, v in DEFAULT_INFO.items() if k != field}
+        message = assert_results_contain(
+            check(ufo(tmp_path, f"No-{field}.ufo", info=info)),
+            FAIL,
+            "missing-field",
+            f"without {field}...",
+        )
+        assert field in message
+
+
+@check_id("ufo/duplicate_codepoints")
+def test_check_ufo_duplicate_codepoints(check, tmp_path):
+    """Is each codepoint assigned to only one glyph?"""
+
+    assert_PASS(check(ufo(tmp_path, "Good.ufo")), "with distinct codepoints...")
+
+    glyphs = glyphs_with(Aalt={"unicodes": [0x41], "contours": SQUARE})
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Duplicate.ufo", glyphs)),
+        FAIL,
+        "duplicate-codepoint",
+        "with U+0041 assigned to two glyphs...",
+    )
+    assert "U+0041" in message
+
+
+@check_id("ufo/glyph_names")
+def test_check_ufo_glyph_names(check, tmp_path):
+    """Are glyph names valid production names, or mapped to one?"""
+
+    assert_PASS(check(ufo(tmp_path, "Good.ufo")), "with production names...")
+
+    glyphs = glyphs_with(**{"A-cy": {"contours": SQUARE}})
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Unmapped.ufo", glyphs)),
+        WARN,
+        "non-production-name",
+        "with a glyph name containing a hyphen...",
+    )
+    assert "A-cy" in message
+
+    lib = {"public.postscriptNames": {"A-cy": "uni0410"}}
+    assert_PASS(
+        check(ufo(tmp_path, "Mapped.ufo", glyphs, lib=lib)),
+        "with the glyph given a production name...",
+    )
+
+    lib = {"public.postscriptNames": {"A-cy": "A-cy.prod"}}
+    assert_results_contain(
+        check(ufo(tmp_path, "Invalid.ufo", glyphs, lib=lib)),
+        FAIL,
+        "invalid-production-name",
+        "with the glyph given an invalid production name...",
+    )
+
+
+@check_id("ufo/lib_keys")
+def test_check_ufo_lib_keys(check, tmp_path):
+    """Do the lib keys which affect compilation match the glyphs?"""
+
+    assert_PASS(check(ufo(tmp_path, "NoLib.ufo")), "with no lib...")
+
+    lib = {"public.glyphOrder": [".notdef", "A", "B"]}
+    assert_PASS(
+        check(ufo(tmp_path, "Good.ufo", lib=lib)), "with a complete glyph order..."
+    )
+
+    lib = {"public.glyphOrder": [".notdef", "A"]}
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Unordered.ufo", lib=lib)),
+        WARN,
+        "glyphs-missing-from-glyph-order",
+        "with a glyph missing from public.glyphOrder...",
+    )
+    assert "B" in message
+
+    for key, value in [
+        ("public.glyphOrder", [".notdef", "A", "B", "C"]),
+        ("public.skipExportGlyphs", ["C"]),
+        ("public.postscriptNames", {"C": "uni0043"}),
+    ]:
+        message = assert_results_contain(
+            check(ufo(tmp_path, f"Unknown-{key}.ufo", lib={key: value})),
+            WARN,
+            "unknown-glyphs",
+            f"with {key} listing a glyph which is not in the font...",
+        )
+        assert key in message
+
+
+@check_id("ufo/groups")
+def test_check_ufo_groups(check, tmp_path):
+    """Are the groups free of cruft?"""
+
+    groups = {"public.kern1.A": ["A"], "public.kern2.B": ["B"]}
+    kerning = {"public.kern1.A": {"public.kern2.B": -10}}
+    assert_PASS(
+        check(ufo(tmp_path, "Good.ufo", groups=groups, kerning=kerning)),
+        "with kerning groups used by the kerning...",
+    )
+
+    assert_results_contain(
+        check(ufo(tmp_path, "Empty.ufo", groups={"empty": []})),
+        WARN,
+        "empty-group",
+        "with a group with no members...",
+    )
+
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Missing.ufo", groups={"letters": ["A", "C"]})),
+        WARN,
+        "missing-glyph",
+        "with a group containing a glyph which is not in the font...",
+    )
+    assert "C in letters" in message
+
+    assert_results_contain(
+        check(ufo(tmp_path, "Unused.ufo", groups=groups)),
+        WARN,
+        "unused-kerning-group",
+        "with kerning groups but no kerning...",
+    )
+
+    groups = {"letters": ["A", "B"], "capitals": ["B", "A"]}
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Identical.ufo", groups=groups)),
+        WARN,
+        "identical-groups",
+        "with two groups with the same members...",
+    )
+    assert "capitals, letters" in message
+
+
+@check_id("ufo/kerning_references")
+def test_check_ufo_kerning_references(check, tmp_path):
+    """Do kerning pairs refer to existing glyphs and groups?"""
+
+    groups = {"public.kern1.A": ["A"], "public.kern2.B": ["B"]}
+    kerning = {"public.kern1.A": {"public.kern2.B": -10}, "A": {"B": -5}}
+    assert_PASS(
+        check(ufo(tmp_path, "Good.ufo", groups=groups, kerning=kerning)),
+        "with pairs of existing glyphs and groups...",
+    )
+
+    message = assert_results_contain(
+        check(ufo(tmp_path, "Glyph.ufo", kerning={"A": {"C": -10}})),
+        FAIL,
+        "missing-glyph",
+        "with a pair kerning a glyph which is not in the font...",
+    )
+    assert "A C: C" in message
+
+    assert_results_contain(
+        check(ufo(tmp_path, "Group.ufo", kerning={"public.kern1.O": {"B": -10}})),
+        FAIL,
+        "missing-group",
+        "with a pair kerning an undefined group...",
+    )
+
+    kerning = {"public.kern2.B": {"A": -10}}
+    assert_results_contain(
+        check(ufo(tmp_path, "Side.ufo", groups=groups, kerning=kerning)),
+        FAIL,
+        "wrong-side-group",
+        "with a second-side group on the first side of a pair...",
+    )
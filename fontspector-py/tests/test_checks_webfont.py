@@ -0,0 +1,141 @@
+import os
+
+from fontTools.ttLib import TTFont
+from fontTools.ttLib.sfnt import WOFFFlavorData
+from fontTools.ttLib.woff2 import WOFF2FlavorData
+
+from conftest import check_id
+from fontbakery.codetesting import (
+    TEST_FILE,
+    assert_PASS,
+    assert_results_contain,
+)
+from fontbakery.status import FAIL, INFO, WARN
+
+GOOD_METADATA = b'<?xml version="1.0" encoding="UTF-8"?>\n<metadata version="1.0"><vendor name="Test"/></metadata>'
+
+
+def webfont(tmp_path, font, flavor, metadata=None, private_data=None, **kwargs):
+    """Save a font as a WOFF or WOFF2 file, returning its path."""
+    ttFont = TTFont(TEST_FILE(font))
+    ttFont.flavor = flavor
+    if flavor == "woff2":
+        ttFont.flavorData = WOFF2FlavorData(**kwargs)
+    else:
+        ttFont.flavorData = WOFFFlavorData()
+    ttFont.flavorData.metaData = metadata
+    ttFont.flavorData.privData = private_data
+    path = tmp_path / f"{os.path.splitext(os.path.basename(font))[0]}.{flavor}"
+    ttFont.save(path)
+    return str(path)
+
+
+@check_id("webfont/metadata")
+def test_check_webfont_metadata(check, tmp_path):
+    """Is the web font extended metadata block well-formed?"""
+
+    for flavor in ["woff", "woff2"]:
+        font = webfont(tmp_path, "mada/Mada-Regular.ttf", flavor)
+        assert_PASS(check(font), f"with a {flavor} file with no metadata...")
+
+        font = webfont(
+            tmp_path, "mada/Mada-Regular.ttf", flavor, metadata=GOOD_METADATA
+        )
+        assert_PASS(check(font), f"with a {flavor} file with good metadata...")
+
+        font = webfont(
+            tmp_path,
+            "mada/Mada-Regular.ttf",
+            flavor,
+            metadata=b'<metadata version="1.0"><vendor name="Test"></metadata>',
+        )
+        assert_results_contain(
+            check(font),
+            FAIL,
+            "malformed-xml",
+            f"with a {flavor} file whose metadata has a mismatched tag...",
+        )
+
+        font = webfont(
+            tmp_path,
+            "mada/Mada-Regular.ttf",
+            flavor,
+            metadata=b'<metadata version="2.0"></metadata>',
+        )
+        assert_results_contain(
+            check(font),
+            FAIL,
+            "malformed-xml",
+            f"with a {flavor} file with the wrong metadata version...",
+        )
+
+        font = webfont(
+            tmp_path, "mada/Mada-Regular.ttf", flavor, metadata=b"<metadata \xff>"
+        )
+        assert_results_contain(
+            check(font),
+            FAIL,
+            "not-utf8",
+            f"with a {flavor} file whose metadata is not UTF-8...",
+        )
+
+
+@check_id("webfont/private_data")
+def test_check_webfont_private_data(check, tmp_path):
+    """Does the web font contain a private data block?"""
+
+    for flavor in ["woff", "woff2"]:
+        font = webfont(tmp_path, "mada/Mada-Regular.ttf", flavor)
+        assert_PASS(check(font), f"with a {flavor} file with no private data...")
+
+        font = webfont(
+            tmp_path, "mada/Mada-Regular.ttf", flavor, private_data=b"build 1234"
+        )
+        message = assert_results_contain(
+            check(font),
+            WARN,
+            "private-data",
+            f"with a {flavor} file with private data...",
+        )
+        assert "10 bytes" in message
+
+
+@check_id("webfont/woff2_transforms")
+def test_check_webfont_woff2_transforms(check, tmp_path):
+    """Are the WOFF2 table transforms valid?"""
+
+    font = webfont(tmp_path, "mada/Mada-Regular.ttf", "woff2")
+    assert_PASS(check(font), "with the default glyf and loca transforms...")
+
+    font = webfont(
+        tmp_path,
+        "mada/Mada-Regular.ttf",
+        "woff2",
+        transformedTables={"glyf", "loca", "hmtx"},
+    )
+    assert_PASS(check(font), "with the hmtx table transformed too...")
+
+    font = webfont(tmp_path, "mada/Mada-Regular.ttf", "woff2", transformedTables=())
+    assert_PASS(check(font), "with no tables transformed...")
+
+
+@check_id("hinting_impact")
+def test_check_hinting_impact_webfont(check, tmp_path):
+    """Checks which read the font's binary data see the decompressed font."""
+
+    for flavor in ["woff", "woff2"]:
+        font = webfont(tmp_path, "nunito/Nunito-Regular.ttf", flavor)
+        assert_results_contain(
+            check(font), INFO, "size-impact", f"with a hinted {flavor} file..."
+        )
+
+
+@check_id("soft_dotted")
+def test_check_soft_dotted_webfont(check, tmp_path):
+    """Shaping checks run on web fonts as they do on the fonts inside them."""
+
+    for flavor in ["woff", "woff2"]:
+        font = webfont(tmp_path, "abeezee/ABeeZee-Regular.ttf", flavor)
+        assert_results_contain(
+            check(font), WARN, "soft-dotted", f"with a {flavor} file..."
+        )
@@ -2,7 +2,8 @@ use js_sys::{Reflect, Uint8Array};
 use wasm_bindgen::prelude::*;
 extern crate console_error_panic_hook;
 use fontspector_checkapi::{
-    Check, CheckResult, Context, Plugin, Registry, Testable, TestableCollection, TestableType,
+    Check, CheckResult, CheckSelection, Context, Plugin, Registry, Testable, TestableCollection,
+    TestableType,
 };
 use profile_googlefonts::GoogleFonts;
 use profile_opentype::OpenType;
@@ -33,11 +34,7 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
             let file: JsValue = Reflect::get(fonts, &filename).unwrap();
             let contents = Uint8Array::new(&file).to_vec();
 
-            Testable {
-                filename: filename.as_string().unwrap().into(),
-                source: None,
-                contents,
-            }
+            Testable::new_with_contents(filename.as_string().unwrap(), contents)
         })
         .collect();
     let collection = TestableCollection::from_testables(testables, None);
@@ -50,14 +47,12 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
         network_timeout: None,
         configuration: serde_json::Map::new(),
         check_metadata: serde_json::Value::Null,
-        full_lists: false,
-        cache: Default::default(),
+        ..Default::default()
     };
     let all_testables: Vec<TestableType> = collection.collection_and_files().collect();
 
     let checkorder: Vec<(String, &TestableType, &Check, Context)> = profile.check_order(
-        &None,
-        &None,
+        &CheckSelection::default(),
         &registry,
         context,
         serde_json::Map::new(),
@@ -67,11 +62,9 @@ pub fn check_fonts(fonts: &JsValue, profile: &str) -> Result<String, JsValue> {
     let results: Vec<CheckResult> = checkorder
         .iter()
         .map(|(sectionname, testable, check, context)| {
-            (
-                testable,
-                check,
-                check.run(testable, context, Some(sectionname)),
-            )
+            let result = check.run(testable, context, Some(sectionname));
+            context.typed_cache.check_finished();
+            (testable, check, result)
         })
         .flat_map(|(_, _, result)| result)
         .collect();
@@ -81,6 +81,57 @@ function showError(msg) {
   $("#errorText").html(msg);
 }
 
+/** Format a subresult's message and items as Markdown
+ *
+ * Long lists of items are abbreviated, as in the command line reports.
+ * @param {Object} log - A subresult
+ */
+function messageWithItems(log) {
+  const message = log.message || "";
+  const items = log.items || [];
+  if (items.length == 0) {
+    return message;
+  }
+  const list = items
+    .slice(0, 9)
+    .map((item) => `* ${typeof item == "string" ? item : JSON.stringify(item)}`);
+  if (items.length > 9) {
+    list.push(`... and ${items.length - 9} others`);
+  }
+  return `${message}\n\n${list.join("\n")}`;
+}
+
+/** Escape text for inclusion in HTML, including in attribute values
+ * @param {string} text - The text to escape
+ */
+function escapeHtml(text) {
+  const entities = {
+    "&": "&amp;",
+    "<": "&lt;",
+    ">": "&gt;",
+    '"': "&quot;",
+    "'": "&#39;",
+  };
+  return String(text).replace(/[&<>"']/g, (c) => entities[c]);
+}
+
+/** Show the pictures of glyphs attached to a subresult
+ *
+ * The pictures are SVG documents drawn by the checks themselves; glyph
+ * names come from the font, so they are escaped.
+ * @param {Object} log - A subresult
+ */
+function glyphImages(log) {
+  return (log.images || [])
+    .map(
+      (image) =>
+        `<span class="glyph-image" title="${escapeHtml(
+          image.glyph.glyph_name || ""
+        )}">${image.svg}</span>`
+    )
+    .join("");
+}
+
 /** Record a result and add it to the output pills
  *
  * Used to display Python errors.
@@ -182,7 +233,8 @@ function showResult(data) {
                   class="bg-${log.severity} font-weight-bold">
                   ${log.severity}
                 </span>:
-                <div>${CmarkGFM.convert(log.message || "")}</div>
+                <div>${CmarkGFM.convert(messageWithItems(log))}</div>
+                <div>${glyphImages(log)}</div>
               </li>
             `)
         );
@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fontspector_checkapi::{norad::Font, prelude::*, DESIGNSPACE};
+
+use crate::checks::ufo::{KERN1_PREFIX, KERN2_PREFIX};
+
+/// The kerning groups each glyph belongs to
+fn kerning_groups(font: &Font) -> BTreeMap<String, BTreeSet<String>> {
+    let mut groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (group, members) in font.groups.iter() {
+        if !(group.starts_with(KERN1_PREFIX) || group.starts_with(KERN2_PREFIX)) {
+            continue;
+        }
+        for glyph in members {
+            groups
+                .entry(glyph.to_string())
+                .or_default()
+                .insert(group.to_string());
+        }
+    }
+    groups
+}
+
+#[check(
+    id = "designspace/consistent_kerning_groups",
+    rationale = "
+        Kerning is interpolated between masters pair by pair, and class kerning
+        pairs refer to kerning groups. If a glyph belongs to a kerning group in
+        one master but not in another, it will be kerned differently at each
+        end of the design space, and the interpolated kerning will be wrong.
+    ",
+    proposal = "None",
+    aliases = "designspace_has_consistent_groups",
+    title = "Check that glyphs are in the same kerning groups in all masters.",
+    applies_to = "DESIGNSPACE"
+)]
+fn consistent_kerning_groups(t: &Testable, context: &Context) -> CheckFnResult {
+    let designspace = DESIGNSPACE
+        .from_testable_cached(t, context)
+        .map_err(CheckError::Error)?
+        .ok_or(CheckError::Error("Not a designspace file".to_string()))?;
+    // Sparse masters are layers of another UFO and have no groups of their own
+    let masters = designspace
+        .masters()
+        .filter(|m| m.source.layer.is_none())
+        .collect::<Vec<_>>();
+    let Some((reference, others)) = masters.split_first() else {
+        return Ok(Status::just_one_fail(
+            "no-sources",
+            "Couldn't load any sources",
+        ));
+    };
+    skip!(
+        others.is_empty(),
+        "not-enough-sources",
+        "Not enough sources to compare"
+    );
+    let none = BTreeSet::new();
+    let expected = kerning_groups(reference.font);
+    let mut problems = vec![];
+    for other in others {
+        let found = kerning_groups(other.font);
+        let glyphs = expected.keys().chain(found.keys()).collect::<BTreeSet<_>>();
+        let mut missing = vec![];
+        let mut extra = vec![];
+        for glyph in glyphs {
+            let (expected_groups, found_groups) = (
+                expected.get(glyph).unwrap_or(&none),
+                found.get(glyph).unwrap_or(&none),
+            );
+            for group in expected_groups.difference(found_groups) {
+                missing.push(format!("{} in {}", glyph, group));
+            }
+            for group in found_groups.difference(expected_groups) {
+                extra.push(format!("{} in {}", glyph, group));
+            }
+        }
+        if !missing.is_empty() {
+            problems.push(
+                Status::fail(
+                    "missing-from-group",
+                    &format!(
+                        "The following glyphs are in kerning groups in {} but not in {}:",
+                        reference.name(),
+                        other.name()
+                    ),
+                )
+                .with_items(missing),
+            );
+        }
+        if !extra.is_empty() {
+            problems.push(
+                Status::fail(
+                    "extra-in-group",
+                    &format!(
+                        "The following glyphs are in kerning groups in {} but not in {}:",
+                        other.name(),
+                        reference.name()
+                    ),
+                )
+                .with_items(extra),
+            );
+        }
+    }
+    return_result(problems)
+}
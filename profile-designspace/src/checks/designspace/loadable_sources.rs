@@ -0,0 +1,31 @@
+use fontspector_checkapi::{prelude::*, DESIGNSPACE};
+
+#[check(
+    id = "designspace/loadable_sources",
+    rationale = "
+        Every source listed in a designspace must be a UFO which can be read,
+        and a sparse source must refer to a layer which exists in its UFO.
+        Otherwise the font cannot be built, and the other source checks can
+        only look at the sources which did load.
+    ",
+    proposal = "None",
+    title = "Check that all the sources of a designspace can be loaded.",
+    applies_to = "DESIGNSPACE"
+)]
+fn loadable_sources(t: &Testable, context: &Context) -> CheckFnResult {
+    let designspace = DESIGNSPACE
+        .from_testable_cached(t, context)
+        .map_err(CheckError::Error)?
+        .ok_or(CheckError::Error("Not a designspace file".to_string()))?;
+    return_result(
+        designspace
+            .load_errors()
+            .map(|(source, error)| {
+                Status::fail(
+                    "unloadable-source",
+                    &format!("Could not load source {}: {}", source.filename, error),
+                )
+            })
+            .collect(),
+    )
+}
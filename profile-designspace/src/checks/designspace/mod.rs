@@ -0,0 +1,4 @@
+mod consistent_kerning_groups;
+mod loadable_sources;
+pub use consistent_kerning_groups::consistent_kerning_groups;
+pub use loadable_sources::loadable_sources;
@@ -0,0 +1,3 @@
+pub mod designspace;
+pub mod sources;
+pub mod ufo;
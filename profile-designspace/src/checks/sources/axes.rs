@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+/// Axis tags registered in the OpenType specification
+const REGISTERED_AXES: [&str; 5] = ["ital", "opsz", "slnt", "wdth", "wght"];
+
+#[check(
+    id = "sources/axes",
+    rationale = "
+        Each axis of the design space needs a unique name and a four-character
+        tag. Only the axes registered in the OpenType specification may use
+        lowercase tags; custom axes must use uppercase tags. Every master must
+        also have a location on every axis, and no two masters may share the
+        same location, or the compiler cannot tell which of them to use there.
+    ",
+    proposal = "None",
+    title = "Check the axis definitions and master locations of a source.",
+    applies_to = "SOURCES"
+)]
+fn axes(t: &Testable, context: &Context) -> CheckFnResult {
+    let sources = Sources::new(t, context)?;
+    let mut problems = vec![];
+    let mut names = HashSet::new();
+    let mut tags = HashSet::new();
+    for axis in sources.axes.iter() {
+        if !names.insert(&axis.name) {
+            problems.push(Status::fail(
+                "duplicate-axis-name",
+                &format!("There is more than one axis named {}", axis.name),
+            ));
+        }
+        if !tags.insert(&axis.tag) {
+            problems.push(Status::fail(
+                "duplicate-axis-tag",
+                &format!("There is more than one axis with the tag {}", axis.tag),
+            ));
+        }
+        if axis.tag.len() != 4 || !axis.tag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            problems.push(Status::fail(
+                "invalid-axis-tag",
+                &format!(
+                    "Axis {} has the tag \"{}\", which is not four letters or digits",
+                    axis.name, axis.tag
+                ),
+            ));
+        } else if axis.tag.chars().any(|c| c.is_ascii_lowercase())
+            && !REGISTERED_AXES.contains(&axis.tag.as_str())
+        {
+            problems.push(Status::warn(
+                "unregistered-lowercase-axis-tag",
+                &format!(
+                    "Axis {} has the lowercase tag {}, which is reserved for registered axes",
+                    axis.name, axis.tag
+                ),
+            ));
+        }
+    }
+    let mut seen_locations: Vec<(&Vec<Option<f64>>, &str)> = vec![];
+    for master in sources.masters.iter() {
+        let missing = sources
+            .axes
+            .iter()
+            .enumerate()
+            .filter(|(ix, _)| master.location.get(*ix).copied().flatten().is_none())
+            .map(|(_, axis)| axis.name.as_str())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            problems.push(Status::fail(
+                "missing-master-location",
+                &format!(
+                    "Master {} has no location on the {} axis",
+                    master.name,
+                    missing.join(", ")
+                ),
+            ));
+            continue;
+        }
+        if let Some((_, other)) = seen_locations
+            .iter()
+            .find(|(location, _)| *location == &master.location)
+        {
+            problems.push(Status::fail(
+                "duplicate-master-location",
+                &format!(
+                    "Masters {} and {} are at the same location",
+                    other, master.name
+                ),
+            ));
+        }
+        seen_locations.push((&master.location, master.name.as_str()));
+    }
+    return_result(problems)
+}
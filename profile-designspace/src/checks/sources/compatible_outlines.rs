@@ -0,0 +1,69 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+#[check(
+    id = "sources/compatible_outlines",
+    rationale = "
+        To interpolate between masters, each glyph must have the same structure
+        in every master: the same number of contours, the same number of points
+        in each contour, and the same components in the same order. Finding
+        incompatibilities in the sources is much easier than working out why
+        the compiler rejected them.
+    ",
+    proposal = "None",
+    title = "Check that glyph outlines are compatible across masters.",
+    applies_to = "SOURCES"
+)]
+fn compatible_outlines(t: &Testable, context: &Context) -> CheckFnResult {
+    Sources::new(t, context)?.compare_glyphs(|glyph, other| {
+        let mut problems = vec![];
+        if glyph.contours.len() != other.contours.len() {
+            problems.push((
+                "contour-count",
+                format!(
+                    "has {} contours instead of {}",
+                    other.contours.len(),
+                    glyph.contours.len()
+                ),
+            ));
+        } else {
+            for (ix, (contour, other_contour)) in
+                glyph.contours.iter().zip(other.contours.iter()).enumerate()
+            {
+                if contour.len() != other_contour.len() {
+                    problems.push((
+                        "point-count",
+                        format!(
+                            "has {} points instead of {} in contour {}",
+                            other_contour.len(),
+                            contour.len(),
+                            ix
+                        ),
+                    ));
+                }
+            }
+        }
+        let (bases, other_bases) = (&glyph.components, &other.components);
+        if bases.len() != other_bases.len() {
+            problems.push((
+                "component-count",
+                format!(
+                    "has {} components instead of {}",
+                    other_bases.len(),
+                    bases.len()
+                ),
+            ));
+        } else if bases != other_bases {
+            problems.push((
+                "component-order",
+                format!(
+                    "has components [{}] instead of [{}]",
+                    other_bases.join(", "),
+                    bases.join(", ")
+                ),
+            ));
+        }
+        problems
+    })
+}
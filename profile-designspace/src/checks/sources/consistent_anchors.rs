@@ -0,0 +1,44 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+#[check(
+    id = "sources/consistent_anchors",
+    rationale = "
+        Each glyph should have the same set of anchors in all masters. Mark
+        positioning is interpolated along with the outlines, so an anchor
+        which is missing from one master leaves the compiler with nothing
+        to interpolate it from.
+    ",
+    proposal = "None",
+    title = "Check that the glyphs have the same anchors in all masters.",
+    applies_to = "SOURCES"
+)]
+fn consistent_anchors(t: &Testable, context: &Context) -> CheckFnResult {
+    Sources::new(t, context)?.compare_glyphs(|glyph, other| {
+        let mut problems = vec![];
+        let missing = glyph
+            .anchors
+            .difference(&other.anchors)
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            problems.push((
+                "missing-anchors",
+                format!("is missing anchors {}", missing.join(", ")),
+            ));
+        }
+        let extra = other
+            .anchors
+            .difference(&glyph.anchors)
+            .cloned()
+            .collect::<Vec<_>>();
+        if !extra.is_empty() {
+            problems.push((
+                "extra-anchors",
+                format!("has extra anchors {}", extra.join(", ")),
+            ));
+        }
+        problems
+    })
+}
@@ -0,0 +1,42 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::{MasterGlyph, Sources};
+
+/// The codepoints of a glyph, formatted for display
+fn codepoints(glyph: &MasterGlyph) -> String {
+    if glyph.codepoints.is_empty() {
+        "no codepoints".to_string()
+    } else {
+        glyph
+            .codepoints
+            .iter()
+            .map(|c| format!("U+{:04X}", c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[check(
+    id = "sources/consistent_codepoints",
+    rationale = "
+        The Unicode codepoints assigned to a glyph should be the same in all
+        masters; otherwise the compiled font will take its character mapping
+        from one master and silently ignore the others.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3168",
+    aliases = "designspace_has_consistent_codepoints",
+    title = "Check that the glyphs have the same codepoints in all masters.",
+    applies_to = "SOURCES"
+)]
+fn consistent_codepoints(t: &Testable, context: &Context) -> CheckFnResult {
+    Sources::new(t, context)?.compare_glyphs(|glyph, other| {
+        if glyph.codepoints == other.codepoints {
+            vec![]
+        } else {
+            vec![(
+                "different-codepoints",
+                format!("has {} instead of {}", codepoints(other), codepoints(glyph)),
+            )]
+        }
+    })
+}
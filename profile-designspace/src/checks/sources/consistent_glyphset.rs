@@ -0,0 +1,58 @@
+use std::collections::BTreeSet;
+
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+#[check(
+    id = "sources/consistent_glyphset",
+    rationale = "
+        All full masters of a source must contain the same glyphs, or the
+        glyphs which are missing from some masters cannot be interpolated.
+        (Sparse masters, which are layers of another UFO in a designspace, are
+        allowed to contain only some of the glyphs.)
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3168",
+    aliases = "designspace_has_consistent_glyphset",
+    title = "Check that all masters have the same glyph set.",
+    applies_to = "SOURCES"
+)]
+fn consistent_glyphset(t: &Testable, context: &Context) -> CheckFnResult {
+    let sources = Sources::new(t, context)?;
+    let (reference, others) = match sources.reference_and_others() {
+        Ok(split) => split,
+        Err(status) => return return_result(vec![status]),
+    };
+    let reference_glyphs = reference.glyphs.keys().collect::<BTreeSet<_>>();
+    let mut problems = vec![];
+    for other in others.iter().filter(|m| !m.sparse) {
+        let glyphs = other.glyphs.keys().collect::<BTreeSet<_>>();
+        let missing = reference_glyphs.difference(&glyphs).collect::<Vec<_>>();
+        if !missing.is_empty() {
+            problems.push(
+                Status::fail(
+                    "missing-glyphs",
+                    &format!(
+                        "The following glyphs are in {} but missing from {}:",
+                        reference.name, other.name
+                    ),
+                )
+                .with_items(missing.iter().map(|item| item.to_string())),
+            );
+        }
+        let extra = glyphs.difference(&reference_glyphs).collect::<Vec<_>>();
+        if !extra.is_empty() {
+            problems.push(
+                Status::fail(
+                    "extra-glyphs",
+                    &format!(
+                        "The following glyphs are in {} but missing from {}:",
+                        other.name, reference.name
+                    ),
+                )
+                .with_items(extra.iter().map(|item| item.to_string())),
+            );
+        }
+    }
+    return_result(problems)
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+#[check(
+    id = "sources/instances",
+    rationale = "
+        Instances become the named instances of a variable font and the static
+        fonts built from the source, so each needs a unique name and a unique
+        location. An instance outside the range covered by the masters can
+        only be produced by extrapolation, which compilers do not do.
+    ",
+    proposal = "None",
+    title = "Check the instance definitions of a source.",
+    applies_to = "SOURCES"
+)]
+fn instances(t: &Testable, context: &Context) -> CheckFnResult {
+    let sources = Sources::new(t, context)?;
+    skip!(
+        sources.instances.is_empty(),
+        "no-instances",
+        "The source defines no instances"
+    );
+    let mut problems = vec![];
+    // The extent of the masters along each axis
+    let ranges = (0..sources.axes.len())
+        .map(|ix| {
+            let values = sources
+                .masters
+                .iter()
+                .flat_map(|m| m.location.get(ix).copied().flatten());
+            values.clone().reduce(f64::min).zip(values.reduce(f64::max))
+        })
+        .collect::<Vec<_>>();
+    let mut names = HashSet::new();
+    let mut seen_locations: Vec<(&Vec<Option<f64>>, &str)> = vec![];
+    for instance in sources.instances.iter() {
+        if !names.insert(&instance.name) {
+            problems.push(Status::fail(
+                "duplicate-instance-name",
+                &format!("There is more than one instance named {}", instance.name),
+            ));
+        }
+        if let Some((_, other)) = seen_locations
+            .iter()
+            .find(|(location, _)| *location == &instance.location)
+        {
+            problems.push(Status::warn(
+                "duplicate-instance-location",
+                &format!(
+                    "Instances {} and {} are at the same location",
+                    other, instance.name
+                ),
+            ));
+        }
+        seen_locations.push((&instance.location, instance.name.as_str()));
+        for ((axis, value), range) in sources
+            .axes
+            .iter()
+            .zip(instance.location.iter())
+            .zip(ranges.iter())
+        {
+            if let (Some(value), Some((min, max))) = (value, range) {
+                if value < min || value > max {
+                    problems.push(Status::fail(
+                        "instance-outside-design-space",
+                        &format!(
+                            "Instance {} is at {}={}, outside the masters' range of {} to {}",
+                            instance.name, axis.name, value, min, max
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    return_result(problems)
+}
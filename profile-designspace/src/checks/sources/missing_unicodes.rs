@@ -0,0 +1,98 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::Sources;
+
+/// The codepoint a glyph name implies, if it is named after one
+///
+/// This covers `uniXXXX` and `uXXXX` to `uXXXXXX` names, and the names of the
+/// basic Latin letters.
+fn implied_codepoint(name: &str) -> Option<u32> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return c.is_ascii_alphabetic().then_some(c as u32);
+    }
+    let hex = if let Some(hex) = name.strip_prefix("uni") {
+        (hex.len() == 4).then_some(hex)?
+    } else {
+        let hex = name.strip_prefix('u')?;
+        (4..=6).contains(&hex.len()).then_some(hex)?
+    };
+    if !hex
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_lowercase())
+    {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+#[check(
+    id = "sources/missing_unicodes",
+    rationale = "
+        Glyphs named after a Unicode codepoint, such as `uni0411` or `A`, are
+        almost always meant to be encoded with that codepoint. If the codepoint
+        is missing from the source, the glyph will be unreachable in the
+        compiled font; if it is different, either the name or the codepoint is
+        wrong.
+    ",
+    proposal = "None",
+    title = "Check that glyphs named after a codepoint are encoded with it.",
+    applies_to = "SOURCES"
+)]
+fn missing_unicodes(t: &Testable, context: &Context) -> CheckFnResult {
+    let sources = Sources::new(t, context)?;
+    let Some(reference) = sources.reference() else {
+        return Ok(Status::just_one_fail(
+            "no-sources",
+            "Couldn't load any sources",
+        ));
+    };
+    let mut missing = vec![];
+    let mut mismatched = vec![];
+    for (name, glyph) in reference.glyphs.iter() {
+        let Some(codepoint) = implied_codepoint(name) else {
+            continue;
+        };
+        if glyph.codepoints.is_empty() {
+            missing.push(format!("{} (U+{:04X})", name, codepoint));
+        } else if !glyph.codepoints.contains(&codepoint) {
+            mismatched.push(format!(
+                "{} is encoded as {}",
+                name,
+                glyph
+                    .codepoints
+                    .iter()
+                    .map(|c| format!("U+{:04X}", c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+    let mut problems = vec![];
+    if !missing.is_empty() {
+        problems.push(
+            Status::warn(
+                "missing-unicode",
+                &format!(
+                    "The following glyphs in {} are named after a codepoint but are not encoded:",
+                    reference.name
+                ),
+            )
+            .with_items(missing),
+        );
+    }
+    if !mismatched.is_empty() {
+        problems.push(
+            Status::warn(
+                "mismatched-unicode",
+                &format!(
+                    "The following glyphs in {} are encoded with a different codepoint \
+                     from the one they are named after:",
+                    reference.name
+                ),
+            )
+            .with_items(mismatched),
+        );
+    }
+    return_result(problems)
+}
@@ -0,0 +1,18 @@
+mod axes;
+mod compatible_outlines;
+mod consistent_anchors;
+mod consistent_codepoints;
+mod consistent_glyphset;
+mod instances;
+mod missing_unicodes;
+mod path_direction;
+mod start_points;
+pub use axes::axes;
+pub use compatible_outlines::compatible_outlines;
+pub use consistent_anchors::consistent_anchors;
+pub use consistent_codepoints::consistent_codepoints;
+pub use consistent_glyphset::consistent_glyphset;
+pub use instances::instances;
+pub use missing_unicodes::missing_unicodes;
+pub use path_direction::path_direction;
+pub use start_points::start_points;
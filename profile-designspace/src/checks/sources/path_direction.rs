@@ -0,0 +1,39 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::{OutlinePoint, Sources};
+
+/// Is the contour drawn clockwise?
+fn clockwise(points: &[OutlinePoint]) -> bool {
+    let total: f64 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(point, next)| (next.x - point.x) * (next.y + point.y))
+        .sum();
+    total > 0.0
+}
+
+#[check(
+    id = "sources/path_direction",
+    rationale = "Make sure the paths have the same direction across all masters.",
+    proposal = "chat",
+    aliases = "designspace/path_direction",
+    title = "Check path direction.",
+    applies_to = "SOURCES"
+)]
+fn path_direction(t: &Testable, context: &Context) -> CheckFnResult {
+    Sources::new(t, context)?.compare_glyphs(|glyph, other| {
+        glyph
+            .contours
+            .iter()
+            .zip(other.contours.iter())
+            .enumerate()
+            .filter(|(_, (contour, other_contour))| clockwise(contour) != clockwise(other_contour))
+            .map(|(contour_ix, _)| {
+                (
+                    "path-direction",
+                    format!("has a different path direction in contour {}", contour_ix),
+                )
+            })
+            .collect()
+    })
+}
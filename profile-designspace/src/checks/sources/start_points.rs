@@ -0,0 +1,61 @@
+use fontspector_checkapi::prelude::*;
+
+use crate::masters::{OutlinePoint, PointKind, Sources};
+
+/// The sequence of point kinds around a contour
+fn point_kinds(contour: &[OutlinePoint]) -> Vec<PointKind> {
+    contour.iter().map(|p| p.kind).collect()
+}
+
+#[check(
+    id = "sources/start_points",
+    rationale = "
+        Contours are interpolated point by point, starting from each contour's
+        first point. If a contour starts at a different point in one master,
+        the contour will be compatible on paper but will twist and collapse
+        in the interpolated instances.
+
+        We detect this by comparing the types of the points (on-curve, off-curve
+        and so on) around the contour: if they only line up once the contour is
+        rotated to start at another point, the start point has moved.
+    ",
+    proposal = "None",
+    title = "Check that contours start at the same point in all masters.",
+    applies_to = "SOURCES"
+)]
+fn start_points(t: &Testable, context: &Context) -> CheckFnResult {
+    Sources::new(t, context)?.compare_glyphs(|glyph, other| {
+        let mut problems = vec![];
+        for (ix, (contour, other_contour)) in
+            glyph.contours.iter().zip(other.contours.iter()).enumerate()
+        {
+            let (kinds, other_kinds) = (point_kinds(contour), point_kinds(other_contour));
+            if kinds.len() != other_kinds.len() || kinds == other_kinds {
+                // Different point counts are reported by sources/compatible_outlines
+                continue;
+            }
+            let rotation = (1..kinds.len()).find(|&offset| {
+                kinds
+                    .iter()
+                    .cycle()
+                    .skip(offset)
+                    .zip(other_kinds.iter())
+                    .all(|(a, b)| a == b)
+            });
+            problems.push(match rotation {
+                Some(offset) => (
+                    "start-point",
+                    format!(
+                        "starts contour {} at a different point (offset by {})",
+                        ix, offset
+                    ),
+                ),
+                None => (
+                    "point-types",
+                    format!("has different types of points in contour {}", ix),
+                ),
+            });
+        }
+        problems
+    })
+}
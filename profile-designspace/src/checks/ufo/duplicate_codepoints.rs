@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use fontspector_checkapi::prelude::*;
+
+use super::ufo;
+
+#[check(
+    id = "ufo/duplicate_codepoints",
+    rationale = "
+        A codepoint can only be mapped to one glyph. If several glyphs claim the
+        same codepoint, the compiler picks one of them and the others become
+        unreachable, which usually means one of them was a copy-and-paste error.
+    ",
+    proposal = "None",
+    title = "Check that no codepoint is assigned to more than one glyph.",
+    applies_to = "UFO"
+)]
+fn duplicate_codepoints(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let mut glyphs_by_codepoint: BTreeMap<char, Vec<String>> = BTreeMap::new();
+    for glyph in font.default_layer().iter() {
+        for codepoint in glyph.codepoints.iter() {
+            glyphs_by_codepoint
+                .entry(codepoint)
+                .or_default()
+                .push(glyph.name().to_string());
+        }
+    }
+    return_result(
+        glyphs_by_codepoint
+            .into_iter()
+            .filter(|(_, glyphs)| glyphs.len() > 1)
+            .map(|(codepoint, glyphs)| {
+                Status::fail(
+                    "duplicate-codepoint",
+                    &format!(
+                        "U+{:04X} is assigned to more than one glyph:",
+                        codepoint as u32
+                    ),
+                )
+                .with_items(glyphs)
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,79 @@
+use fontspector_checkapi::prelude::*;
+
+use super::ufo;
+
+/// Can this name be used as a glyph name in a compiled font?
+///
+/// Production glyph names are at most 63 characters from `A-Z`, `a-z`, `0-9`,
+/// `.` and `_`, and do not start with a digit or a period (except `.notdef`
+/// and `.null`).
+fn is_production_name(name: &str) -> bool {
+    if name == ".notdef" || name == ".null" {
+        return true;
+    }
+    !name.is_empty()
+        && name.len() <= 63
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+}
+
+#[check(
+    id = "ufo/glyph_names",
+    rationale = "
+        UFO glyph names can contain almost anything, but the glyph names in a
+        compiled font are restricted to a small set of characters. Glyphs with
+        other names must be given a production name through the
+        `public.postscriptNames` lib key, or the compiler will rename them
+        (or reject them) in ways the designer does not control.
+    ",
+    proposal = "None",
+    title = "Check that glyph names are valid production names.",
+    applies_to = "UFO"
+)]
+fn glyph_names(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let postscript_names = font
+        .lib
+        .get("public.postscriptNames")
+        .and_then(|v| v.as_dictionary());
+    let mut invalid = vec![];
+    let mut unmapped = vec![];
+    for glyph in font.default_layer().iter() {
+        let name = glyph.name().to_string();
+        if is_production_name(&name) {
+            continue;
+        }
+        match postscript_names
+            .and_then(|names| names.get(&name))
+            .and_then(|v| v.as_string())
+        {
+            Some(production) if is_production_name(production) => {}
+            Some(production) => invalid.push(format!("{} (mapped to {})", name, production)),
+            None => unmapped.push(name),
+        }
+    }
+    let mut problems = vec![];
+    if !invalid.is_empty() {
+        problems.push(
+            Status::fail(
+                "invalid-production-name",
+                "The following glyphs are given invalid production names in \
+                 public.postscriptNames:",
+            )
+            .with_items(invalid),
+        );
+    }
+    if !unmapped.is_empty() {
+        problems.push(
+            Status::warn(
+                "non-production-name",
+                "The following glyph names are not valid production names, and have no \
+                 entry in public.postscriptNames:",
+            )
+            .with_items(unmapped),
+        );
+    }
+    return_result(problems)
+}
@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fontspector_checkapi::prelude::*;
+
+use super::{ufo, KERN1_PREFIX, KERN2_PREFIX};
+
+#[check(
+    id = "ufo/groups",
+    rationale = "
+        Groups accumulate cruft: groups with no members, members listed twice,
+        glyphs which have since been deleted, and kerning groups which no kerning
+        pair uses any more. None of these break the build on their own, but they
+        make the kerning harder to maintain and hide real mistakes.
+
+        A glyph may also only belong to one kerning group on each side; if it
+        is in more than one, which group's kerning applies is undefined.
+    ",
+    proposal = "None",
+    title = "Check for empty, redundant and inconsistent groups.",
+    applies_to = "UFO"
+)]
+fn groups(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let mut problems = vec![];
+    let used_in_kerning = font
+        .kerning
+        .iter()
+        .flat_map(|(first, seconds)| std::iter::once(first).chain(seconds.keys()))
+        .map(|name| name.to_string())
+        .collect::<BTreeSet<_>>();
+    let mut kerning_group_of: BTreeMap<(String, &str), Vec<String>> = BTreeMap::new();
+    let mut groups_by_members: BTreeMap<Vec<String>, Vec<String>> = BTreeMap::new();
+    let mut duplicate_members = vec![];
+    let mut missing_glyphs = vec![];
+    for (group, members) in font.groups.iter() {
+        if members.is_empty() {
+            problems.push(Status::warn(
+                "empty-group",
+                &format!("Group {} has no members", group),
+            ));
+            continue;
+        }
+        let mut seen = BTreeSet::new();
+        for member in members {
+            if !seen.insert(member.to_string()) {
+                duplicate_members.push(format!("{} in {}", member, group));
+            }
+            if font.default_layer().get_glyph(member).is_none() {
+                missing_glyphs.push(format!("{} in {}", member, group));
+            }
+        }
+        let side = [KERN1_PREFIX, KERN2_PREFIX]
+            .into_iter()
+            .find(|prefix| group.starts_with(*prefix));
+        if let Some(side) = side {
+            for member in seen.iter() {
+                kerning_group_of
+                    .entry((member.clone(), side))
+                    .or_default()
+                    .push(group.to_string());
+            }
+            if !used_in_kerning.contains(&group.to_string()) {
+                problems.push(Status::warn(
+                    "unused-kerning-group",
+                    &format!("Kerning group {} is not used by any kerning pair", group),
+                ));
+            }
+        }
+        groups_by_members
+            .entry(seen.into_iter().collect())
+            .or_default()
+            .push(group.to_string());
+    }
+    if !duplicate_members.is_empty() {
+        problems.push(
+            Status::warn(
+                "duplicate-member",
+                "The following glyphs are listed more than once in a group:",
+            )
+            .with_items(duplicate_members),
+        );
+    }
+    if !missing_glyphs.is_empty() {
+        problems.push(
+            Status::warn(
+                "missing-glyph",
+                "The following group members are not in the font:",
+            )
+            .with_items(missing_glyphs),
+        );
+    }
+    for ((glyph, _), groups) in kerning_group_of.iter() {
+        if groups.len() > 1 {
+            problems.push(Status::fail(
+                "glyph-in-multiple-kerning-groups",
+                &format!(
+                    "Glyph {} is in more than one kerning group on the same side: {}",
+                    glyph,
+                    groups.join(", ")
+                ),
+            ));
+        }
+    }
+    for groups in groups_by_members.values() {
+        if groups.len() > 1 {
+            problems.push(Status::warn(
+                "identical-groups",
+                &format!("Groups {} have the same members", groups.join(", ")),
+            ));
+        }
+    }
+    return_result(problems)
+}
@@ -0,0 +1,71 @@
+use fontspector_checkapi::prelude::*;
+
+use super::{ufo, KERN1_PREFIX, KERN2_PREFIX};
+
+#[check(
+    id = "ufo/kerning_references",
+    rationale = "
+        Each side of a kerning pair must be either a glyph in the font or a
+        kerning group defined in groups.plist, and groups must be used on the
+        correct side: `public.kern1.` groups on the left of the pair and
+        `public.kern2.` groups on the right. Compilers drop pairs which break
+        these rules, so the kerning silently goes missing.
+    ",
+    proposal = "None",
+    title = "Check that kerning pairs refer to existing glyphs and groups.",
+    applies_to = "UFO"
+)]
+fn kerning_references(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let mut problems = vec![];
+    let mut missing_glyphs = vec![];
+    let mut missing_groups = vec![];
+    let mut wrong_side = vec![];
+    for (first, seconds) in font.kerning.iter() {
+        for second in seconds.keys() {
+            for (name, own_prefix, other_prefix) in [
+                (first, KERN1_PREFIX, KERN2_PREFIX),
+                (second, KERN2_PREFIX, KERN1_PREFIX),
+            ] {
+                let pair = format!("{} {}", first, second);
+                if name.starts_with(other_prefix) {
+                    wrong_side.push(format!("{}: {}", pair, name));
+                } else if name.starts_with(own_prefix) {
+                    if !font.groups.contains_key(name) {
+                        missing_groups.push(format!("{}: {}", pair, name));
+                    }
+                } else if font.default_layer().get_glyph(name).is_none() {
+                    missing_glyphs.push(format!("{}: {}", pair, name));
+                }
+            }
+        }
+    }
+    for (code, description, items) in [
+        (
+            "missing-glyph",
+            "glyphs which are not in the font",
+            missing_glyphs,
+        ),
+        (
+            "missing-group",
+            "groups which are not defined",
+            missing_groups,
+        ),
+        (
+            "wrong-side-group",
+            "kerning groups on the wrong side of the pair",
+            wrong_side,
+        ),
+    ] {
+        if !items.is_empty() {
+            problems.push(
+                Status::fail(
+                    code,
+                    &format!("The following kerning pairs refer to {}:", description),
+                )
+                .with_items(items),
+            );
+        }
+    }
+    return_result(problems)
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+
+use fontspector_checkapi::{norad::Font, prelude::*};
+
+use super::ufo;
+
+/// The glyph names listed under a lib key, either as an array or as the keys of a dictionary
+fn listed_glyphs(font: &Font, key: &str) -> Option<Vec<String>> {
+    let value = font.lib.get(key)?;
+    if let Some(array) = value.as_array() {
+        Some(
+            array
+                .iter()
+                .flat_map(|v| v.as_string())
+                .map(|s| s.to_string())
+                .collect(),
+        )
+    } else {
+        value
+            .as_dictionary()
+            .map(|dict| dict.keys().map(|k| k.to_string()).collect())
+    }
+}
+
+#[check(
+    id = "ufo/lib_keys",
+    rationale = "
+        Several keys in a UFO's lib.plist change how the font is compiled:
+        `public.glyphOrder` sets the order of the glyphs in the font,
+        `public.skipExportGlyphs` lists glyphs which are not exported, and
+        `public.postscriptNames` gives glyphs their production names.
+        When glyphs are added, renamed or deleted these lists are easily left
+        behind, so that glyphs end up in an arbitrary order or the wrong glyphs
+        are renamed or dropped.
+    ",
+    proposal = "None",
+    title = "Check that lib keys which affect compilation match the glyphs in the font.",
+    applies_to = "UFO"
+)]
+fn lib_keys(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let glyphs = font
+        .default_layer()
+        .iter()
+        .map(|g| g.name().to_string())
+        .collect::<BTreeSet<_>>();
+    let mut problems = vec![];
+    if let Some(order) = listed_glyphs(&font, "public.glyphOrder") {
+        let order = order.into_iter().collect::<BTreeSet<_>>();
+        let unordered = glyphs.difference(&order).collect::<Vec<_>>();
+        if !unordered.is_empty() {
+            problems.push(
+                Status::warn(
+                    "glyphs-missing-from-glyph-order",
+                    "The following glyphs are not in public.glyphOrder, so will be placed \
+                     in an arbitrary order:",
+                )
+                .with_items(unordered.into_iter().cloned()),
+            );
+        }
+    }
+    for key in [
+        "public.glyphOrder",
+        "public.skipExportGlyphs",
+        "public.postscriptNames",
+    ] {
+        let unknown = listed_glyphs(&font, key)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|name| !glyphs.contains(name))
+            .collect::<Vec<_>>();
+        if !unknown.is_empty() {
+            problems.push(
+                Status::warn(
+                    "unknown-glyphs",
+                    &format!("{} refers to glyphs which are not in the font:", key),
+                )
+                .with_items(unknown),
+            );
+        }
+    }
+    return_result(problems)
+}
@@ -0,0 +1,26 @@
+mod duplicate_codepoints;
+mod glyph_names;
+mod groups;
+mod kerning_references;
+mod lib_keys;
+mod required_fields;
+pub use duplicate_codepoints::duplicate_codepoints;
+pub use glyph_names::glyph_names;
+pub use groups::groups;
+pub use kerning_references::kerning_references;
+pub use lib_keys::lib_keys;
+pub use required_fields::required_fields;
+
+use fontspector_checkapi::{prelude::*, Ufo, UFO};
+
+/// Load the UFO being tested, sharing it with the testable's other checks
+pub(crate) fn ufo(t: &Testable, context: &Context) -> Result<Ufo, CheckError> {
+    UFO.from_testable_cached(t, context)
+        .map_err(CheckError::Error)?
+        .ok_or(CheckError::Error("Not a UFO source".to_string()))
+}
+
+/// The prefix of groups used on the first side of kerning pairs
+pub(crate) const KERN1_PREFIX: &str = "public.kern1.";
+/// The prefix of groups used on the second side of kerning pairs
+pub(crate) const KERN2_PREFIX: &str = "public.kern2.";
@@ -0,0 +1,33 @@
+use fontspector_checkapi::prelude::*;
+
+use super::ufo;
+
+#[check(
+    id = "ufo/required_fields",
+    rationale = "
+        Font compilers fill in missing font info with defaults, which are rarely
+        what the designer wanted: a family called \"New Font\", version 0.000, and
+        no copyright notice. These fields should always be set in the sources.
+    ",
+    proposal = "None",
+    aliases = "ufo_required_fields",
+    title = "Check that required fontinfo.plist fields are present.",
+    applies_to = "UFO"
+)]
+fn required_fields(t: &Testable, context: &Context) -> CheckFnResult {
+    let font = ufo(t, context)?;
+    let info = &font.font_info;
+    let missing = [
+        ("familyName", info.family_name.is_none()),
+        ("styleName", info.style_name.is_none()),
+        ("unitsPerEm", info.units_per_em.is_none()),
+        ("versionMajor", info.version_major.is_none()),
+        ("versionMinor", info.version_minor.is_none()),
+        ("copyright", info.copyright.is_none()),
+    ]
+    .into_iter()
+    .filter(|(_, missing)| *missing)
+    .map(|(field, _)| Status::fail("missing-field", &format!("{} is not set", field)))
+    .collect();
+    return_result(missing)
+}
@@ -1,97 +1,44 @@
-use fontspector_checkapi::{prelude::*, skip};
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+mod checks;
+mod masters;
 
-struct Designspace;
+use fontspector_checkapi::{FileType, ProfileBuilder, Registry, DESIGNSPACE, GLYPHS, UFO};
 
-fn direction(contour: &norad::Contour) -> bool {
-    let mut total = 0.0;
-    for (i, point) in contour.points.iter().enumerate() {
-        let next = contour.points.get((i + 1) % contour.points.len()).unwrap();
-        total += (next.x - point.x) * (next.y + point.y);
-    }
-    total > 0.0
-}
+/// Sources with masters, which the source compatibility checks apply to
+const SOURCES: FileType = FileType {
+    pattern: "*.{designspace,glyphs,glyphspackage}",
+};
 
-#[check(
-    id = "designspace/path_direction",
-    rationale = "Make sure the paths have the same direction across all masters.",
-    proposal = "chat",
-    title = "Check path direction.",
-    applies_to = "DESIGNSPACE"
-)]
-fn path_direction(t: &Testable, _context: &Context) -> CheckFnResult {
-    let ds_contents = std::str::from_utf8(&t.contents)
-        .map_err(|_| CheckError::Error("designspace is not valid UTF-8".to_string()))?;
-    let ds = quick_xml::de::from_str::<norad::designspace::DesignSpaceDocument>(ds_contents)?;
-    #[allow(clippy::unwrap_used)] // Life is short
-    let dirname = t.filename.parent().unwrap();
-    let master_names = ds.sources.iter().map(|s| &s.filename).collect::<Vec<_>>();
-    let ufos = ds
-        .sources
-        .iter()
-        .flat_map(|s| {
-            let source_full_path = dirname.join(&s.filename);
-            norad::Font::load(&source_full_path)
-        })
-        .collect::<Vec<_>>();
-    if ufos.is_empty() {
-        return Ok(Status::just_one_fail(
-            "no-sources",
-            "Couldn't load any sources",
-        ));
-    }
-    skip!(
-        ufos.len() < 2,
-        "not-enough-sources",
-        "Not enough sources to compare"
-    );
-    let glyphnames = ufos.first().unwrap().iter_names().collect::<Vec<_>>();
-    let mut problems = vec![];
-    for glyph in glyphnames {
-        // println!("Checking glyph {}", glyph);
-        let all_glyphs = ufos
-            .iter()
-            .flat_map(|s| s.get_glyph(&glyph))
-            .collect::<Vec<_>>();
-        let first = all_glyphs.first().unwrap();
-        let others = all_glyphs.iter().skip(1);
-        for (other_ix, other) in others.enumerate() {
-            for (contour_ix, (first_contour, other_contour)) in
-                first.contours.iter().zip(other.contours.iter()).enumerate()
-            {
-                if direction(first_contour) != direction(other_contour) {
-                    problems.push((glyph.to_string(), other_ix, contour_ix));
-                }
-            }
-        }
-    }
-    return_result(
-        problems
-            .iter()
-            .map(|(glyph, other_ix, contour_ix)| {
-                Status::fail(
-                    "path-direction",
-                    &format!(
-                        "Glyph {} has different path direction in master {} contour {}",
-                        glyph,
-                        master_names[1 + other_ix],
-                        contour_ix
-                    ),
-                )
-            })
-            .collect::<Vec<_>>(),
-    )
-}
+pub struct DesignspaceProfile;
 
-impl fontspector_checkapi::Plugin for Designspace {
+impl fontspector_checkapi::Plugin for DesignspaceProfile {
     fn register(&self, cr: &mut Registry) -> Result<(), String> {
-        let designspace = FileType::new("*.designspace");
-        let ufo = FileType::new("*.ufo");
-        cr.register_filetype("DESIGNSPACE", designspace);
-        cr.register_filetype("UFO", ufo);
+        cr.register_filetype("DESIGNSPACE", DESIGNSPACE.filetype);
+        cr.register_filetype("GLYPHS", GLYPHS.filetype);
+        cr.register_filetype("SOURCES", SOURCES);
+        cr.register_filetype("UFO", UFO.filetype);
 
-        cr.register_simple_profile("designspace", vec![path_direction])
+        ProfileBuilder::new()
+            .add_section("Source Checks")
+            .add_and_register_check(checks::sources::axes)
+            .add_and_register_check(checks::sources::compatible_outlines)
+            .add_and_register_check(checks::sources::consistent_anchors)
+            .add_and_register_check(checks::sources::consistent_codepoints)
+            .add_and_register_check(checks::sources::consistent_glyphset)
+            .add_and_register_check(checks::sources::instances)
+            .add_and_register_check(checks::sources::missing_unicodes)
+            .add_and_register_check(checks::sources::path_direction)
+            .add_and_register_check(checks::sources::start_points)
+            .add_section("Designspace Checks")
+            .add_and_register_check(checks::designspace::consistent_kerning_groups)
+            .add_and_register_check(checks::designspace::loadable_sources)
+            .add_section("UFO Checks")
+            .add_and_register_check(checks::ufo::duplicate_codepoints)
+            .add_and_register_check(checks::ufo::glyph_names)
+            .add_and_register_check(checks::ufo::groups)
+            .add_and_register_check(checks::ufo::kerning_references)
+            .add_and_register_check(checks::ufo::lib_keys)
+            .add_and_register_check(checks::ufo::required_fields)
+            .build("designspace", cr)
     }
 }
-
-#[cfg(not(target_family = "wasm"))]
-pluginator::plugin_implementation!(fontspector_checkapi::Plugin, Designspace);
@@ -0,0 +1,355 @@
+//! A common view of the masters, axes and instances of designspace and Glyphs sources
+//!
+//! The source compatibility checks only need to know about the structure of each
+//! glyph in each master, so we reduce both kinds of source to the same data and
+//! run the same checks on both.
+use std::collections::{BTreeMap, BTreeSet};
+
+use fontspector_checkapi::{
+    glyphs_reader::{self, NodeType, Shape},
+    norad::{self, PointType},
+    prelude::*,
+    DESIGNSPACE, GLYPHS,
+};
+
+/// The kind of a point in a contour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PointKind {
+    /// The first point of an open contour
+    Move,
+    /// An on-curve point ending a straight line
+    Line,
+    /// An off-curve control point
+    OffCurve,
+    /// An on-curve point ending a cubic curve
+    Curve,
+    /// An on-curve point ending a quadratic curve
+    QCurve,
+}
+
+/// A point in a contour
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OutlinePoint {
+    /// What kind of point this is
+    pub kind: PointKind,
+    /// The x coordinate
+    pub x: f64,
+    /// The y coordinate
+    pub y: f64,
+}
+
+/// A glyph in one master
+#[derive(Debug, Default)]
+pub(crate) struct MasterGlyph {
+    /// The Unicode codepoints mapped to the glyph
+    pub codepoints: BTreeSet<u32>,
+    /// The glyph's contours
+    pub contours: Vec<Vec<OutlinePoint>>,
+    /// The names of the glyphs used as components, in order
+    pub components: Vec<String>,
+    /// The names of the glyph's anchors
+    pub anchors: BTreeSet<String>,
+}
+
+/// A master of a source
+pub(crate) struct SourceMaster {
+    /// A name for the master, suitable for reporting to the user
+    pub name: String,
+    /// Whether this is a sparse master, which need not contain every glyph
+    pub sparse: bool,
+    /// The master's location in design coordinates, in the order of the source's axes
+    pub location: Vec<Option<f64>>,
+    /// The glyphs in the master, by name
+    pub glyphs: BTreeMap<String, MasterGlyph>,
+}
+
+/// An axis of a source's design space
+pub(crate) struct SourceAxis {
+    /// The axis name
+    pub name: String,
+    /// The axis tag
+    pub tag: String,
+}
+
+/// An instance defined by a source
+pub(crate) struct SourceInstance {
+    /// The instance's name
+    pub name: String,
+    /// The instance's location in design coordinates, in the order of the source's axes
+    pub location: Vec<Option<f64>>,
+}
+
+/// A designspace or Glyphs source, reduced to the parts we compare
+pub(crate) struct Sources {
+    /// The axes of the design space
+    pub axes: Vec<SourceAxis>,
+    /// The masters which could be loaded
+    pub masters: Vec<SourceMaster>,
+    /// The instances the source defines
+    pub instances: Vec<SourceInstance>,
+    /// The index of the master we compare the others against
+    reference: usize,
+}
+
+impl From<&norad::Glyph> for MasterGlyph {
+    fn from(glyph: &norad::Glyph) -> Self {
+        MasterGlyph {
+            codepoints: glyph.codepoints.iter().map(|c| c as u32).collect(),
+            contours: glyph
+                .contours
+                .iter()
+                .map(|contour| {
+                    contour
+                        .points
+                        .iter()
+                        .map(|point| OutlinePoint {
+                            kind: match point.typ {
+                                PointType::Move => PointKind::Move,
+                                PointType::Line => PointKind::Line,
+                                PointType::OffCurve => PointKind::OffCurve,
+                                PointType::Curve => PointKind::Curve,
+                                PointType::QCurve => PointKind::QCurve,
+                            },
+                            x: point.x,
+                            y: point.y,
+                        })
+                        .collect()
+                })
+                .collect(),
+            components: glyph
+                .components
+                .iter()
+                .map(|c| c.base.to_string())
+                .collect(),
+            anchors: glyph
+                .anchors
+                .iter()
+                .flat_map(|a| a.name.as_ref())
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl MasterGlyph {
+    /// Build a glyph from one layer of a Glyphs glyph
+    fn from_glyphs_layer(glyph: &glyphs_reader::Glyph, layer: &glyphs_reader::Layer) -> Self {
+        let mut master_glyph = MasterGlyph {
+            codepoints: glyph.unicode.iter().copied().collect(),
+            anchors: layer.anchors.iter().map(|a| a.name.to_string()).collect(),
+            ..Default::default()
+        };
+        for shape in layer.shapes.iter() {
+            match shape {
+                Shape::Path(path) => master_glyph.contours.push(
+                    path.nodes
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, node)| OutlinePoint {
+                            kind: match node.node_type {
+                                _ if ix == 0 && !path.closed => PointKind::Move,
+                                NodeType::Line | NodeType::LineSmooth => PointKind::Line,
+                                NodeType::OffCurve => PointKind::OffCurve,
+                                NodeType::Curve | NodeType::CurveSmooth => PointKind::Curve,
+                                NodeType::QCurve | NodeType::QCurveSmooth => PointKind::QCurve,
+                            },
+                            x: node.pt.x,
+                            y: node.pt.y,
+                        })
+                        .collect(),
+                ),
+                Shape::Component(component) => {
+                    master_glyph.components.push(component.name.to_string())
+                }
+            }
+        }
+        master_glyph
+    }
+}
+
+impl Sources {
+    /// Read the designspace or Glyphs source being tested
+    ///
+    /// The UFOs or Glyphs file behind it are shared with the testable's other
+    /// checks through the context.
+    pub(crate) fn new(t: &Testable, context: &Context) -> Result<Self, CheckError> {
+        if let Some(designspace) = DESIGNSPACE
+            .from_testable_cached(t, context)
+            .map_err(CheckError::Error)?
+        {
+            let document = &designspace.document;
+            let location = |dimensions: &[norad::designspace::Dimension]| {
+                document
+                    .axes
+                    .iter()
+                    .map(|axis| {
+                        dimensions
+                            .iter()
+                            .find(|d| d.name == axis.name)
+                            .and_then(|d| d.xvalue)
+                            .map(f64::from)
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let masters = designspace
+                .masters()
+                .map(|master| SourceMaster {
+                    name: master.name(),
+                    sparse: master.source.layer.is_some(),
+                    location: location(&master.source.location),
+                    glyphs: master
+                        .layer
+                        .iter()
+                        .map(|glyph| (glyph.name().to_string(), MasterGlyph::from(glyph)))
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+            Ok(Sources {
+                axes: document
+                    .axes
+                    .iter()
+                    .map(|axis| SourceAxis {
+                        name: axis.name.clone(),
+                        tag: axis.tag.clone(),
+                    })
+                    .collect(),
+                instances: document
+                    .instances
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, instance)| SourceInstance {
+                        name: instance
+                            .name
+                            .clone()
+                            .or(instance.filename.clone())
+                            .unwrap_or(format!("instance {}", ix)),
+                        location: location(&instance.location),
+                    })
+                    .collect(),
+                // Compare against the first full master
+                reference: masters.iter().position(|m| !m.sparse).unwrap_or(0),
+                masters,
+            })
+        } else if let Some(font) = GLYPHS
+            .from_testable_cached(t, context)
+            .map_err(CheckError::Error)?
+        {
+            Ok(Sources {
+                axes: font
+                    .axes
+                    .iter()
+                    .map(|axis| SourceAxis {
+                        name: axis.name.clone(),
+                        tag: axis.tag.clone(),
+                    })
+                    .collect(),
+                masters: font
+                    .masters
+                    .iter()
+                    .map(|master| SourceMaster {
+                        name: master.name.clone(),
+                        sparse: false,
+                        location: master.axes_values.iter().map(|v| Some(v.0)).collect(),
+                        glyphs: font
+                            .glyphs
+                            .values()
+                            .flat_map(|glyph| {
+                                glyph
+                                    .layers
+                                    .iter()
+                                    .find(|layer| layer.layer_id == master.id)
+                                    .map(|layer| {
+                                        (
+                                            glyph.name.to_string(),
+                                            MasterGlyph::from_glyphs_layer(glyph, layer),
+                                        )
+                                    })
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                instances: font
+                    .instances
+                    .iter()
+                    // Variable font settings are stored as instances without a location
+                    .filter(|instance| instance.axes_values.len() == font.axes.len())
+                    .map(|instance| SourceInstance {
+                        name: instance.name.clone(),
+                        location: instance.axes_values.iter().map(|v| Some(v.0)).collect(),
+                    })
+                    .collect(),
+                reference: font.default_master_idx,
+            })
+        } else {
+            Err(CheckError::Error(
+                "Not a designspace or Glyphs source".to_string(),
+            ))
+        }
+    }
+
+    /// The master we compare the others against
+    ///
+    /// For a designspace, this is the first full master; for a Glyphs source,
+    /// it is the default master.
+    pub(crate) fn reference(&self) -> Option<&SourceMaster> {
+        self.masters.get(self.reference)
+    }
+
+    /// Split the masters into the reference master and the rest
+    ///
+    /// Fails if there are no masters, and skips if there is nothing to compare.
+    pub(crate) fn reference_and_others(
+        &self,
+    ) -> Result<(&SourceMaster, Vec<&SourceMaster>), Status> {
+        let reference = self
+            .reference()
+            .ok_or_else(|| Status::fail("no-sources", "Couldn't load any sources"))?;
+        let others = self
+            .masters
+            .iter()
+            .enumerate()
+            .filter(|(ix, _)| *ix != self.reference)
+            .map(|(_, master)| master)
+            .collect::<Vec<_>>();
+        if others.is_empty() {
+            return Err(Status::skip(
+                "not-enough-sources",
+                "Not enough sources to compare",
+            ));
+        }
+        Ok((reference, others))
+    }
+
+    /// Compare each glyph in the reference master with the same glyph in each other master
+    ///
+    /// The comparison returns a status code and description for each incompatibility
+    /// it finds, which is reported along with the glyph name and the masters' names.
+    /// Glyphs missing from a master are left to `sources/consistent_glyphset`.
+    pub(crate) fn compare_glyphs(
+        &self,
+        compare: impl Fn(&MasterGlyph, &MasterGlyph) -> Vec<(&'static str, String)>,
+    ) -> CheckFnResult {
+        let (reference, others) = match self.reference_and_others() {
+            Ok(split) => split,
+            Err(status) => return return_result(vec![status]),
+        };
+        let mut problems = vec![];
+        for (name, glyph) in reference.glyphs.iter() {
+            for other in others.iter() {
+                let Some(other_glyph) = other.glyphs.get(name) else {
+                    continue;
+                };
+                for (code, message) in compare(glyph, other_glyph) {
+                    problems.push(Status::fail(
+                        code,
+                        &format!(
+                            "Glyph {} in {} {} (compared with {})",
+                            name, other.name, message, reference.name
+                        ),
+                    ));
+                }
+            }
+        }
+        return_result(problems)
+    }
+}
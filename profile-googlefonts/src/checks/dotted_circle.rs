@@ -69,6 +69,8 @@ fn find_all_attachments(f: &TestFont) -> Result<(HashSet<GlyphId>, AttachmentMap
 
 #[check(
     id = "dotted_circle",
+    aliases = "com.google.fonts/check/dotted_circle",
+    tags = "shaping",
     rationale = "
         
         The dotted circle character (U+25CC) is inserted by shaping engines before
@@ -91,7 +93,7 @@ fn find_all_attachments(f: &TestFont) -> Result<(HashSet<GlyphId>, AttachmentMap
     proposal = "https://github.com/fonttools/fontbakery/issues/3600",
     title = "Ensure dotted circle glyph is present and can attach marks."
 )]
-fn dotted_circle(t: &Testable, context: &Context) -> CheckFnResult {
+fn dotted_circle(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
     let mappings = f.font().charmap().mappings().collect::<Vec<_>>();
@@ -117,18 +119,17 @@ fn dotted_circle(t: &Testable, context: &Context) -> CheckFnResult {
             }
         }
         if !unattached.is_empty() {
-            problems.push(Status::fail(
-                "unattached-dotted-circle-marks",
-                &format!(
-                    "The following glyphs could not be attached to the dotted circle glyph:\n\n{}",
-                    bullet_list(
-                        context,
-                        unattached
-                            .into_iter()
-                            .map(|g| f.glyph_name_for_id_synthesise(g))
-                    )
+            problems.push(
+                Status::fail(
+                    "unattached-dotted-circle-marks",
+                    "The following glyphs could not be attached to the dotted circle glyph:",
+                )
+                .with_items(
+                    unattached
+                        .into_iter()
+                        .map(|g| f.glyph_name_for_id_synthesise(g)),
                 ),
-            ));
+            );
         }
     } else if is_complex_shaper_font(&f).unwrap_or(false) {
         problems.push(Status::fail(
@@ -4,6 +4,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "googlefonts/STAT/axis_order",
+    aliases = "com.google.fonts/check/STAT/axis_order",
     rationale = "
         
         This is (for now) a merely informative check to detect what's the axis ordering
@@ -70,6 +70,7 @@ fn process_axis(
 
 #[check(
     id = "googlefonts/STAT/axisregistry",
+    aliases = "com.google.fonts/check/STAT/gf_axisregistry",
     rationale = "
         
         Check that particle names and values on STAT table match the fallback names
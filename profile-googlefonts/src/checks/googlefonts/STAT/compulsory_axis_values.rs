@@ -103,6 +103,7 @@ fn stat_axis_values(f: &FontRef) -> Result<HashMap<(String, String), SimpleAxisV
 }
 #[check(
     id = "googlefonts/STAT/compulsory_axis_values",
+    aliases = "com.google.fonts/check/STAT/compulsory_axis_values",
     rationale = "
         
         Check a font's STAT table contains compulsory Axis Values which exist
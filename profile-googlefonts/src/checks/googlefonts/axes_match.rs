@@ -9,6 +9,8 @@ use crate::{
 
 #[check(
     id = "googlefonts/axes_match",
+    aliases = "com.google.fonts/check/axes_match",
+    tags = "network",
     rationale = "
         An updated font family must include the same axes found in the Google
         Fonts version, with the same axis ranges.
@@ -21,7 +23,7 @@ fn axes_match(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let fonts = msg
         .fonts
         .iter()
@@ -6,6 +6,8 @@ use google_fonts_axisregistry::AxisRegistry;
 
 #[check(
     id = "googlefonts/axisregistry/fvar_axis_defaults",
+    aliases = "com.google.fonts/check/gf_axisregistry/fvar_axis_defaults",
+    tags = "variable",
     rationale = "
         
         Check that axis defaults have a corresponding fallback name registered at the
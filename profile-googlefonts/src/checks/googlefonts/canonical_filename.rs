@@ -3,6 +3,7 @@ use google_fonts_axisregistry::build_filename;
 
 #[check(
     id = "googlefonts/canonical_filename",
+    aliases = "com.google.fonts/check/canonical_filename",
     rationale = "
         
         A font's filename must be composed as \"<familyname>-<stylename>.ttf\":
@@ -5,6 +5,7 @@ const NANOEMOJI_ADVICE : &str = "You can do it by using the maximum_color tool p
 
 #[check(
     id = "googlefonts/color_fonts",
+    aliases = "com.google.fonts/check/color_fonts",
     rationale = "
         COLR v0 fonts are widely supported in most browsers so they do not require
         an SVG color table. However, some environments (e.g. Safari, Adobe apps)
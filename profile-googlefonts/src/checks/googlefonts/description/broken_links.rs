@@ -6,6 +6,8 @@ use crate::network_conditions::get_url;
 
 #[check(
     id = "googlefonts/description/broken_links",
+    aliases = "com.google.fonts/check/description/broken_links",
+    tags = "network",
     rationale = "
         
         The snippet of HTML in the DESCRIPTION.en_us.html/ARTICLE.en_us.html file is
@@ -57,13 +59,9 @@ fn broken_links(desc: &Testable, context: &Context) -> CheckFnResult {
         }
     }
     if !broken.is_empty() {
-        problems.push(Status::fail(
-            "broken-links",
-            &format!(
-                "The following links are broken:\n{}",
-                bullet_list(context, broken)
-            ),
-        ));
+        problems.push(
+            Status::fail("broken-links", "The following links are broken:").with_items(broken),
+        );
     }
 
     return_result(problems)
@@ -2,6 +2,7 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/description/eof_linebreak",
+    aliases = "com.google.fonts/check/description/eof_linebreak",
     title = "DESCRIPTION.en_us.html should end in a linebreak.",
     rationale = "
         Some older text-handling tools sometimes misbehave if the last line of data
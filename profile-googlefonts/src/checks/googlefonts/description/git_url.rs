@@ -3,6 +3,7 @@ use scraper::{Html, Selector};
 
 #[check(
     id = "googlefonts/description/git_url",
+    aliases = "com.google.fonts/check/description/git_url",
     rationale = "
         
         The contents of the DESCRIPTION.en-us.html file are displayed on the
@@ -3,6 +3,7 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/description/has_article",
+    aliases = "com.google.fonts/check/description/has_article",
     rationale = "
         
         Fonts may have a longer article about them, or a description, but
@@ -13,7 +14,7 @@ use fontspector_checkapi::prelude::*;
     title = "Check for presence of an ARTICLE.en_us.html file",
     implementation = "all"
 )]
-fn has_article(c: &TestableCollection, _context: &Context) -> CheckFnResult {
+fn has_article(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
     let article = c.get_file("ARTICLE.en_us.html");
     let description = c.get_file("DESCRIPTION.en_us.html");
@@ -21,7 +22,7 @@ fn has_article(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let description_is_empty = description.map(|t| t.contents.is_empty()).unwrap_or(false);
     let is_noto = c
         .get_file("METADATA.pb")
-        .and_then(|t| family_proto(t).ok())
+        .and_then(|t| family_proto(t, context).ok())
         .map(|msg| msg.name().starts_with("Noto "))
         .unwrap_or(false);
     if !is_noto {
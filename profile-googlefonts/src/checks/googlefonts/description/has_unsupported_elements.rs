@@ -8,6 +8,7 @@ const UNSUPPORTED_ELEMENTS: [&str; 16] = [
 
 #[check(
     id = "googlefonts/description/has_unsupported_elements",
+    aliases = "com.google.fonts/check/description/has_unsupported_elements",
     rationale = "
         
         The Google Fonts backend doesn't support the following html elements:
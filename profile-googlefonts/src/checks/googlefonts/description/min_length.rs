@@ -2,6 +2,7 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/description/min_length",
+    aliases = "com.google.fonts/check/description/min_length",
     title = "DESCRIPTION.en_us.html must have more than 200 bytes.",
     rationale = "
         The DESCRIPTION.en_us.html file is intended to provide a brief overview of
@@ -3,6 +3,7 @@ use scraper::{Html, Selector};
 
 #[check(
     id = "googlefonts/description/urls",
+    aliases = "com.google.fonts/check/description/urls",
     rationale = "
         
         The snippet of HTML in the DESCRIPTION.en_us.html file is added to the font
@@ -3,6 +3,7 @@ use scraper::{Html, Selector};
 
 #[check(
     id = "googlefonts/description/valid_html",
+    aliases = "com.google.fonts/check/description/valid_html",
     rationale = "
         
         Sometimes people write malformed HTML markup. This check should ensure the
@@ -5,6 +5,7 @@ use fontspector_checkapi::{prelude::*, FileTypeConvert};
 
 #[check(
     id = "googlefonts/family/equal_codepoint_coverage",
+    aliases = "com.google.fonts/check/family/equal_codepoint_coverage",
     title = "Fonts have equal codepoint coverage?",
     rationale = "For a given family, all fonts must have the same codepoint coverage.
                 This is because we want to avoid the situation where, for example,
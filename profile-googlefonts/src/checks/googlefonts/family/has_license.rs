@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/family/has_license",
+    aliases = "com.google.fonts/check/family/has_license",
+    tags = "licensing",
     rationale = "
         
         A license file is required for all fonts in the Google Fonts collection.
@@ -14,17 +16,16 @@ use fontspector_checkapi::prelude::*;
     title = "Check font has a license.",
     implementation = "all"
 )]
-fn has_license(c: &TestableCollection, context: &Context) -> CheckFnResult {
+fn has_license(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let licenses = c.iter().filter(|x| LICENSE.applies(x)).collect::<Vec<_>>();
-    Ok(if licenses.len() > 1 {
-        Status::just_one_fail(
+    if licenses.len() > 1 {
+        return return_result(vec![Status::fail(
             "multiple",
-            &format!(
-                "More than a single license file found: {}",
-                bullet_list(context, licenses.iter().flat_map(|x| x.basename())),
-            ),
+            "More than a single license file found:",
         )
-    } else if licenses.is_empty() {
+        .with_items(licenses.iter().flat_map(|x| x.basename()))]);
+    }
+    Ok(if licenses.is_empty() {
         Status::just_one_fail(
             "no-license",
             "No license file was found. Please add an OFL.txt or a LICENSE.txt file.",
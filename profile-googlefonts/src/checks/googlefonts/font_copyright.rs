@@ -7,6 +7,8 @@ use crate::constants::EXPECTED_COPYRIGHT_PATTERN;
 
 #[check(
     id = "googlefonts/font_copyright",
+    aliases = "com.google.fonts/check/font_copyright",
+    tags = "licensing",
     rationale = "
         
         This check aims at ensuring a uniform and legally accurate copyright statement
@@ -53,7 +55,7 @@ fn font_copyright(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
     let mdpb = c
         .get_file("METADATA.pb")
-        .map(|x: &Testable| family_proto(x))
+        .map(|x: &Testable| family_proto(x, context))
         .transpose()?;
     let mdpb_copyrights = mdpb
         .map(|proto| {
@@ -107,15 +109,13 @@ fn font_copyright(c: &TestableCollection, context: &Context) -> CheckFnResult {
             entry.or_default().push(source);
         }
         if copyright_sources.len() > 1 {
-            problems.push(Status::fail(
-                "mismatch",
-                &format!("Copyright notices differ between name table entries and METADATA.pb. The following entries were found:\n{}",
-                bullet_list(
-                  context,
-                  copyright_sources.iter().map(|(a, b)| format!("{}: {:?}", a, b))
-              )
+            problems.push(
+                Status::fail(
+                    "mismatch",
+                    "Copyright notices differ between name table entries and METADATA.pb. The following entries were found:",
+                )
+                .with_items(copyright_sources.iter().map(|(a, b)| format!("{}: {:?}", a, b))),
             )
-          ))
         }
     }
 
@@ -17,6 +17,7 @@ const NAME_IDS: [(StringId, &str); 6] = [
 ];
 #[check(
     id = "googlefonts/font_names",
+    aliases = "com.google.fonts/check/font_names",
     rationale = "
         
         Google Fonts has several rules which need to be adhered to when
@@ -19,6 +19,7 @@ const FSTYPE_RESTRICTIONS: [(u16, &str); 5] = [
 
 #[check(
     id = "googlefonts/fstype",
+    aliases = "com.google.fonts/check/fstype",
     rationale = "
         
         The fsType in the OS/2 table is a legacy DRM-related field. Fonts in the
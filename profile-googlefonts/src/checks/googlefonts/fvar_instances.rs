@@ -8,6 +8,8 @@ use crate::utils::build_expected_font;
 
 #[check(
     id = "googlefonts/fvar_instances",
+    aliases = "com.google.fonts/check/fvar_instances",
+    tags = "variable",
     rationale = "
         
         Check a font's fvar instance coordinates comply with our guidelines:
@@ -24,6 +24,7 @@ fn gasp_meaning(value: GaspRangeBehavior) -> String {
 
 #[check(
     id = "googlefonts/gasp",
+    aliases = "com.google.fonts/check/gasp",
     rationale = "
         
         Traditionally version 0 'gasp' tables were set so that font sizes below 8 ppem
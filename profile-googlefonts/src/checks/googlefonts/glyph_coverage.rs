@@ -4,6 +4,7 @@ use google_fonts_glyphsets::get_coverage;
 
 #[check(
     id = "googlefonts/glyph_coverage",
+    aliases = "com.google.fonts/check/glyph_coverage",
     rationale = "
         
         Google Fonts expects that fonts in its collection support at least the minimal
@@ -17,7 +18,7 @@ use google_fonts_glyphsets::get_coverage;
 fn glyph_coverage(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let required_glyphset = if c
         .get_file("METADATA.pb")
-        .and_then(|mdpb| family_proto(mdpb).ok())
+        .and_then(|mdpb| family_proto(mdpb, context).ok())
         .map(|msg| msg.primary_script().to_string())
         .is_some()
     {
@@ -41,21 +42,23 @@ fn glyph_coverage(c: &TestableCollection, context: &Context) -> CheckFnResult {
         let codepoints = f.codepoints(Some(context));
         #[allow(clippy::unwrap_used)]
         // A static key lookup of one or another key we know to be in there
-        let coverage = get_coverage(&codepoints, required_glyphset).unwrap();
+        let coverage = get_coverage(codepoints.as_ref(), required_glyphset).unwrap();
         if !coverage.missing.is_empty() {
             let missing = coverage
                 .missing
                 .iter()
                 .map(|c| format!("0x{:04X}", c,))
                 .collect::<Vec<String>>();
-            problems.push(Status::fail(
-                "missing-codepoints",
-                &format!(
-                    "{} missing required codepoints:\n\n{}",
-                    f.filename.as_os_str().to_string_lossy(),
-                    bullet_list(context, missing)
-                ),
-            ));
+            problems.push(
+                Status::fail(
+                    "missing-codepoints",
+                    &format!(
+                        "{} missing required codepoints:",
+                        f.filename.as_os_str().to_string_lossy(),
+                    ),
+                )
+                .with_items(missing),
+            );
         }
     }
     return_result(problems)
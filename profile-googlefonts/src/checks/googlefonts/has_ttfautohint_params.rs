@@ -4,6 +4,7 @@ use skrifa::string::StringId;
 
 #[check(
     id = "googlefonts/has_ttfautohint_params",
+    aliases = "com.google.fonts/check/has_ttfautohint_params",
     rationale = "
         
         It is critically important that all static TTFs in the Google Fonts API
@@ -6,6 +6,8 @@ use crate::constants::OFL_BODY_TEXT;
 
 #[check(
     id = "googlefonts/license/OFL_body_text",
+    aliases = "com.google.fonts/check/license/OFL_body_text",
+    tags = "licensing",
     rationale = "
         Check OFL body text is correct.
         Often users will accidently delete parts of the body text.
@@ -15,7 +17,7 @@ use crate::constants::OFL_BODY_TEXT;
     applies_to = "LICENSE"
 )]
 fn OFL_body_text(t: &Testable, _context: &Context) -> CheckFnResult {
-    let mut license_contents = String::from_utf8(t.contents.clone())
+    let mut license_contents = String::from_utf8(t.contents.to_vec())
         .map_err(|e| CheckError::Error(format!("OFL.txt is not valid UTF-8: {:?}", e)))?;
     if license_contents.ends_with('\n') {
         license_contents.pop();
@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/license/OFL_copyright",
+    aliases = "com.google.fonts/check/license/OFL_copyright",
+    tags = "licensing",
     rationale = "
         
         An OFL.txt file's first line should be the font copyright.
@@ -37,7 +39,7 @@ use fontspector_checkapi::prelude::*;
     applies_to = "LICENSE"
 )]
 fn OFL_copyright(t: &Testable, _context: &Context) -> CheckFnResult {
-    let license_contents = String::from_utf8(t.contents.clone())
+    let license_contents = String::from_utf8(t.contents.to_vec())
         .map_err(|e| CheckError::Error(format!("OFL.txt is not valid UTF-8: {:?}", e)))?
         .trim()
         .split("\n")
@@ -35,6 +35,7 @@ fn dump_data_map(
 }
 #[check(
     id = "googlefonts/meta/script_lang_tags",
+    aliases = "com.google.fonts/check/meta/script_lang_tags",
     rationale = "
         
         The OpenType 'meta' table originated at Apple. Microsoft added it to OT with
@@ -7,6 +7,7 @@ use crate::checks::googlefonts::metadata::family_proto;
 
 #[check(
     id = "googlefonts/metadata/axes",
+    tags = "metadata",
     rationale = "
         
         Each axis range in a METADATA.pb file must be registered, and within the bounds
@@ -23,7 +24,7 @@ fn axes(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     // Skip if no variable fonts
     let fonts = msg
         .fonts
@@ -87,20 +88,14 @@ fn axes(c: &TestableCollection, context: &Context) -> CheckFnResult {
     if !missing.is_empty() {
         problems.push(Status::fail(
             "missing-axes",
-            &format!(
-                "The font variation axes {} are present in the font's fvar table but are not declared on the METADATA.pb file.",
-                bullet_list(context, &missing)
-            ),
-        ));
+            "The following font variation axes are present in the font's fvar table but are not declared on the METADATA.pb file:",
+        ).with_items(missing.iter().map(|axis| axis.as_str())));
     }
     if !extra.is_empty() {
         problems.push(Status::fail(
             "extra-axes",
-            &format!(
-                "The METADATA.pb file lists font variation axes that are not supported by this family: {}",
-                bullet_list(context, &extra)
-            ),
-        ));
+            "The METADATA.pb file lists font variation axes that are not supported by this family:",
+        ).with_items(extra.iter().map(|axis| axis.as_str())));
     }
 
     return_result(problems)
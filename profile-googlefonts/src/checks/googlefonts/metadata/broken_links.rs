@@ -4,6 +4,8 @@ use reqwest::StatusCode;
 
 #[check(
     id = "googlefonts/metadata/broken_links",
+    aliases = "com.google.fonts/check/metadata/broken_links",
+    tags = "metadata, network",
     rationale = "
         
         This check ensures that any URLs found within the copyright
@@ -21,12 +23,12 @@ fn broken_links(c: &Testable, context: &Context) -> CheckFnResult {
         "network-check",
         "Skipping network check"
     );
-    let msg = family_proto(c).map_err(|e| {
+    let msg = family_proto(c, context).map_err(|e| {
         CheckError::Error(format!("METADATA.pb is not a valid FamilyProto: {:?}", e))
     })?;
     let mut unique_links: Vec<String> = vec![];
     let mut broken = vec![];
-    for font_metadata in msg.fonts {
+    for font_metadata in msg.fonts.iter() {
         let copyright = font_metadata.copyright();
         if copyright.contains("mailto:") {
             if unique_links.contains(&copyright.to_string()) {
@@ -84,13 +86,9 @@ fn broken_links(c: &Testable, context: &Context) -> CheckFnResult {
     }
 
     if !broken.is_empty() {
-        problems.push(Status::fail(
-            "broken-links",
-            &format!(
-                "The following links are broken:\n{}",
-                bullet_list(context, broken)
-            ),
-        ));
+        problems.push(
+            Status::fail("broken-links", "The following links are broken:").with_items(broken),
+        );
     }
     return_result(problems)
 }
@@ -6,6 +6,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
 
 #[check(
     id = "googlefonts/metadata/can_render_samples",
+    aliases = "com.google.fonts/check/metadata/can_render_samples",
+    tags = "metadata",
     title = "Check samples can be rendered",
     rationale = "
         In order to prevent tofu from being seen on fonts.google.com, this check
@@ -20,8 +22,8 @@ fn can_render_samples(c: &TestableCollection, context: &Context) -> CheckFnResul
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
-    let languages = msg.languages;
+    let msg = family_proto(mdpb, context)?;
+    let languages = &msg.languages;
     if languages.is_empty() {
         skip!("no-languages", "No languages specified in METADATA.pb");
     }
@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/metadata/category",
+    aliases = "com.google.fonts/check/metadata/category",
+    tags = "metadata",
     rationale = "
         
         There are only five acceptable values for the category field in a METADATA.pb
@@ -25,13 +27,13 @@ use fontspector_checkapi::prelude::*;
     title = "Ensure METADATA.pb category field is valid.",
     implementation = "all"
 )]
-fn category(c: &TestableCollection, _context: &Context) -> CheckFnResult {
+fn category(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let family_metadata = family_proto(mdpb)?;
+    let family_metadata = family_proto(mdpb, context)?;
     let mut problems = vec![];
-    for category_value in family_metadata.category {
+    for category_value in family_metadata.category.iter() {
         if ["MONOSPACE", "SANS_SERIF", "SERIF", "DISPLAY", "HANDWRITING"]
             .iter()
             .all(|s| category_value != *s)
@@ -18,6 +18,8 @@ fn clean_url(url: &str) -> String {
 
 #[check(
     id = "googlefonts/metadata/consistent_repo_urls",
+    aliases = "com.google.fonts/check/metadata/consistent_repo_urls",
+    tags = "metadata",
     rationale = "
         
         Sometimes, perhaps due to copy-pasting, projects may declare different URLs
@@ -32,7 +34,7 @@ fn consistent_repo_urls(c: &TestableCollection, context: &Context) -> CheckFnRes
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let repo_url = clean_url(msg.source.repository_url());
     if repo_url.is_empty() {
         return Ok(Status::just_one_fail(
@@ -43,7 +45,7 @@ fn consistent_repo_urls(c: &TestableCollection, context: &Context) -> CheckFnRes
 
     let mut bad_urls = vec![];
 
-    for font in msg.fonts {
+    for font in msg.fonts.iter() {
         if let Some(httpbit) = font.copyright().split("http").nth(1) {
             let link = clean_url(&format!("http{}", httpbit));
             if link != repo_url {
@@ -53,7 +55,7 @@ fn consistent_repo_urls(c: &TestableCollection, context: &Context) -> CheckFnRes
     }
 
     if let Some(ofl) = c.get_file("OFL.txt") {
-        let license_contents = String::from_utf8(ofl.contents.clone())
+        let license_contents = String::from_utf8(ofl.contents.to_vec())
             .map_err(|e| CheckError::Error(format!("OFL.txt is not valid UTF-8: {:?}", e)))?;
         let first_line = license_contents.lines().next().unwrap_or_default();
         if first_line.contains("http") {
@@ -66,7 +68,7 @@ fn consistent_repo_urls(c: &TestableCollection, context: &Context) -> CheckFnRes
 
     if let Some(description) = c.get_file("DESCRIPTION.en_us.html") {
         let description_contents =
-            String::from_utf8(description.contents.clone()).map_err(|e| {
+            String::from_utf8(description.contents.to_vec()).map_err(|e| {
                 CheckError::Error(format!(
                     "DESCRIPTION.en_us.html is not valid UTF-8: {:?}",
                     e
@@ -86,19 +88,15 @@ fn consistent_repo_urls(c: &TestableCollection, context: &Context) -> CheckFnRes
     }
 
     if !bad_urls.is_empty() {
-        return Ok(Status::just_one_fail(
+        return return_result(vec![Status::fail(
             "mismatch",
-            &format!(
-                "Repository URL is {}. But:\n{}",
-                repo_url,
-                bullet_list(
-                    context,
-                    bad_urls
-                        .iter()
-                        .map(|(location, url)| format!("{} has '{}'", location, url))
-                )
-            ),
-        ));
+            &format!("Repository URL is {}. But:", repo_url),
+        )
+        .with_items(
+            bad_urls
+                .iter()
+                .map(|(location, url)| format!("{} has '{}'", location, url)),
+        )]);
     }
     return Ok(Status::just_one_pass());
 }
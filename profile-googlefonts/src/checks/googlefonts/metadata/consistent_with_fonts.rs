@@ -4,6 +4,7 @@ use skrifa::string::StringId;
 
 #[check(
     id = "googlefonts/metadata/consistent_with_fonts",
+    tags = "metadata",
     title = "Check METADATA.pb parses correctly",
     rationale = "
         The purpose of this check is to ensure that the information in the METADATA.pb file
@@ -19,12 +20,12 @@ use skrifa::string::StringId;
     proposal = "https://github.com/fonttools/fontbakery/issues/2597 and https://github.com/fonttools/fontbakery/issues/4829",
     implementation = "all"
 )]
-fn consistent_with_fonts(c: &TestableCollection, _context: &Context) -> CheckFnResult {
+fn consistent_with_fonts(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mut problems = vec![];
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let mut declared_files = msg
         .fonts
         .iter()
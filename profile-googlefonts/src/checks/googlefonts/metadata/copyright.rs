@@ -3,6 +3,7 @@ use fontspector_checkapi::{prelude::*, StatusCode};
 
 #[check(
     id="googlefonts/metadata/copyright",
+    tags="metadata, licensing",
     rationale="
         The METADATA.pb file includes a copyright field for each font
         file in the family. The value of this field should be the same
@@ -13,7 +14,7 @@ use fontspector_checkapi::{prelude::*, StatusCode};
     title="METADATA.pb: Copyright notice is the same in all fonts?"
 )]
 fn copyright(c: &Testable, context: &Context) -> CheckFnResult {
-    let msg = family_proto(c).map_err(|e| {
+    let msg = family_proto(c, context).map_err(|e| {
         CheckError::Error(format!("METADATA.pb is not a valid FamilyProto: {:?}", e))
     })?;
     assert_all_the_same(
@@ -3,6 +3,8 @@ use std::fs::read_to_string;
 
 #[check(
     id = "googlefonts/metadata/escaped_strings",
+    aliases = "com.google.fonts/check/metadata/escaped_strings",
+    tags = "metadata",
     rationale = "
         
         In some cases we've seen designer names and other fields with escaped strings
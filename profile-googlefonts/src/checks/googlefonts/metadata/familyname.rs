@@ -3,6 +3,8 @@ use fontspector_checkapi::{prelude::*, StatusCode};
 
 #[check(
     id = "googlefonts/metadata/familyname",
+    aliases = "com.google.fonts/check/metadata/familyname",
+    tags = "metadata",
     rationale = "
         
         The METADATA.pb file includes a family name field for each font
@@ -18,7 +20,7 @@ fn familyname(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     assert_all_the_same(
         context,
         &(msg.fonts.iter().map(|f|
@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/metadata/has_regular",
+    aliases = "com.google.fonts/check/metadata/has_regular",
+    tags = "metadata",
     rationale = "
         
         According to Google Fonts standards, families should have a Regular
@@ -13,11 +15,11 @@ use fontspector_checkapi::prelude::*;
     title = "Ensure there is a regular style defined in METADATA.pb.",
     implementation = "all"
 )]
-fn has_regular(c: &TestableCollection, _context: &Context) -> CheckFnResult {
+fn has_regular(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     if msg
         .fonts
         .iter()
@@ -3,6 +3,7 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id="googlefonts/metadata/license",
+    tags="metadata, licensing",
     rationale="
         The license field in METADATA.pb must contain one of the
         three values \"APACHE2\", \"UFL\" or \"OFL\". (New fonts should
@@ -12,8 +13,8 @@ use fontspector_checkapi::prelude::*;
     proposal="https://github.com/fonttools/fontbakery/issues/4829",  // legacy check
     title="METADATA.pb license is \"APACHE2\", \"UFL\" or \"OFL\"?"
 )]
-fn license(c: &Testable, _context: &Context) -> CheckFnResult {
-    let msg = family_proto(c).map_err(|e| {
+fn license(c: &Testable, context: &Context) -> CheckFnResult {
+    let msg = family_proto(c, context).map_err(|e| {
         CheckError::Error(format!("METADATA.pb is not a valid FamilyProto: {:?}", e))
     })?;
     if msg.license() != "APACHE2" && msg.license() != "UFL" && msg.license() != "OFL" {
@@ -30,14 +30,28 @@ mod protos {
     include!(concat!(env!("OUT_DIR"), "/protos/mod.rs"));
 }
 pub(crate) use fonts_public::FamilyProto;
-use fontspector_checkapi::{CheckError, Testable};
+use fontspector_checkapi::{CheckError, Context, Testable};
 use protos::fonts_public;
+use std::sync::Arc;
 
-pub(crate) fn family_proto(t: &Testable) -> Result<FamilyProto, CheckError> {
-    let mdpb = std::str::from_utf8(&t.contents)
-        .map_err(|_| CheckError::Error("METADATA.pb is not valid UTF-8".to_string()))?;
+/// Parse a METADATA.pb file, sharing the result with the other checks which need it
+pub(crate) fn family_proto(
+    t: &Testable,
+    context: &Context,
+) -> Result<Arc<FamilyProto>, CheckError> {
+    context.get_or_try_compute(&t.filename.to_string_lossy(), || {
+        let mdpb = std::str::from_utf8(&t.contents)
+            .map_err(|_| CheckError::Error("METADATA.pb is not valid UTF-8".to_string()))?;
+        protobuf::text_format::parse_from_str::<FamilyProto>(mdpb)
+            .map_err(|e| CheckError::Error(format!("Error parsing METADATA.pb: {}", e)))
+    })
+}
+
+/// The filenames of the fonts declared in the contents of a METADATA.pb file
+pub fn declared_font_filenames(mdpb: &str) -> Result<Vec<String>, String> {
     protobuf::text_format::parse_from_str::<FamilyProto>(mdpb)
-        .map_err(|e| CheckError::Error(format!("Error parsing METADATA.pb: {}", e)))
+        .map(|msg| msg.fonts.iter().map(|f| f.filename().to_string()).collect())
+        .map_err(|e| e.to_string())
 }
 mod valid_nameid25;
 pub use valid_nameid25::valid_nameid25;
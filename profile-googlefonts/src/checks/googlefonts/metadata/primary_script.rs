@@ -8,7 +8,8 @@ fn get_primary_script(font: &TestFont, context: &Context) -> String {
     let mut script_count = HashMap::new();
     for c in font
         .codepoints(Some(context))
-        .into_iter()
+        .iter()
+        .copied()
         .filter_map(char::from_u32)
     {
         for script in c.script_extension().iter() {
@@ -41,6 +42,8 @@ fn is_sibling_script(script1: &str, script2: &str) -> bool {
 
 #[check(
     id = "googlefonts/metadata/primary_script",
+    aliases = "com.google.fonts/check/metadata/primary_script",
+    tags = "metadata",
     rationale = "
         
         Try to guess font's primary script and see if that's set in METADATA.pb.
@@ -57,7 +60,7 @@ fn primary_script(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let fonts = msg
         .fonts
         .iter()
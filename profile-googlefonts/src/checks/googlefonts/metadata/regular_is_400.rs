@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/metadata/regular_is_400",
+    aliases = "com.google.fonts/check/metadata/regular_is_400",
+    tags = "metadata",
     rationale = "
         
         The weight of the regular style should be set to 400.
@@ -16,7 +18,7 @@ fn regular_is_400(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let badfonts = msg
         .fonts
         .iter()
@@ -27,12 +29,10 @@ fn regular_is_400(c: &TestableCollection, context: &Context) -> CheckFnResult {
     if badfonts.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "not-400",
-            &format!(
-                "METADATA.pb: Regular font weight must be 400. Please fix these: {}",
-                bullet_list(context, badfonts),
-            ),
-        ))
+            "METADATA.pb: Regular font weight must be 400. Please fix these:",
+        )
+        .with_items(badfonts)])
     }
 }
@@ -3,6 +3,8 @@ use fontspector_checkapi::prelude::*;
 
 #[check(
     id = "googlefonts/metadata/reserved_font_name",
+    aliases = "com.google.fonts/check/metadata/reserved_font_name",
+    tags = "metadata, licensing",
     rationale = "
         
         Unless an exception has been granted, we expect fonts on
@@ -22,14 +24,14 @@ use fontspector_checkapi::prelude::*;
     title = "Copyright notice on METADATA.pb should not contain 'Reserved Font Name'.",
     implementation = "all"
 )]
-fn reserved_font_name(c: &TestableCollection, _context: &Context) -> CheckFnResult {
+fn reserved_font_name(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
 
     let mut problems = vec![];
-    let family_metadata = family_proto(mdpb)?;
-    for font_metadata in family_metadata.fonts {
+    let family_metadata = family_proto(mdpb, context)?;
+    for font_metadata in family_metadata.fonts.iter() {
         let copyright = font_metadata.copyright();
         if copyright.contains("Reserved Font Name") {
             problems.push(Status::warn(
@@ -49,6 +49,7 @@ fn support_percentage(
 
 #[check(
     id="googlefonts/metadata/subsets_correct",
+    tags="metadata",
     rationale="
         The subsets fields in METADATA.pb must not contain any subsets
         for which the font has zero codepoints, and should contain all
@@ -65,7 +66,7 @@ fn subsets_correct(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let fonts = msg
         .fonts
         .iter()
@@ -75,7 +76,7 @@ fn subsets_correct(c: &TestableCollection, context: &Context) -> CheckFnResult {
     if fonts.is_empty() {
         skip!("no-fonts", "No font files found in METADATA.pb");
     }
-    let subsets = msg.subsets;
+    let subsets = msg.subsets.clone();
     let mut problems = vec![];
 
     // Let's get our SUBSETS constant into a useful format.
@@ -6,6 +6,8 @@ use skrifa::string::StringId;
 
 #[check(
     id = "googlefonts/metadata/valid_nameid25",
+    aliases = "com.google.fonts/check/metadata/valid_nameid25",
+    tags = "metadata",
     rationale = "
         
         Due to a bug in (at least) Adobe Indesign, name ID 25
@@ -59,6 +59,7 @@ fn clean_url(url: &str) -> String {
 
 #[check(
     id = "googlefonts/metadata/validate",
+    tags = "metadata",
     title = "Check METADATA.pb parses correctly",
     rationale = "
         The purpose of this check is to ensure that the METADATA.pb file is not
@@ -67,8 +68,8 @@ fn clean_url(url: &str) -> String {
     proposal = "https://github.com/fonttools/fontbakery/issues/2248",
     applies_to = "MDPB"
 )]
-fn validate(c: &Testable, _context: &Context) -> CheckFnResult {
-    let msg = family_proto(c).map_err(|e| {
+fn validate(c: &Testable, context: &Context) -> CheckFnResult {
+    let msg = family_proto(c, context).map_err(|e| {
         CheckError::Error(format!("METADATA.pb is not a valid FamilyProto: {:?}", e))
     })?;
     let mut problems = vec![];
@@ -3,6 +3,7 @@ use skrifa::raw::tables::name::NameId;
 
 #[check(
     id = "googlefonts/name/description_max_length",
+    aliases = "com.google.fonts/check/name/description_max_length",
     rationale = "
         
         An old FontLab version had a bug which caused it to store copyright notices
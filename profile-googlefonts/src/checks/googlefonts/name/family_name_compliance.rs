@@ -27,6 +27,7 @@ static ABBREVIATION_EXCEPTIONS: LazyLock<HashSet<String>> = LazyLock::new(|| {
 
 #[check(
     id = "googlefonts/name/family_name_compliance",
+    aliases = "com.google.fonts/check/name/family_name_compliance",
     rationale = "
         
         Checks the family name for compliance with the Google Fonts Guide.
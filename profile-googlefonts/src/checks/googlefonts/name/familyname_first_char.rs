@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "googlefonts/name/familyname_first_char",
+    aliases = "com.google.fonts/check/name/familyname_first_char",
     rationale = "
         
         Font family names which start with a numeral are often not discoverable
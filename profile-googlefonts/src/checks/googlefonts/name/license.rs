@@ -4,6 +4,8 @@ use skrifa::string::StringId;
 
 #[check(
     id = "googlefonts/name/license",
+    aliases = "com.google.fonts/check/name/license",
+    tags = "licensing",
     rationale = "
         
         A known licensing description must be provided in the NameID 14
@@ -71,7 +73,7 @@ fn license(c: &TestableCollection, _context: &Context) -> CheckFnResult {
                             record.encoding_id(),
                             record.name_id()
                         ),
-                    ));
+                    ).with_location(Location::name_record(record)));
                     license_description = license_description.replace("http://", "https://");
                     http_warn = true;
                 }
@@ -93,7 +95,7 @@ fn license(c: &TestableCollection, _context: &Context) -> CheckFnResult {
                             license_description,
                             placeholder
                         ),
-                    ));
+                    ).with_location(Location::name_record(record)));
                 }
             }
         }
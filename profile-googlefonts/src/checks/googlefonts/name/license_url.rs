@@ -35,6 +35,8 @@ fn identify_license(name_string: &str) -> Option<&'static License> {
 
 #[check(
     id = "googlefonts/name/license_url",
+    aliases = "com.google.fonts/check/name/license_url",
+    tags = "licensing",
     rationale = "
         
         A known license URL must be provided in the NameID 14 (LICENSE INFO URL)
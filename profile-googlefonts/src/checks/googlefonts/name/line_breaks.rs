@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "googlefonts/name/line_breaks",
+    aliases = "com.google.fonts/check/name/line_breaks",
     rationale = "
         
         There are some entries on the name table that may include more than one line
@@ -25,14 +26,17 @@ fn line_breaks(t: &Testable, _context: &Context) -> CheckFnResult {
     for record in name.name_record().iter() {
         let string = record.string(name.string_data())?;
         if string.chars().any(|c| c == '\n') {
-            problems.push(Status::fail(
-                "line-break",
-                &format!(
-                    "Name entry {} on platform {} contains a line-break.",
-                    record.name_id(),
-                    record.platform_id()
-                ),
-            ));
+            problems.push(
+                Status::fail(
+                    "line-break",
+                    &format!(
+                        "Name entry {} on platform {} contains a line-break.",
+                        record.name_id(),
+                        record.platform_id()
+                    ),
+                )
+                .with_location(Location::name_record(record)),
+            );
         }
     }
     return_result(problems)
@@ -3,6 +3,7 @@ use skrifa::string::StringId;
 
 #[check(
     id = "googlefonts/name/mandatory_entries",
+    aliases = "com.google.fonts/check/name/mandatory_entries",
     rationale = "
         
         We require all fonts to have values for their font family name,
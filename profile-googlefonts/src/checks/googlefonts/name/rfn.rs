@@ -12,6 +12,8 @@ static RFN_RE: LazyLock<Regex> =
 // Although this is a /name/ check, it's really about licensing
 #[check(
     id = "googlefonts/name/rfn",
+    aliases = "com.google.fonts/check/name/rfn",
+    tags = "licensing",
     title = "Name table strings must not contain the string 'Reserved Font Name'.",
     rationale = "
         Some designers adopt the \"Reserved Font Name\" clause of the OFL license. This
@@ -63,27 +65,33 @@ fn rfn(t: &Testable, _context: &Context) -> CheckFnResult {
             let rfn_string = &matches.expect("wont happen")[1];
 
             if familyname.contains(rfn_string) {
-                problems.push(Status::fail(
-                    "rfn",
-                    &format!(
-                        "Name table entry contains \"Reserved Font Name\":\n\
+                problems.push(
+                    Status::fail(
+                        "rfn",
+                        &format!(
+                            "Name table entry contains \"Reserved Font Name\":\n\
                               \t\"{}\"\n\
                               \n\
                               This is bad except in a few specific rare cases.",
-                        name_string
-                    ),
-                ));
+                            name_string
+                        ),
+                    )
+                    .with_location(Location::name_record(name)),
+                );
             } else {
-                problems.push(Status::warn(
-                    "legacy-familyname",
-                    &format!(
-                        "Name table entry contains \"Reserved Font Name\" for a \
+                problems.push(
+                    Status::warn(
+                        "legacy-familyname",
+                        &format!(
+                            "Name table entry contains \"Reserved Font Name\" for a \
                               family name ({}) that differs \
                               from the currently used family name ({}), \
                               which is fine.",
-                        rfn_string, familyname
-                    ),
-                ));
+                            rfn_string, familyname
+                        ),
+                    )
+                    .with_location(Location::name_record(name)),
+                );
             }
         }
     }
@@ -10,6 +10,7 @@ static VALID_VERSION_RE: LazyLock<Regex> =
 
 #[check(
     id = "googlefonts/name/version_format",
+    aliases = "com.google.fonts/check/name/version_format",
     rationale = "
         
         For Google Fonts, the version string must be in the format \"Version X.Y\".
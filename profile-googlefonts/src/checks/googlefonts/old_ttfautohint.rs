@@ -5,6 +5,7 @@ use crate::constants::{LATEST_TTFAUTOHINT_VERSION, TTFAUTOHINT_RE};
 
 #[check(
     id = "googlefonts/old_ttfautohint",
+    aliases = "com.google.fonts/check/old_ttfautohint",
     rationale = "
         Check if font has been hinted with an outdated version of ttfautohint.
     ",
@@ -4,6 +4,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "googlefonts/render_own_name",
+    aliases = "com.google.fonts/check/render_own_name",
     title = "Ensure font can render its own name.",
     rationale = "
         A base expectation is that a font family's regular/default (400 roman) style
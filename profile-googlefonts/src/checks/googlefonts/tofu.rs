@@ -105,7 +105,6 @@ impl OurLang<'_> {
         &self,
         codepoints: &HashSet<char>,
         subsets: &[(&str, &[u32])],
-        context: &Context,
         support: Option<String>,
     ) -> Vec<Status> {
         let mut problems = vec![];
@@ -133,10 +132,9 @@ impl OurLang<'_> {
                 problems.push(Status::fail(
                     reason,
                     &format!(
-                    "We detected support for the {} language because {}, but the font is missing the following codepoints needed to render the {} sample text{}:\n{}",
+                    "We detected support for the {} language because {}, but the font is missing the following codepoints needed to render the {} sample text{}:",
                     self.name, support.as_ref().unwrap(), sample_name, supplement,
-                    bullet_list(context,unique_missing.iter().map(|c| format!("{} (U+{:04X})", c, *c as u32)).collect::<Vec<_>>())                    
-                )));
+                )).with_items(unique_missing.iter().map(|c| format!("{} (U+{:04X})", c, *c as u32))));
                 missing_codepoints.extend(unique_missing);
             }
         }
@@ -174,11 +172,10 @@ impl OurLang<'_> {
                 problems.push(Status::fail(
                     "missing-subsetted",
                     &format!(
-                    "The font has the following codepoints needed to render the {} sample text for language {}, but although {}, tofu will still be produced because the codepoints do not appear in any of the subsets {}:\n{}",
+                    "The font has the following codepoints needed to render the {} sample text for language {}, but although {}, tofu will still be produced because the codepoints do not appear in any of the subsets {}:",
                     sample_name, self.name, support.as_ref().unwrap(),
-                    subsets.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>().join(", "), 
-                     bullet_list(context,unique_missing.iter().map(|c| format!("{} (U+{:04X})", c, *c as u32)).collect::<Vec<_>>())
-                )));
+                    subsets.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>().join(", "),
+                )).with_items(unique_missing.iter().map(|c| format!("{} (U+{:04X})", c, *c as u32))));
                 missing_codepoints.extend(unique_missing);
             }
         }
@@ -201,7 +198,7 @@ fn tofu(c: &TestableCollection, context: &Context) -> CheckFnResult {
     let mdpb = c
         .get_file("METADATA.pb")
         .ok_or_else(|| CheckError::skip("no-mdpb", "No METADATA.pb file found"))?;
-    let msg = family_proto(mdpb)?;
+    let msg = family_proto(mdpb, context)?;
     let testable = msg
         .fonts
         .first()
@@ -230,7 +227,7 @@ fn tofu(c: &TestableCollection, context: &Context) -> CheckFnResult {
         .iter()
         .flat_map(|l| {
             if let Some(support) = l.determine_support(&msg, &codepoints) {
-                l.find_problems(&codepoints, &subsets, context, Some(support))
+                l.find_problems(&codepoints, &subsets, Some(support))
             } else {
                 vec![]
             }
@@ -5,6 +5,7 @@ const RECOMMENDED: [u16; 11] = [16, 32, 64, 128, 256, 500, 512, 1000, 1024, 2000
 
 #[check(
     id = "googlefonts/unitsperem",
+    aliases = "com.google.fonts/check/unitsperem_strict",
     rationale = "
         
         Even though the OpenType spec allows unitsPerEm to be any value between 16
@@ -3,6 +3,7 @@ use read_fonts::{tables::os2::SelectionFlags, TableProvider};
 
 #[check(
     id = "googlefonts/use_typo_metrics",
+    aliases = "com.google.fonts/check/os2/use_typo_metrics",
     rationale = "
         All fonts on the Google Fonts collection should have OS/2.fsSelection bit 7
         (USE_TYPO_METRICS) set. This requirement is part of the vertical metrics scheme
@@ -2,6 +2,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
 
 #[check(
     id = "googlefonts/varfont/has_HVAR",
+    aliases = "com.google.fonts/check/varfont/has_HVAR",
+    tags = "variable",
     rationale = "
         
         Not having a HVAR table can lead to costly text-layout operations on some
@@ -17,6 +17,7 @@ const SUGGEST_MICROSOFT_VENDORLIST_WEBSITE: &str = "If you registered it recentl
 
 #[check(
     id = "googlefonts/vendor_id",
+    aliases = "com.google.fonts/check/vendor_id",
     rationale = "
         
         Microsoft keeps a list of font vendors and their respective contact info. This
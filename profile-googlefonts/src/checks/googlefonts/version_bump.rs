@@ -5,6 +5,8 @@ use crate::network_conditions::remote_styles;
 
 #[check(
     id = "googlefonts/version_bump",
+    aliases = "com.google.fonts/check/version_bump",
+    tags = "network",
     rationale = "
         
         We check that the version number has been bumped since the last release on
@@ -4,6 +4,8 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "googlefonts/vertical_metrics",
+    aliases = "com.google.fonts/check/vertical_metrics",
+    tags = "network",
     rationale = "
         
         This check generally enforces Google Fonts’ vertical metrics specifications.
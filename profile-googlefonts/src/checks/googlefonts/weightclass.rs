@@ -6,6 +6,7 @@ use crate::utils::build_expected_font;
 
 #[check(
     id = "googlefonts/weightclass",
+    aliases = "com.google.fonts/check/usweightclass",
     rationale = "
         
         Google Fonts expects variable fonts, static ttfs and static otfs to have
@@ -1,18 +1,18 @@
 use std::collections::HashMap;
 
-use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert, DEFAULT_LOCATION};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use read_fonts::TableProvider;
-use skrifa::{outline::OutlinePen, MetadataProvider};
+use skrifa::outline::OutlinePen;
 
-use super::close_but_not_on;
+use super::{close_but_not_on, outline_glyphs, outline_locations, OutlineFindings};
 const ALIGNMENT_MISS_EPSILON: i16 = 2; // Four point lee-way on alignment misses
 
 struct AlignmentMissPen<'a> {
-    glyph_name: &'a str,
     is_uppercase: bool,
     alignments: &'a HashMap<String, i16>,
     epsilon: i16,
-    warnings: Vec<String>,
+    warnings: Vec<(&'a str, String)>,
+    misses: Vec<(f32, f32)>,
 }
 
 impl AlignmentMissPen<'_> {
@@ -22,10 +22,11 @@ impl AlignmentMissPen<'_> {
                 continue;
             }
             if close_but_not_on(*y_expected, y as i16, self.epsilon) {
-                self.warnings.push(format!(
-                    "{}: X={},Y={} (should be at {} {}?)",
-                    self.glyph_name, x, y, line, y_expected
+                self.warnings.push((
+                    line.as_str(),
+                    format!("X={},Y={} (should be at {} {}?)", x, y, line, y_expected),
                 ));
+                self.misses.push((x, y));
             }
         }
     }
@@ -53,6 +54,8 @@ impl OutlinePen for AlignmentMissPen<'_> {
 
 #[check(
     id = "outline_alignment_miss",
+    aliases = "com.google.fonts/check/outline_alignment_miss",
+    tags = "outline",
     rationale = "
         
         This check heuristically looks for on-curve points which are close to, but
@@ -89,26 +92,31 @@ fn alignment_miss(t: &Testable, context: &Context) -> CheckFnResult {
                 &format!("x-height and cap-height checks are skipped because OS/2 table version is only {} and version >= 2 is required for those checks."
                 ,os2.version())));
     }
-    let mut all_warnings = vec![];
-    for glyph in f.all_glyphs() {
-        let mut name = f.glyph_name_for_id_synthesise(glyph);
-        if let Some((cp, _gid)) = f
-            .font()
-            .charmap()
-            .mappings()
-            .find(|(_cp, gid)| *gid == glyph)
-        {
-            name = format!("{} (U+{:04X})", name, cp);
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
+    for glyph in outline_glyphs(&f) {
+        let name = glyph.to_string();
+        for location in locations.iter() {
+            let mut pen = AlignmentMissPen {
+                is_uppercase: name.len() > 1 || name.to_uppercase() == name,
+                alignments: &alignments,
+                epsilon: ALIGNMENT_MISS_EPSILON,
+                warnings: vec![],
+                misses: vec![],
+            };
+            let outline = f.cached_bezglyph(context, glyph.id, location.settings())?;
+            outline.replay(&mut pen);
+            for (line, warning) in pen.warnings {
+                all_warnings.add(&glyph, location, line, warning);
+            }
+            if !pen.misses.is_empty() {
+                all_warnings.add_image(&glyph, location, &outline, |svg| {
+                    for (x, y) in pen.misses {
+                        svg.highlight_point(x, y);
+                    }
+                });
+            }
         }
-        let mut pen = AlignmentMissPen {
-            is_uppercase: name.len() > 1 || name.to_uppercase() == name,
-            alignments: &alignments,
-            epsilon: ALIGNMENT_MISS_EPSILON,
-            warnings: vec![],
-            glyph_name: &name,
-        };
-        f.draw_glyph(glyph, &mut pen, DEFAULT_LOCATION)?;
-        all_warnings.extend(pen.warnings);
         if all_warnings.len() > 100 {
             problems.push(Status::pass(
                 // "skip-many-misalignments",
@@ -118,13 +126,20 @@ fn alignment_miss(t: &Testable, context: &Context) -> CheckFnResult {
         }
     }
     if !all_warnings.is_empty() {
-        problems.push(Status::warn(
-            "found-misalignments",
-            &format!(
-                "The following glyphs have on-curve points which have potentially incorrect y coordinates:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        ));
+        let glyph_locations = all_warnings.locations();
+        let images = all_warnings.images();
+        problems.push(
+            Status::warn(
+                "found-misalignments",
+                "The following glyphs have on-curve points which have potentially \
+                 incorrect y coordinates:",
+            )
+            .with_items(
+                all_warnings.into_messages(|glyph, details| format!("{}: {}", glyph, details)),
+            )
+            .with_locations(glyph_locations)
+            .with_images(images),
+        );
     }
 
     return_result(problems)
@@ -1,19 +1,23 @@
-use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use itertools::Itertools;
 
-use crate::checks::outline::name_and_bezglyph;
+use crate::checks::outline::{name_and_bezglyph, outline_locations, OutlineFindings};
 
 const COLINEAR_EPSILON: f64 = 0.1; // Radians
 
 #[check(
     id = "outline_colinear_vectors",
+    aliases = "com.google.fonts/check/outline_colinear_vectors",
+    tags = "outline",
     rationale = "
         
         This check looks for consecutive line segments which have the same angle. This
         normally happens if an outline point has been added by accident.
 
-        This check is not run for variable fonts, as they may legitimately have
-        colinear vectors.
+        Variable fonts may legitimately have points which are only colinear at
+        some masters, so that the outline interpolates with the others; for
+        variable fonts, only glyphs with colinear vectors at every master and
+        named instance are reported.
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
@@ -21,13 +25,9 @@ const COLINEAR_EPSILON: f64 = 0.1; // Radians
 )]
 fn colinear_vectors(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
-    let mut all_warnings = vec![];
-    skip!(
-        f.is_variable_font(),
-        "variable-font",
-        "This check produces too many false positives with variable fonts."
-    );
-    for (name, result) in name_and_bezglyph(&f) {
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let pen = result?;
         for contour in pen.iter() {
             let segs = contour.segments().collect::<Vec<_>>();
@@ -36,7 +36,12 @@ fn colinear_vectors(t: &Testable, context: &Context) -> CheckFnResult {
                     let prev_angle = (prev.p1 - prev.p0).angle();
                     let next_angle = (next.p1 - next.p0).angle();
                     if (prev_angle - next_angle).abs() < COLINEAR_EPSILON {
-                        all_warnings.push(format!("{}: {:?} -> {:?}", name, prev, next));
+                        all_warnings.add(
+                            &glyph,
+                            location,
+                            "colinear",
+                            format!("{:?} -> {:?}", prev, next),
+                        );
                     }
                 }
             }
@@ -45,15 +50,15 @@ fn colinear_vectors(t: &Testable, context: &Context) -> CheckFnResult {
             }
         }
     }
-    Ok(if !all_warnings.is_empty() {
-        Status::just_one_warn(
-            "found-colinear-vectors",
-            &format!(
-                "The following glyphs have colinear vectors:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        )
-    } else {
-        Status::just_one_pass()
-    })
+    all_warnings.retain_found_everywhere();
+    if all_warnings.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    let glyph_locations = all_warnings.locations();
+    return_result(vec![Status::warn(
+        "found-colinear-vectors",
+        "The following glyphs have colinear vectors:",
+    )
+    .with_items(all_warnings.into_messages(|glyph, details| format!("{}: {}", glyph, details)))
+    .with_locations(glyph_locations)])
 }
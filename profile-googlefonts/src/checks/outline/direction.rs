@@ -1,4 +1,4 @@
-use super::name_and_bezglyph;
+use super::{name_and_bezglyph, outline_locations, OutlineFindings};
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use kurbo::{Rect, Shape};
 
@@ -8,6 +8,8 @@ use kurbo::{Rect, Shape};
 // fontmake when doing things with cubic sources.
 #[check(
     id = "outline_direction",
+    aliases = "com.google.fonts/check/outline_direction",
+    tags = "outline",
     rationale = "
         
         In TrueType fonts, the outermost contour of a glyph should be oriented
@@ -22,17 +24,20 @@ use kurbo::{Rect, Shape};
 fn direction(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
-    let mut all_warnings = vec![];
-    for (name, result) in name_and_bezglyph(&f) {
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let pen = result?;
         let bounds: Vec<Rect> = pen.iter().map(|path| path.bounding_box()).collect();
         let mut is_within = vec![vec![]; bounds.len()];
         for (i, my_bounds) in bounds.iter().enumerate() {
             if my_bounds.is_zero_area() {
-                all_warnings.push(format!(
-                    "{} has a path with no bounds (probably a single point)",
-                    name
-                ));
+                all_warnings.add(
+                    &glyph,
+                    location,
+                    "no-bounds",
+                    "has a path with no bounds (probably a single point)".to_string(),
+                );
                 continue;
             }
             for (j, their_bounds) in bounds.iter().enumerate() {
@@ -49,18 +54,27 @@ fn direction(t: &Testable, context: &Context) -> CheckFnResult {
         }
         for (i, path) in pen.iter().enumerate() {
             if is_within[i].is_empty() && path.area() > 0.0 {
-                all_warnings.push(format!("{} has a counter-clockwise outer contour", name));
+                all_warnings.add(
+                    &glyph,
+                    location,
+                    "ccw-outer-contour",
+                    "has a counter-clockwise outer contour".to_string(),
+                );
             }
         }
     }
     if !all_warnings.is_empty() {
-        problems.push(Status::warn(
-            "ccw-outer-contour",
-            &format!(
-                "The following glyphs have a counter-clockwise outer contour:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        ));
+        let glyph_locations = all_warnings.locations();
+        problems.push(
+            Status::warn(
+                "ccw-outer-contour",
+                "The following glyphs have a counter-clockwise outer contour:",
+            )
+            .with_items(
+                all_warnings.into_messages(|glyph, details| format!("{} {}", glyph, details)),
+            )
+            .with_locations(glyph_locations),
+        );
     }
 
     return_result(problems)
@@ -1,5 +1,5 @@
-use crate::checks::outline::name_and_bezglyph;
-use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use crate::checks::outline::{name_and_bezglyph, outline_locations, OutlineFindings};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use itertools::Itertools;
 use kurbo::{ParamCurve, ParamCurveDeriv, PathSeg, Vec2};
 
@@ -12,10 +12,24 @@ fn tangent_at_time(p: &PathSeg, t: f64) -> Vec2 {
     .to_vec2()
 }
 
+/// The points of a segment, for highlighting it in a picture of the glyph
+fn segment_points(p: &PathSeg) -> Vec<(f32, f32)> {
+    match p {
+        PathSeg::Line(line) => vec![line.p0, line.p1],
+        PathSeg::Quad(quad_bez) => vec![quad_bez.p0, quad_bez.p1, quad_bez.p2],
+        PathSeg::Cubic(cubic_bez) => vec![cubic_bez.p0, cubic_bez.p1, cubic_bez.p2, cubic_bez.p3],
+    }
+    .into_iter()
+    .map(|point| (point.x as f32, point.y as f32))
+    .collect()
+}
+
 const JAG_ANGLE: f64 = 0.25; // Radians
 
 #[check(
     id = "outline_jaggy_segments",
+    aliases = "com.google.fonts/check/outline_jaggy_segments",
+    tags = "outline",
     rationale = "
         
         This check heuristically detects outline segments which form a particularly
@@ -29,16 +43,13 @@ const JAG_ANGLE: f64 = 0.25; // Radians
 )]
 fn jaggy_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
-    skip!(
-        f.is_variable_font(),
-        "variable-font",
-        "This check produces too many false positives with variable fonts."
-    );
     let mut problems = vec![];
-    let mut all_warnings = vec![];
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
 
-    for (name, result) in name_and_bezglyph(&f) {
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let pen = result?;
+        let mut jags = vec![];
         for path in pen.iter() {
             let segs = path.segments().collect::<Vec<_>>();
             for (prev, cur) in segs.iter().circular_tuple_windows() {
@@ -55,24 +66,40 @@ fn jaggy_segments(t: &Testable, context: &Context) -> CheckFnResult {
                 if jag_angle.abs() > JAG_ANGLE || jag_angle == 0.0 {
                     continue;
                 }
-                all_warnings.push(format!(
-                    "{}: {:?}/{:?} = {}",
-                    name,
-                    prev,
-                    cur,
-                    jag_angle.to_degrees()
-                ));
+                all_warnings.add(
+                    &glyph,
+                    location,
+                    "jaggy",
+                    format!("{:?}/{:?} = {}", prev, cur, jag_angle.to_degrees()),
+                );
+                jags.push((*prev, *cur));
             }
         }
+        if !jags.is_empty() {
+            all_warnings.add_image(&glyph, location, &pen, |svg| {
+                for (prev, cur) in jags {
+                    svg.highlight_segment(&segment_points(&prev));
+                    svg.highlight_segment(&segment_points(&cur));
+                    let corner = prev.end();
+                    svg.highlight_point(corner.x as f32, corner.y as f32);
+                }
+            });
+        }
     }
     if !all_warnings.is_empty() {
-        problems.push(Status::warn(
-            "found-jaggy-segments",
-            &format!(
-                "The following glyphs have jaggy segments:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        ));
+        let glyph_locations = all_warnings.locations();
+        let images = all_warnings.images();
+        problems.push(
+            Status::warn(
+                "found-jaggy-segments",
+                "The following glyphs have jaggy segments:",
+            )
+            .with_items(
+                all_warnings.into_messages(|glyph, details| format!("{}: {}", glyph, details)),
+            )
+            .with_locations(glyph_locations)
+            .with_images(images),
+        );
     }
     return_result(problems)
 }
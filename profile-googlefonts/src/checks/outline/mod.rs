@@ -1,6 +1,11 @@
-use fontspector_checkapi::{pens::BezGlyph, CheckError, TestFont, DEFAULT_LOCATION};
-use skrifa::MetadataProvider;
-use std::ops::Sub;
+use fontspector_checkapi::{
+    pens::{BezGlyph, SvgPen},
+    CheckError, Context, GlyphImage, Location, TestFont,
+};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use skrifa::{GlyphId, MetadataProvider};
+use std::{collections::HashMap, fmt::Display, ops::Sub, sync::Arc};
 
 mod alignment_miss;
 mod colinear_vectors;
@@ -24,21 +29,325 @@ where
     (actual - expected).abs() <= epsilon && actual != expected
 }
 
+/// A location at which we examine a font's outlines
+pub(crate) struct OutlineLocation {
+    /// A name for the location, suitable for reporting to the user
+    pub name: String,
+    /// The location in user coordinates; empty for a static font
+    pub coords: HashMap<String, f32>,
+}
+
+impl OutlineLocation {
+    /// The variation settings to draw glyphs at this location
+    pub fn settings(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.coords
+            .iter()
+            .map(|(tag, value)| (tag.as_str(), *value))
+    }
+}
+
+/// The locations at which to examine a font's outlines
+///
+/// For a static font this is just the default location. For a variable font
+/// it is each named instance, the default location and each master, so that
+/// problems which only appear away from the default are found too.
+pub(crate) fn outline_locations(f: &TestFont) -> Vec<OutlineLocation> {
+    let Some(default) = f.default_location() else {
+        return vec![OutlineLocation {
+            name: "default".to_string(),
+            coords: HashMap::new(),
+        }];
+    };
+    let mut locations: Vec<OutlineLocation> = f
+        .named_instances()
+        .map(|(name, coords)| OutlineLocation { name, coords })
+        .collect();
+    let axis_order = f.axis_ranges().map(|(tag, ..)| tag).collect::<Vec<_>>();
+    let others = std::iter::once(("default".to_string(), default)).chain(
+        f.master_locations().into_iter().map(|coords| {
+            let name = axis_order
+                .iter()
+                .flat_map(|tag| coords.get(tag).map(|value| format!("{}={}", tag, value)))
+                .join(",");
+            (name, coords)
+        }),
+    );
+    for (name, coords) in others {
+        if !locations.iter().any(|location| location.coords == coords) {
+            locations.push(OutlineLocation { name, coords });
+        }
+    }
+    locations
+}
+
+/// A glyph whose outline we examine
+#[derive(Clone)]
+pub(crate) struct OutlineGlyph {
+    /// The glyph ID
+    pub id: GlyphId,
+    /// The glyph name
+    pub name: String,
+    /// The first codepoint mapped to the glyph, if any
+    pub codepoint: Option<u32>,
+}
+
+impl OutlineGlyph {
+    /// The glyph's location in the font
+    pub fn location(&self) -> Location {
+        let location = Location::glyph(self.id, self.name.clone());
+        match self.codepoint {
+            Some(cp) => location.with_codepoint(cp),
+            None => location,
+        }
+    }
+}
+
+/// The glyph's name, with its codepoint if it has one
+impl Display for OutlineGlyph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.codepoint {
+            Some(cp) => write!(f, "{} (U+{:04X})", self.name, cp),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Each glyph in the font
+pub(crate) fn outline_glyphs(f: &TestFont) -> Vec<OutlineGlyph> {
+    let mut codepoints = HashMap::new();
+    for (cp, gid) in f.font().charmap().mappings() {
+        codepoints.entry(gid).or_insert(cp);
+    }
+    f.all_glyphs()
+        .map(|glyph| OutlineGlyph {
+            id: glyph,
+            name: f.glyph_name_for_id_synthesise(glyph),
+            codepoint: codepoints.get(&glyph).copied(),
+        })
+        .collect()
+}
+
+/// Draw each glyph of the font at each of the given locations
+///
+/// Outlines are shared with the other outline checks through the context.
 pub(crate) fn name_and_bezglyph<'a>(
     f: &'a TestFont,
-) -> impl Iterator<Item = (String, Result<BezGlyph, CheckError>)> + 'a {
-    f.all_glyphs().map(|glyph| {
-        let mut name = f.glyph_name_for_id_synthesise(glyph);
-        if let Some((cp, _gid)) = f
-            .font()
-            .charmap()
-            .mappings()
-            .find(|(_cp, gid)| *gid == glyph)
-        {
-            name = format!("{} (U+{:04X})", name, cp);
-        }
-        let mut pen = BezGlyph::default();
-        let result = f.draw_glyph(glyph, &mut pen, DEFAULT_LOCATION);
-        (name, result.map(|_| pen))
+    context: &'a Context,
+    locations: &'a [OutlineLocation],
+) -> impl Iterator<
+    Item = (
+        OutlineGlyph,
+        &'a OutlineLocation,
+        Result<Arc<BezGlyph>, CheckError>,
+    ),
+> + 'a {
+    outline_glyphs(f).into_iter().flat_map(move |glyph| {
+        locations.iter().map(move |location| {
+            let result = f.cached_bezglyph(context, glyph.id, location.settings());
+            (glyph.clone(), location, result)
+        })
     })
 }
+
+/// The most glyph pictures an outline check attaches to its status
+const MAX_GLYPH_IMAGES: usize = 9;
+
+/// A kind of problem found in a glyph by an outline check
+struct OutlineFinding {
+    /// The glyph the problem was found in
+    glyph: OutlineGlyph,
+    /// The names of the locations where the problem was found
+    found_at: Vec<String>,
+    /// The glyph at each location where the problem was found
+    glyph_at: Vec<Location>,
+    /// The details (usually coordinates) of each instance of the problem,
+    /// with the name of the location where it was found
+    details: Vec<(String, String)>,
+}
+
+/// The problems found by an outline check, and the locations at which they were found
+///
+/// Problems are grouped by glyph and kind, so that a problem which appears
+/// at several locations is reported once, with its details at each location.
+pub(crate) struct OutlineFindings {
+    /// The number of locations examined
+    location_count: usize,
+    /// Each kind of problem in each glyph, keyed by glyph name and kind
+    findings: IndexMap<(String, String), OutlineFinding>,
+    /// The number of distinct problems found at each location
+    found_per_location: HashMap<String, usize>,
+    /// Pictures of the first few glyphs with problems
+    images: Vec<GlyphImage>,
+}
+
+impl OutlineFindings {
+    /// Start collecting problems found at the given locations
+    pub fn new(locations: &[OutlineLocation]) -> Self {
+        OutlineFindings {
+            location_count: locations.len(),
+            findings: IndexMap::new(),
+            found_per_location: HashMap::new(),
+            images: vec![],
+        }
+    }
+
+    /// Picture a glyph with problems, highlighting them with the given function
+    ///
+    /// Only the first few glyphs are pictured, so that reports stay a
+    /// reasonable size; after that the glyph is not drawn at all.
+    pub fn add_image(
+        &mut self,
+        glyph: &OutlineGlyph,
+        location: &OutlineLocation,
+        outline: &BezGlyph,
+        highlight: impl FnOnce(&mut SvgPen),
+    ) {
+        if self.images.len() >= MAX_GLYPH_IMAGES {
+            return;
+        }
+        let mut pen = SvgPen::new();
+        outline.replay(&mut pen);
+        highlight(&mut pen);
+        self.images.push(GlyphImage {
+            glyph: glyph.location().at(location.settings()),
+            svg: pen.svg(),
+        });
+    }
+
+    /// The pictures of glyphs with problems, for the status
+    pub fn images(&self) -> Vec<GlyphImage> {
+        self.images.clone()
+    }
+
+    /// Record a problem of the given kind found in a glyph at a location
+    ///
+    /// The detail describes this instance of the problem, usually by its
+    /// coordinates; the same detail found twice at one location is only
+    /// recorded once.
+    pub fn add(
+        &mut self,
+        glyph: &OutlineGlyph,
+        location: &OutlineLocation,
+        kind: &str,
+        detail: String,
+    ) {
+        let entry = self
+            .findings
+            .entry((glyph.name.clone(), kind.to_string()))
+            .or_insert_with(|| OutlineFinding {
+                glyph: glyph.clone(),
+                found_at: vec![],
+                glyph_at: vec![],
+                details: vec![],
+            });
+        if !entry.found_at.contains(&location.name) {
+            entry.found_at.push(location.name.clone());
+            entry
+                .glyph_at
+                .push(glyph.location().at(location.settings()));
+        }
+        let detail = (location.name.clone(), detail);
+        if !entry.details.contains(&detail) {
+            entry.details.push(detail);
+            *self
+                .found_per_location
+                .entry(location.name.clone())
+                .or_default() += 1;
+        }
+    }
+
+    /// Where each problem was found, for the status's structured locations
+    ///
+    /// As with the messages, a problem found everywhere is given without a
+    /// design-space location.
+    pub fn locations(&self) -> Vec<Location> {
+        let mut locations: Vec<Location> = vec![];
+        for finding in self.findings.values() {
+            let glyph = finding.glyph.location();
+            let found = if finding.found_at.len() == self.location_count {
+                std::slice::from_ref(&glyph)
+            } else {
+                finding.glyph_at.as_slice()
+            };
+            for location in found {
+                if !locations.contains(location) {
+                    locations.push(location.clone());
+                }
+            }
+        }
+        locations
+    }
+
+    /// The most distinct problems found at any one location
+    ///
+    /// Checks which give up on fonts with too many problems compare this
+    /// against their threshold, so that examining more locations does not
+    /// make them give up sooner.
+    pub fn len(&self) -> usize {
+        self.found_per_location.values().copied().max().unwrap_or(0)
+    }
+
+    /// Forget the problems which were not found at every location
+    ///
+    /// Variable fonts often need points which are redundant at one master so
+    /// that the outline interpolates with the others. Checks for such points
+    /// call this so that they only report glyphs where the problem is found
+    /// everywhere. For a static font this changes nothing.
+    pub fn retain_found_everywhere(&mut self) {
+        let location_count = self.location_count;
+        self.findings
+            .retain(|_, finding| finding.found_at.len() == location_count);
+        let findings = &self.findings;
+        self.images.retain(|image| {
+            findings
+                .values()
+                .any(|finding| image.glyph.glyph_name.as_ref() == Some(&finding.glyph.name))
+        });
+    }
+
+    /// Whether no problems were found
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Describe each problem using the given function, which is passed the
+    /// glyph and the details of the problem
+    ///
+    /// When only one location was examined, each instance of a problem is
+    /// described separately. Otherwise each kind of problem in a glyph is
+    /// described once: its details are listed with the locations where they
+    /// were found, unless they were found everywhere.
+    pub fn into_messages(self, describe: impl Fn(&OutlineGlyph, &str) -> String) -> Vec<String> {
+        let mut messages = vec![];
+        for finding in self.findings.into_values() {
+            if self.location_count == 1 {
+                messages.extend(
+                    finding
+                        .details
+                        .iter()
+                        .map(|(_, detail)| describe(&finding.glyph, detail)),
+                );
+                continue;
+            }
+            let mut found_at: IndexMap<&str, Vec<&str>> = IndexMap::new();
+            for (location, detail) in finding.details.iter() {
+                found_at
+                    .entry(detail.as_str())
+                    .or_default()
+                    .push(location.as_str());
+            }
+            let details = found_at
+                .into_iter()
+                .map(|(detail, locations)| {
+                    if locations.len() == self.location_count {
+                        detail.to_string()
+                    } else {
+                        format!("{} (at {})", detail, locations.join(", "))
+                    }
+                })
+                .join("; ");
+            messages.push(describe(&finding.glyph, &details));
+        }
+        messages
+    }
+}
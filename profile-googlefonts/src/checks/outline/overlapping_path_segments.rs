@@ -3,10 +3,12 @@ use std::collections::HashSet;
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use kurbo::ParamCurve;
 
-use crate::checks::outline::name_and_bezglyph;
+use crate::checks::outline::{name_and_bezglyph, outline_locations, OutlineFindings};
 
 #[check(
     id = "overlapping_path_segments",
+    aliases = "com.google.fonts/check/overlapping_path_segments",
+    tags = "outline",
     rationale = "
         
         Some rasterizers encounter difficulties when rendering glyphs with
@@ -22,8 +24,9 @@ use crate::checks::outline::name_and_bezglyph;
 )]
 fn overlapping_path_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
-    let mut all_warnings = vec![];
-    for (name, result) in name_and_bezglyph(&f) {
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let mut seen = HashSet::new();
         let pen = result?;
         for contour in pen.iter() {
@@ -43,24 +46,25 @@ fn overlapping_path_segments(t: &Testable, context: &Context) -> CheckFnResult {
                     start.y.to_bits(),
                 ];
                 if seen.contains(&normal) || seen.contains(&flipped) {
-                    all_warnings.push(format!(
-                        "{}: {:?} has the same coordinates as a previous segment.",
-                        name, seg
-                    ));
+                    all_warnings.add(&glyph, location, "overlapping", format!("{:?}", seg));
                 }
                 seen.insert(normal);
             }
         }
     }
-    Ok(if !all_warnings.is_empty() {
-        Status::just_one_warn(
-            "overlapping-path-segments",
-            &format!(
-                "The following glyphs have overlapping path segments:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
+    if all_warnings.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    let glyph_locations = all_warnings.locations();
+    return_result(vec![Status::warn(
+        "overlapping-path-segments",
+        "The following glyphs have overlapping path segments:",
+    )
+    .with_items(all_warnings.into_messages(|glyph, details| {
+        format!(
+            "{}: {} has the same coordinates as a previous segment.",
+            glyph, details
         )
-    } else {
-        Status::just_one_pass()
-    })
+    }))
+    .with_locations(glyph_locations)])
 }
@@ -1,9 +1,11 @@
-use super::name_and_bezglyph;
+use super::{name_and_bezglyph, outline_locations, OutlineFindings};
 use crate::checks::outline::close_but_not_on;
 use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
 
 #[check(
     id = "outline_semi_vertical",
+    aliases = "com.google.fonts/check/outline_semi_vertical",
+    tags = "outline",
     rationale = "
         
         This check detects line segments which are nearly, but not quite, exactly
@@ -19,19 +21,15 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
 )]
 fn semi_vertical(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
-    let mut all_warnings = vec![];
-    skip!(
-        f.is_variable_font(),
-        "variable-font",
-        "This check produces too many false positives with variable fonts."
-    );
     skip!(
         f.is_italic()?,
         "italic",
         "This check produces too many false positives with italic fonts."
     );
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
 
-    for (name, result) in name_and_bezglyph(&f) {
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let pen = result?;
         for path in pen.iter() {
             for seg in path.segments() {
@@ -39,22 +37,26 @@ fn semi_vertical(t: &Testable, context: &Context) -> CheckFnResult {
                     let angle = (line.p1 - line.p0).angle().to_degrees();
                     for y_expected in [-180.0, -90.0, 0.0, 90.0, 180.0] {
                         if close_but_not_on(angle, y_expected, 0.5) {
-                            all_warnings.push(format!("{}: {:?}", name, seg));
+                            all_warnings.add(
+                                &glyph,
+                                location,
+                                "semi-vertical",
+                                format!("{:?}", seg),
+                            );
                         }
                     }
                 }
             }
         }
     }
-    Ok(if !all_warnings.is_empty() {
-        Status::just_one_warn(
-            "found-semi-vertical",
-            &format!(
-                "The following glyphs have semi-vertical/semi-horizontal lines:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        )
-    } else {
-        Status::just_one_pass()
-    })
+    if all_warnings.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    let glyph_locations = all_warnings.locations();
+    return_result(vec![Status::warn(
+        "found-semi-vertical",
+        "The following glyphs have semi-vertical/semi-horizontal lines:",
+    )
+    .with_items(all_warnings.into_messages(|glyph, details| format!("{}: {}", glyph, details)))
+    .with_locations(glyph_locations)])
 }
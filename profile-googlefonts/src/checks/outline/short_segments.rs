@@ -1,5 +1,5 @@
-use crate::checks::outline::name_and_bezglyph;
-use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use crate::checks::outline::{name_and_bezglyph, outline_locations, OutlineFindings};
+use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 use kurbo::{ParamCurveArclen, PathSeg, Shape};
 
 const SHORT_PATH_ABSOLUTE_EPSILON: f64 = 3.0;
@@ -20,15 +20,18 @@ fn segment_is_short(pathseg: &PathSeg, total_length: f64, prev_was_line: bool) -
 
 #[check(
     id = "outline_short_segments",
+    aliases = "com.google.fonts/check/outline_short_segments",
+    tags = "outline",
     rationale = "
         
         This check looks for outline segments which seem particularly short (less
         than 0.6% of the overall path length).
 
-        This check is not run for variable fonts, as they may legitimately have
-        short segments. As this check is liable to generate significant numbers
-        of false positives, it will pass if there are more than
-        100 reported short segments.
+        Variable fonts may legitimately have segments which are only short at
+        some masters, so for variable fonts only glyphs with short segments at
+        every master and named instance are reported. As this check is liable
+        to generate significant numbers of false positives, it will pass if
+        there are more than 100 reported short segments.
     
     ",
     proposal = "https://github.com/fonttools/fontbakery/pull/3088",
@@ -36,13 +39,9 @@ fn segment_is_short(pathseg: &PathSeg, total_length: f64, prev_was_line: bool) -
 )]
 fn short_segments(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
-    let mut all_warnings = vec![];
-    skip!(
-        f.is_variable_font(),
-        "variable-font",
-        "This check produces too many false positives with variable fonts."
-    );
-    for (name, result) in name_and_bezglyph(&f) {
+    let locations = outline_locations(&f);
+    let mut all_warnings = OutlineFindings::new(&locations);
+    for (glyph, location, result) in name_and_bezglyph(&f, context, &locations) {
         let pen = result?;
         for path in pen.iter() {
             let outline_length = path.perimeter(0.01);
@@ -54,7 +53,7 @@ fn short_segments(t: &Testable, context: &Context) -> CheckFnResult {
             let mut prev_was_line = matches!(segments.last().unwrap(), kurbo::PathSeg::Line(_));
             for seg in segments.iter() {
                 if segment_is_short(seg, outline_length, prev_was_line) {
-                    all_warnings.push(format!("{} contains a short segment {:?}", name, seg));
+                    all_warnings.add(&glyph, location, "short", format!("{:?}", seg));
                 }
                 prev_was_line = matches!(seg, kurbo::PathSeg::Line(_));
             }
@@ -63,15 +62,19 @@ fn short_segments(t: &Testable, context: &Context) -> CheckFnResult {
             }
         }
     }
-    Ok(if !all_warnings.is_empty() {
-        Status::just_one_warn(
-            "found-short-segments",
-            &format!(
-                "The following glyphs have short segments:\n\n{}",
-                bullet_list(context, all_warnings)
-            ),
-        )
-    } else {
-        Status::just_one_pass()
-    })
+    all_warnings.retain_found_everywhere();
+    if all_warnings.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    let glyph_locations = all_warnings.locations();
+    return_result(vec![Status::warn(
+        "found-short-segments",
+        "The following glyphs have short segments:",
+    )
+    .with_items(
+        all_warnings.into_messages(|glyph, details| {
+            format!("{} contains a short segment {}", glyph, details)
+        }),
+    )
+    .with_locations(glyph_locations)])
 }
@@ -9,6 +9,8 @@ use rustybuzz::{Face, GlyphBuffer};
 
 #[check(
     id = "shaping/forbidden",
+    aliases = "com.google.fonts/check/shaping/forbidden",
+    tags = "shaping",
     rationale = "
         
         Fonts with complex layout rules can benefit from regression tests to ensure
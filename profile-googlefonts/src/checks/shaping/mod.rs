@@ -75,7 +75,7 @@ pub(crate) trait ShapingCheck {
         t: &Testable,
         context: &Context,
     ) -> Result<Vec<(String, Vec<FailedCheck>)>, CheckError> {
-        let mut face = Face::from_slice(&t.contents, 0)
+        let mut face = Face::from_slice(t.contents.sfnt(), 0)
             .ok_or(CheckError::Error("Failed to load font file".to_string()))?;
 
         let basename = t.basename().unwrap_or_default();
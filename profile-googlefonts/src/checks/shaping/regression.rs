@@ -8,6 +8,8 @@ use rustybuzz::{Face, GlyphBuffer};
 
 #[check(
     id = "shaping/regression",
+    aliases = "com.google.fonts/check/shaping/regression",
+    tags = "shaping, slow",
     rationale = "
         
         Fonts with complex layout rules can benefit from regression tests to ensure
@@ -24,6 +24,8 @@ const SOFT_DOTTED_CHARS: [char; 47] = [
 
 #[check(
     id = "soft_dotted",
+    aliases = "com.google.fonts/check/soft_dotted",
+    tags = "shaping, slow",
     rationale = "
         
         An accent placed on characters with a \"soft dot\", like i or j, causes
@@ -115,7 +117,7 @@ fn soft_dotted(t: &Testable, context: &Context) -> CheckFnResult {
         );
     }
 
-    let face = Face::from_slice(&t.contents, 0)
+    let face = Face::from_slice(t.contents.sfnt(), 0)
         .ok_or(CheckError::Error("Failed to load font file".to_string()))?;
 
     let mut fail_unchanged_strings = vec![];
@@ -169,22 +171,25 @@ fn soft_dotted(t: &Testable, context: &Context) -> CheckFnResult {
             }
         }
     }
-    let mut message = "".to_string();
+    let mut message = vec![];
     if !fail_unchanged_strings.is_empty() {
-        message.push_str(&format!(
-            "The dot of soft dotted characters used in orthographies _must_ disappear in the following strings: {}",
-            bullet_list(context, fail_unchanged_strings)
-        ));
+        message.push(
+            "The dot of soft dotted characters used in orthographies _must_ disappear in the strings marked as required below.",
+        );
     }
     if !warn_unchanged_strings.is_empty() {
-        message.push_str(&format!(
-            "The dot of soft dotted characters _should_ disappear in other cases, for example: {}",
-            bullet_list(context, warn_unchanged_strings)
-        ));
+        message.push(
+            "The dot of soft dotted characters _should_ disappear in other cases, for example in the strings below.",
+        );
     }
     if message.is_empty() {
         return Ok(Status::just_one_pass());
-    } else {
-        return Ok(Status::just_one_warn("soft-dotted", &message));
     }
+    return_result(vec![Status::warn("soft-dotted", &message.join(" "))
+        .with_items(
+            fail_unchanged_strings
+                .into_iter()
+                .map(|text| format!("{} (required)", text))
+                .chain(warn_unchanged_strings),
+        )])
 }
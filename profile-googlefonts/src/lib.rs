@@ -1,5 +1,6 @@
 #![deny(clippy::unwrap_used, clippy::expect_used)]
 mod checks;
+pub use checks::googlefonts::metadata::declared_font_filenames;
 
 pub mod constants;
 use fontspector_checkapi::{prelude::*, ProfileBuilder, Registry};
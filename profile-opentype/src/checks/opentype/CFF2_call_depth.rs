@@ -0,0 +1,22 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+
+use super::CFF_call_depth::call_depth_problems;
+
+#[check(
+    id = "opentype/CFF2_call_depth",
+    aliases = "com.adobe.fonts/check/cff2_call_depth",
+    rationale = "
+        Per \"The CFF2 CharString Format\", the \"Subr nesting, stack limit\" is 10.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/2425",
+    title = "Is the CFF2 subr/gsubr call depth > 10?"
+)]
+fn CFF2_call_depth(t: &Testable, _context: &Context) -> CheckFnResult {
+    let font = testfont!(t);
+    skip!(
+        !font.has_table(b"CFF2"),
+        "no-cff2",
+        "This check only applies to CFF2 fonts."
+    );
+    call_depth_problems(&font)
+}
@@ -0,0 +1,57 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+use read_fonts::tables::postscript::dict::Entry;
+
+#[check(
+    id = "opentype/CFF_ascii_strings",
+    aliases = "com.adobe.fonts/check/cff_ascii_strings",
+    rationale = "
+        All CFF Table top dict data values must be encoded as ASCII. Non-ASCII
+        characters in these strings are not portable, and some applications
+        will fail to read the font or will display garbled names.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/issues/4619",
+    title = "Does the font's CFF table top dict strings fit into the ASCII range?"
+)]
+fn CFF_ascii_strings(t: &Testable, _context: &Context) -> CheckFnResult {
+    let font = testfont!(t);
+    skip!(
+        !font.has_table(b"CFF "),
+        "no-cff",
+        "This check only applies to CFF fonts."
+    );
+    let not_ascii = font
+        .cff_top_dict()?
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Entry::Version(id) => Some(("Version", id)),
+            Entry::Notice(id) => Some(("Notice", id)),
+            Entry::Copyright(id) => Some(("Copyright", id)),
+            Entry::FullName(id) => Some(("FullName", id)),
+            Entry::FamilyName(id) => Some(("FamilyName", id)),
+            Entry::Weight(id) => Some(("Weight", id)),
+            Entry::PostScript(id) => Some(("PostScript", id)),
+            Entry::BaseFontName(id) => Some(("BaseFontName", id)),
+            Entry::FontName(id) => Some(("FontName", id)),
+            _ => None,
+        })
+        .filter_map(|(key, id)| {
+            let string = font.cff_string(id)?;
+            // CFF strings are Latin-1
+            (!string.is_ascii()).then(|| {
+                format!(
+                    "{}: {}",
+                    key,
+                    string.iter().map(|&b| b as char).collect::<String>()
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if not_ascii.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    return_result(vec![Status::fail(
+        "cff-string-not-in-ascii",
+        "The following CFF TopDict strings are not in the ASCII range:",
+    )
+    .with_items(not_ascii)])
+}
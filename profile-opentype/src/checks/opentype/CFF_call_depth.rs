@@ -0,0 +1,45 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert, TestFont};
+
+/// The Type 2 and CFF2 charstring formats both limit subroutine nesting to ten levels
+const MAX_CALL_DEPTH: usize = 10;
+
+/// Report the glyphs whose charstrings nest subroutine calls too deeply
+pub(super) fn call_depth_problems(font: &TestFont) -> CheckFnResult {
+    let too_deep = font
+        .cff_charstrings()?
+        .into_iter()
+        .filter(|info| info.max_call_depth > MAX_CALL_DEPTH)
+        .map(|info| font.glyph_name_for_id_synthesise(info.glyph))
+        .collect::<Vec<_>>();
+    if too_deep.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    return_result(vec![Status::fail(
+        "max-depth",
+        &format!(
+            "Subroutine call depth exceeded maximum of {} for the following glyphs:",
+            MAX_CALL_DEPTH,
+        ),
+    )
+    .with_items(too_deep)])
+}
+
+#[check(
+    id = "opentype/CFF_call_depth",
+    aliases = "com.adobe.fonts/check/cff_call_depth",
+    rationale = "
+        Per \"The Type 2 Charstring Format, Technical Note #5177\",
+        the \"Subr nesting, stack limit\" is 10.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/2425",
+    title = "Is the CFF subr/gsubr call depth > 10?"
+)]
+fn CFF_call_depth(t: &Testable, _context: &Context) -> CheckFnResult {
+    let font = testfont!(t);
+    skip!(
+        !font.has_table(b"CFF "),
+        "no-cff",
+        "This check only applies to CFF fonts."
+    );
+    call_depth_problems(&font)
+}
@@ -0,0 +1,53 @@
+use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
+
+#[check(
+    id = "opentype/CFF_deprecated_operators",
+    aliases = "com.adobe.fonts/check/cff_deprecated_operators",
+    rationale = "
+        The 'dotsection' operator and the use of 'endchar' to build accented characters
+        from the Adobe Standard Encoding Character Set (\"seac\") are deprecated in CFF.
+        Adobe recommends repairing any fonts that use these, especially endchar-as-seac,
+        because a rendering issue was discovered in Microsoft Word with a font that
+        makes use of this operation. The check treats that usage as a FAIL.
+        There are no known ill effects of using dotsection, so that check is a WARN.
+    ",
+    proposal = "https://github.com/fonttools/fontbakery/pull/3033",
+    title = "Does the font use deprecated CFF operators or operations?"
+)]
+fn CFF_deprecated_operators(t: &Testable, _context: &Context) -> CheckFnResult {
+    let font = testfont!(t);
+    skip!(
+        !font.has_table(b"CFF "),
+        "no-cff",
+        "This check only applies to CFF fonts."
+    );
+    let charstrings = font.cff_charstrings()?;
+    let mut problems = vec![];
+    let dotsection = charstrings
+        .iter()
+        .filter(|info| info.operators.contains("dotsection"))
+        .map(|info| font.glyph_name_for_id_synthesise(info.glyph))
+        .collect::<Vec<_>>();
+    if !dotsection.is_empty() {
+        problems.push(
+            Status::warn(
+                "deprecated-operator-dotsection",
+                "The following glyphs use the deprecated \"dotsection\" operator:",
+            )
+            .with_items(dotsection),
+        );
+    }
+    let seac = charstrings
+        .iter()
+        .filter(|info| info.endchar_seac)
+        .map(|info| font.glyph_name_for_id_synthesise(info.glyph))
+        .collect::<Vec<_>>();
+    if !seac.is_empty() {
+        problems.push(Status::fail(
+            "deprecated-operation-endchar-seac",
+            "The following glyphs use the deprecated \"endchar\" operator to build accented characters (seac):",
+        )
+        .with_items(seac));
+    }
+    return_result(problems)
+}
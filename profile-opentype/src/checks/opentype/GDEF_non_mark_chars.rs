@@ -1,6 +1,6 @@
 use crate::checks::opentype::GDEF_mark_chars::is_nonspacing_mark;
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
-use read_fonts::TableProvider;
+use read_fonts::{types::Tag, TableProvider};
 use skrifa::{GlyphId16, MetadataProvider};
 
 fn swaption<T, U>(a: T, b: Option<U>) -> Option<(T, U)> {
@@ -9,6 +9,7 @@ fn swaption<T, U>(a: T, b: Option<U>) -> Option<(T, U)> {
 
 #[check(
     id = "opentype/GDEF_non_mark_chars",
+    aliases = "com.google.fonts/check/gdef_non_mark_chars",
     rationale = "
         Glyphs in the GDEF mark glyph class become non-spacing and may be repositioned
         if they have mark anchors.
@@ -37,20 +38,22 @@ fn GDEF_non_mark_chars(t: &Testable, context: &Context) -> CheckFnResult {
         .flat_map(|(cp, gid)| swaption(cp, GlyphId16::try_from(gid).ok()));
     let non_mark_gids_in_mark = non_mark_gids.filter(|(_cp, gid)| glyph_classdef.get(*gid) == 3);
     if non_mark_gids_in_mark.clone().count() > 0 {
-        return Ok(Status::just_one_warn(
+        return return_result(vec![Status::warn(
             "non-mark-chars",
-            &format!(
-                "The following non-mark characters should not be in the GDEF mark glyph class:\n{}",
-                bullet_list(
-                    context,
-                    non_mark_gids_in_mark.map(|(cp, gid)| format!(
-                        "U+{:04X} ({})",
-                        cp as u32,
-                        f.glyph_name_for_id_synthesise(gid)
-                    ))
-                ),
-            ),
-        ));
+            "The following non-mark characters should not be in the GDEF mark glyph class:",
+        )
+        .with_items(non_mark_gids_in_mark.clone().map(|(cp, gid)| {
+            format!(
+                "U+{:04X} ({})",
+                cp as u32,
+                f.glyph_name_for_id_synthesise(gid)
+            )
+        }))
+        .with_locations(non_mark_gids_in_mark.map(|(cp, gid)| {
+            Location::glyph(gid, f.glyph_name_for_id_synthesise(gid))
+                .with_codepoint(cp as u32)
+                .in_table(Tag::new(b"GDEF"))
+        }))]);
     }
     Ok(Status::just_one_pass())
 }
@@ -1,8 +1,9 @@
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
-use read_fonts::TableProvider;
+use read_fonts::{types::Tag, TableProvider};
 
 #[check(
     id = "opentype/GDEF_spacing_marks",
+    aliases = "com.google.fonts/check/gdef_spacing_marks",
     rationale = "
         Glyphs in the GDEF mark glyph class should be non-spacing.
 
@@ -12,7 +13,7 @@ use read_fonts::TableProvider;
     proposal = "https://github.com/fonttools/fontbakery/issues/2877",
     title = "Check glyphs in mark glyph class are non-spacing."
 )]
-fn GDEF_spacing_marks(f: &Testable, context: &Context) -> CheckFnResult {
+fn GDEF_spacing_marks(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let hmtx = font.font().hmtx()?;
     let gdef = font
@@ -22,18 +23,19 @@ fn GDEF_spacing_marks(f: &Testable, context: &Context) -> CheckFnResult {
     let glyph_classdef = gdef.glyph_class_def().ok_or_else(|| {
         CheckError::skip("no-glyph-class-def", "GDEF table has no GlyphClassDef")
     })??;
-    let nonspacing_mark_glyphs = bullet_list(
-        context,
-        glyph_classdef
-            .iter()
-            .filter(|(glyph, class)| *class == 3 && hmtx.advance((*glyph).into()).unwrap_or(0) > 0)
-            .map(|(glyph, _)| font.glyph_name_for_id_synthesise(glyph)),
-    );
-    if !nonspacing_mark_glyphs.is_empty() {
-        return Ok(Status::just_one_warn("spacing-mark-glyphs", &format!(
-            "The following glyphs seem to be spacing (because they have width > 0 on the hmtx table) so they may be in the GDEF mark glyph class by mistake, or they should have zero width instead:\n{}",
-                nonspacing_mark_glyphs
-        )));
+    let spacing_mark_glyphs: Vec<_> = glyph_classdef
+        .iter()
+        .filter(|(glyph, class)| *class == 3 && hmtx.advance((*glyph).into()).unwrap_or(0) > 0)
+        .map(|(glyph, _)| (glyph, font.glyph_name_for_id_synthesise(glyph)))
+        .collect();
+    if !spacing_mark_glyphs.is_empty() {
+        return return_result(vec![Status::warn("spacing-mark-glyphs",
+            "The following glyphs seem to be spacing (because they have width > 0 on the hmtx table) so they may be in the GDEF mark glyph class by mistake, or they should have zero width instead:",
+        )
+        .with_items(spacing_mark_glyphs.iter().map(|(_, name)| name.clone()))
+        .with_locations(spacing_mark_glyphs.into_iter().map(|(glyph, name)| {
+            Location::glyph(glyph, name).in_table(Tag::new(b"GDEF"))
+        }))]);
     }
 
     Ok(Status::just_one_pass())
@@ -189,6 +189,7 @@ fn check_ital_is_binary_and_last(t: &TestFont, is_italic: bool) -> Result<Vec<St
 
 #[check(
     id = "opentype/STAT/ital_axis",
+    aliases = "com.google.fonts/check/italic_axis_in_stat",
     rationale = "
         Check that related Upright and Italic VFs have an
         'ital' axis in the STAT table.
@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/caret_slope",
+    aliases = "com.google.fonts/check/caret_slope",
     title = "Check hhea.caretSlopeRise and hhea.caretSlopeRun",
     proposal = "https://github.com/fonttools/fontbakery/issues/3670",
     rationale = r#"
@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/code_pages",
+    aliases = "com.google.fonts/check/code_pages",
     title = "Check code page character ranges",
     rationale = "
         At least some programs (such as Word and Sublime Text) under Windows 7
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use fontspector_checkapi::prelude::*;
+use itertools::Itertools;
+use read_fonts::{types::Tag, CollectionRef, TableProvider};
+
+/// Tables which are indexed by glyph ID, and so only make sense for one glyph count
+const GLYPH_INDEXED_TABLES: [&[u8; 4]; 7] = [
+    b"glyf", b"loca", b"CFF ", b"CFF2", b"hmtx", b"vmtx", b"gvar",
+];
+
+#[check(
+    id = "opentype/collection/consistent_glyph_count",
+    rationale = "
+        Faces in a font collection frequently share outline and metrics tables.
+        Those tables are indexed by glyph ID, so every face which uses a shared
+        glyf, loca, CFF, CFF2, hmtx, vmtx or gvar table must declare the same
+        number of glyphs in its maxp table; otherwise some glyphs will be
+        inaccessible or will be read from past the end of the shared data.
+    ",
+    proposal = "https://learn.microsoft.com/en-us/typography/opentype/spec/otff#collections",
+    title = "Do faces sharing glyph data agree on the number of glyphs?",
+    applies_to = "TTC"
+)]
+fn consistent_glyph_count(t: &Testable, _context: &Context) -> CheckFnResult {
+    let collection = CollectionRef::new(&t.contents)
+        .map_err(|e| CheckError::skip("unparsable", &format!("Not a font collection: {}", e)))?;
+    // (tag, offset) -> [(face index, number of glyphs)]
+    let mut users: HashMap<(Tag, u32), Vec<(usize, u16)>> = HashMap::new();
+    for (index, face) in collection.iter().enumerate() {
+        let face = face?;
+        let num_glyphs = face.maxp()?.num_glyphs();
+        for record in face.table_directory.table_records() {
+            if GLYPH_INDEXED_TABLES.contains(&&record.tag().to_be_bytes()) {
+                users
+                    .entry((record.tag(), record.offset()))
+                    .or_default()
+                    .push((index, num_glyphs));
+            }
+        }
+    }
+    let mut problems = vec![];
+    for ((tag, _), faces) in users.iter().sorted() {
+        if faces.iter().map(|(_, count)| count).all_equal() {
+            continue;
+        }
+        problems.push(Status::fail(
+            "inconsistent-glyph-count",
+            &format!(
+                "Faces sharing the same {} table have different glyph counts: {}",
+                tag,
+                faces
+                    .iter()
+                    .map(|(index, count)| format!("face {} has {} glyphs", index, count))
+                    .join(", ")
+            ),
+        ));
+    }
+    return_result(problems)
+}
@@ -0,0 +1,5 @@
+mod consistent_glyph_count;
+mod shared_tables;
+
+pub use consistent_glyph_count::consistent_glyph_count;
+pub use shared_tables::shared_tables;
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use fontspector_checkapi::prelude::*;
+use itertools::Itertools;
+use read_fonts::{types::Tag, CollectionRef};
+
+#[check(
+    id = "opentype/collection/shared_tables",
+    rationale = "
+        The point of a font collection is that faces can share tables which are
+        identical between them, such as a large glyf or CFF table. Each face's
+        table directory refers to a table by offset; when two faces share a table
+        they must agree on its length, and distinct tables must not overlap each
+        other or run beyond the end of the file.
+
+        Identical tables which are stored more than once are not an error, but
+        they needlessly increase the size of the collection and usually point to
+        a problem in the build process.
+    ",
+    proposal = "https://learn.microsoft.com/en-us/typography/opentype/spec/otff#collections",
+    title = "Are the tables of a font collection shared sensibly?",
+    applies_to = "TTC"
+)]
+fn shared_tables(t: &Testable, _context: &Context) -> CheckFnResult {
+    let collection = CollectionRef::new(&t.contents)
+        .map_err(|e| CheckError::skip("unparsable", &format!("Not a font collection: {}", e)))?;
+    let mut problems = vec![];
+    // offset -> (tag, length, faces using it)
+    let mut tables: HashMap<u32, (Tag, u32, Vec<usize>)> = HashMap::new();
+    for (index, face) in collection.iter().enumerate() {
+        let face = face?;
+        for record in face.table_directory.table_records() {
+            let (tag, offset, length) = (record.tag(), record.offset(), record.length());
+            if offset as usize + length as usize > t.contents.len() {
+                problems.push(Status::fail(
+                    "table-out-of-bounds",
+                    &format!(
+                        "The {} table of face {} extends beyond the end of the file",
+                        tag, index
+                    ),
+                ));
+                continue;
+            }
+            let entry = tables.entry(offset).or_insert((tag, length, vec![]));
+            if entry.0 != tag || entry.1 != length {
+                problems.push(Status::fail(
+                    "inconsistent-shared-table",
+                    &format!(
+                        "Face {} has a {} table of {} bytes at offset {}, but face {} has a {} table of {} bytes there",
+                        index, tag, length, offset, entry.2[0], entry.0, entry.1
+                    ),
+                ));
+                continue;
+            }
+            entry.2.push(index);
+        }
+    }
+
+    let by_offset = tables
+        .iter()
+        .sorted_by_key(|(offset, _)| **offset)
+        .collect::<Vec<_>>();
+    for ((offset, (tag, length, _)), (next_offset, (next_tag, _, _))) in
+        by_offset.iter().tuple_windows()
+    {
+        if **offset + length > **next_offset {
+            problems.push(Status::fail(
+                "overlapping-tables",
+                &format!(
+                    "The {} table at offset {} overlaps the {} table at offset {}",
+                    tag, offset, next_tag, next_offset
+                ),
+            ));
+        }
+    }
+
+    let mut contents: HashMap<(Tag, &[u8]), Vec<&Vec<usize>>> = HashMap::new();
+    for (offset, (tag, length, faces)) in by_offset.iter() {
+        let start = **offset as usize;
+        contents
+            .entry((*tag, &t.contents[start..start + *length as usize]))
+            .or_default()
+            .push(faces);
+    }
+    for ((tag, _), copies) in contents.iter().sorted_by_key(|((tag, _), _)| *tag) {
+        if copies.len() > 1 {
+            problems.push(Status::warn(
+                "duplicate-tables",
+                &format!(
+                    "The {} table is stored {} times with identical contents (used by faces {}); it could be shared",
+                    tag,
+                    copies.len(),
+                    copies.iter().map(|faces| faces.iter().join(", ")).join("; ")
+                ),
+            ));
+        }
+    }
+    return_result(problems)
+}
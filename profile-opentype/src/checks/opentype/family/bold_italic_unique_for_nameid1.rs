@@ -5,6 +5,7 @@ use read_fonts::tables::os2::SelectionFlags;
 
 #[check(
     id = "opentype/family/bold_italic_unique_for_nameid1",
+    aliases = "com.adobe.fonts/check/family/bold_italic_unique_for_nameid1",
     title = "Check that OS/2.fsSelection bold & italic settings are unique for each NameID1",
     rationale = "Per the OpenType spec: name ID 1 'is used in combination with Font Subfamily
         name (name ID 2), and should be shared among at most four fonts that differ
@@ -6,6 +6,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/family/consistent_family_name",
+    aliases = "com.adobe.fonts/check/family/consistent_family_name",
     rationale = r#"
         Per the OpenType spec:
 
@@ -34,7 +35,7 @@ use skrifa::MetadataProvider;
     title = "Verify that family names in the name table are consistent across all fonts in the family. Checks Typographic Family name (nameID 16) if present, otherwise uses Font Family name (nameID 1)",
     implementation = "all"
 )]
-fn consistent_family_name(c: &TestableCollection, context: &Context) -> CheckFnResult {
+fn consistent_family_name(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let fonts = TTF.from_collection(c);
     let mut problems = vec![];
     let mut family_names = HashMap::new();
@@ -62,20 +63,17 @@ fn consistent_family_name(c: &TestableCollection, context: &Context) -> CheckFnR
             .push(font.filename.to_string_lossy().to_string());
     }
     if family_names.len() > 1 {
-        let report = bullet_list(
-            context,
-            family_names
-                .iter()
-                .map(|(name, fonts)| format!("'{}' (found in fonts {})", name, fonts.join(", "))),
-        );
-        problems.push(Status::fail(
-            "inconsistent-family-name",
-            &format!(
-                "{} different family names were found:\n\n{}",
-                family_names.len(),
-                report
+        problems.push(
+            Status::fail(
+                "inconsistent-family-name",
+                &format!("{} different family names were found:", family_names.len()),
+            )
+            .with_items(
+                family_names.iter().map(|(name, fonts)| {
+                    format!("'{}' (found in fonts {})", name, fonts.join(", "))
+                }),
             ),
-        ));
+        );
     }
     return_result(problems)
 }
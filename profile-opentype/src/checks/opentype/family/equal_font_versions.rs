@@ -3,6 +3,7 @@ use read_fonts::{ReadError, TableProvider};
 
 #[check(
     id = "opentype/family/equal_font_versions",
+    aliases = "com.google.fonts/check/family/equal_font_versions",
     title = "Make sure all font files have the same version value.",
     rationale = "Within a family released at the same time, all members of the family should have the same version number in the head table.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",  // legacy check
@@ -5,6 +5,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "opentype/family/max_4_fonts_per_family_name",
+    aliases = "com.adobe.fonts/check/family/max_4_fonts_per_family_name",
     rationale = "
         Per the OpenType spec:
 
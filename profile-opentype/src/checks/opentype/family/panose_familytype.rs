@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/family/panose_familytype",
+    aliases = "com.google.fonts/check/family/panose_familytype",
     title = "Fonts have consistent PANOSE family type?",
     rationale = "
         The [PANOSE value](https://monotype.github.io/panose/) in the OS/2 table is a
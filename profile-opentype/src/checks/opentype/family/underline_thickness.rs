@@ -6,6 +6,7 @@ use read_fonts::{types::FWord, TableProvider};
 
 #[check(
     id = "opentype/family/underline_thickness",
+    aliases = "com.google.fonts/check/family/underline_thickness",
     title = "Fonts have consistent underline thickness?",
     rationale = r#"
         Dave C Lemon (Adobe Type Team) recommends setting the underline thickness to be
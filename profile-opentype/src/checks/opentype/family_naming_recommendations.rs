@@ -12,6 +12,7 @@ const NAME_LIMITS: [(NameId, usize); 6] = [
 
 #[check(
     id = "opentype/family_naming_recommendations",
+    aliases = "com.google.fonts/check/family_naming_recommendations",
     rationale = "
         This check ensures that the length of various family name and style
         name strings in the name table are within the maximum length
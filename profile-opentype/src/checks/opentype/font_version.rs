@@ -4,6 +4,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/font_version",
+    aliases = "com.google.fonts/check/font_version",
     title = "Checking font version fields (head and name table).",
     rationale = "
         The OpenType specification provides for two fields which contain
@@ -6,6 +6,7 @@ use read_fonts::{
 
 #[check(
     id = "opentype/fsselection",
+    aliases = "com.google.fonts/check/fsselection",
     title = "Checking OS/2 fsSelection value.",
     rationale = "
         The OS/2.fsSelection field is a bit field used to specify the stylistic
@@ -3,6 +3,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/fvar/axis_ranges_correct",
+    tags = "variable",
     title = "Axes and named instances fall within correct ranges?",
     rationale = "According to the Open-Type spec's registered design-variation tags, instances in a variable font should have certain prescribed values.
         If a variable font has a 'wght' (Weight) axis, the valid coordinate range is 1-1000.
@@ -26,6 +26,7 @@ fn find_regular(f: TestFont) -> Option<HashMap<String, f32>> {
 
 #[check(
     id = "opentype/fvar/regular_coords_correct",
+    tags = "variable",
     title = "Axes and named instances fall within correct ranges?",
     rationale = "According to the Open-Type spec's registered design-variation tags, instances in a variable font should have certain prescribed values.
         If a variable font has a 'wght' (Weight) axis, the valid coordinate range is 1-1000.
@@ -8,6 +8,7 @@ use std::collections::HashSet;
 
 #[check(
     id = "opentype/glyf_non_transformed_duplicate_components",
+    aliases = "com.google.fonts/check/glyf_non_transformed_duplicate_components",
     rationale = "
         There have been cases in which fonts had faulty double quote marks, with each
         of them containing two single quote marks as components with the same
@@ -19,7 +20,7 @@ use std::collections::HashSet;
     proposal = "https://github.com/fonttools/fontbakery/pull/2709",
     title = "Check glyphs do not have duplicate components which have the same x,y coordinates."
 )]
-fn glyf_non_transformed_duplicate_components(t: &Testable, context: &Context) -> CheckFnResult {
+fn glyf_non_transformed_duplicate_components(t: &Testable, _context: &Context) -> CheckFnResult {
     let ttf = testfont!(t);
     let font = ttf.font();
     skip!(!ttf.has_table(b"glyf"), "no-glyf", "No glyf table");
@@ -48,11 +49,10 @@ fn glyf_non_transformed_duplicate_components(t: &Testable, context: &Context) ->
     if messages.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(
-            Status::just_one_fail("found-duplicates", 
-                &format!("The following glyphs have duplicate components which have the same x,y coordinates.\n\n{}",
-                    bullet_list(context, messages))
-            )
+        return_result(vec![Status::fail(
+            "found-duplicates",
+            "The following glyphs have duplicate components which have the same x,y coordinates:",
         )
+        .with_items(messages)])
     }
 }
@@ -36,7 +36,7 @@ fn x_leftmost_intersection(paths: &[BezPath], y: f32, x_min: f32, x_max: f32) ->
     proposal="https://github.com/fonttools/fontbakery/issues/4829",  // legacy check
     title="Checking post.italicAngle value."
 )]
-fn italic_angle(t: &Testable, context: &Context) -> CheckFnResult {
+fn italic_angle(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let value = f.font().post()?.italic_angle().to_f32();
     let mut problems = vec![];
@@ -129,18 +129,17 @@ fn italic_angle(t: &Testable, context: &Context) -> CheckFnResult {
         }
     }
     if !bad_gids.is_empty() {
-        problems.push(Status::warn(
-            "empty-glyphs",
-            &format!(
-                "The following glyphs were present but did not contain any outlines:\n{}",
-                bullet_list(
-                    context,
-                    bad_gids
-                        .iter()
-                        .map(|&gid| f.glyph_name_for_id_synthesise(gid))
-                )
+        problems.push(
+            Status::warn(
+                "empty-glyphs",
+                "The following glyphs were present but did not contain any outlines:",
+            )
+            .with_items(
+                bad_gids
+                    .iter()
+                    .map(|&gid| f.glyph_name_for_id_synthesise(gid)),
             ),
-        ))
+        )
     }
     return_result(problems)
 }
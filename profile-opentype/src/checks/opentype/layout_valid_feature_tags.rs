@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
 use fontspector_checkapi::{constants::VALID_FEATURE_TAGS, prelude::*, testfont, FileTypeConvert};
+use read_fonts::{types::Tag, TableProvider};
 
 #[check(
     id = "opentype/layout_valid_feature_tags",
+    aliases = "com.google.fonts/check/layout_valid_feature_tags",
     rationale = "
         Incorrect tags can be indications of typos, leftover debugging code
         or questionable approaches, or user error in the font editor. Such typos can
@@ -18,30 +20,53 @@ use fontspector_checkapi::{constants::VALID_FEATURE_TAGS, prelude::*, testfont,
 fn layout_valid_feature_tags(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let mut bad_tag: HashSet<_> = HashSet::new();
-    for (feature_record, _) in font.feature_records(false) {
-        let tag = feature_record.feature_tag().to_string();
-        // ssXX and cvXX are OK.
-        if (tag.starts_with("ss") || tag.starts_with("cv"))
-            && tag[2..].chars().all(|c| c.is_ascii_digit())
-        {
-            continue;
-        }
-        if !VALID_FEATURE_TAGS.contains(&tag.as_str())
-            && (tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_uppercase()))
-        {
-            bad_tag.insert(tag);
+    let mut locations = vec![];
+    let feature_lists = [
+        (
+            Tag::new(b"GSUB"),
+            font.font()
+                .gsub()
+                .ok()
+                .and_then(|gsub| gsub.feature_list().ok()),
+        ),
+        (
+            Tag::new(b"GPOS"),
+            font.font()
+                .gpos()
+                .ok()
+                .and_then(|gpos| gpos.feature_list().ok()),
+        ),
+    ];
+    for (table, feature_list) in feature_lists.iter() {
+        for feature_record in feature_list.iter().flat_map(|list| list.feature_records()) {
+            let tag = feature_record.feature_tag().to_string();
+            // ssXX and cvXX are OK.
+            if (tag.starts_with("ss") || tag.starts_with("cv"))
+                && tag[2..].chars().all(|c| c.is_ascii_digit())
+            {
+                continue;
+            }
+            if !VALID_FEATURE_TAGS.contains(&tag.as_str())
+                && (tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_uppercase()))
+            {
+                bad_tag.insert(tag);
+                let location = Location::feature(*table, feature_record.feature_tag());
+                if !locations.contains(&location) {
+                    locations.push(location);
+                }
+            }
         }
     }
 
-    Ok(if bad_tag.is_empty() {
-        Status::just_one_pass()
-    } else {
-        Status::just_one_fail(
-            "bad-feature-tags",
-            &format!(
-                "The following invalid feature tags were found in the font: {}",
-                bad_tag.into_iter().collect::<Vec<_>>().join(", ")
-            ),
-        )
-    })
+    if bad_tag.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    return_result(vec![Status::fail(
+        "bad-feature-tags",
+        &format!(
+            "The following invalid feature tags were found in the font: {}",
+            bad_tag.into_iter().collect::<Vec<_>>().join(", ")
+        ),
+    )
+    .with_locations(locations)])
 }
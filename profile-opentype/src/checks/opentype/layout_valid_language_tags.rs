@@ -5,6 +5,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/layout_valid_language_tags",
+    aliases = "com.google.fonts/check/layout_valid_language_tags",
     rationale = "
         Incorrect language tags can be indications of typos, leftover debugging code
         or questionable approaches, or user error in the font editor. Such typos can
@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/loca/maxp_num_glyphs",
+    aliases = "com.google.fonts/check/loca/maxp_num_glyphs",
     title = "Does the number of glyphs in the loca table match the maxp table?",
     rationale = "
         The 'maxp' table contains various statistics about the font, including the
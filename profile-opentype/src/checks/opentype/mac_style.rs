@@ -3,6 +3,7 @@ use read_fonts::{tables::head::MacStyle, TableProvider};
 
 #[check(
     id = "opentype/mac_style",
+    aliases = "com.google.fonts/check/mac_style",
     title = "Checking head.macStyle value.",
     rationale = "
         The values of the flags on the macStyle entry on the 'head' OpenType table
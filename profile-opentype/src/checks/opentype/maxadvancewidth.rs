@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/maxadvancewidth",
+    aliases = "com.google.fonts/check/maxadvancewidth",
     title = "MaxAdvanceWidth is consistent with values in the Hmtx and Hhea tables?",
     rationale = "
         The 'hhea' table contains a field which specifies the maximum advance width.
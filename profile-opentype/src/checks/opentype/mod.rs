@@ -1,4 +1,8 @@
 #![allow(non_snake_case)]
+mod CFF2_call_depth;
+mod CFF_ascii_strings;
+mod CFF_call_depth;
+mod CFF_deprecated_operators;
 mod GDEF_mark_chars;
 mod GDEF_non_mark_chars;
 mod GDEF_spacing_marks;
@@ -26,6 +30,7 @@ mod weight_class_fvar;
 mod xavgcharwidth;
 
 pub mod STAT;
+pub mod collection;
 pub mod family;
 pub mod fvar;
 pub mod kern_table;
@@ -55,6 +60,10 @@ pub use unitsperem::unitsperem;
 pub use vendor_id::vendor_id;
 pub use weight_class_fvar::weight_class_fvar;
 pub use xavgcharwidth::xavgcharwidth;
+pub use CFF2_call_depth::CFF2_call_depth;
+pub use CFF_ascii_strings::CFF_ascii_strings;
+pub use CFF_call_depth::CFF_call_depth;
+pub use CFF_deprecated_operators::CFF_deprecated_operators;
 pub use GDEF_mark_chars::GDEF_mark_chars;
 pub use GDEF_non_mark_chars::GDEF_non_mark_chars;
 pub use GDEF_spacing_marks::GDEF_spacing_marks;
@@ -7,6 +7,7 @@ use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
 
 #[check(
     id = "opentype/monospace",
+    aliases = "com.google.fonts/check/monospace",
     rationale = "
         There are various metadata in the OpenType spec to specify if a font is
         monospaced or not. If the font is not truly monospaced, then no monospaced
@@ -118,13 +119,12 @@ fn monospace(t: &Testable, context: &Context) -> CheckFnResult {
             problems.push(Status::warn(
                 "mono-outliers",
                 &format!(
-                    "Font is monospaced but {unusual_count} glyphs ({outliers_ratio:.2}%) have a different width. You should check the widths of: {}",
-                    bullet_list(context, unusually_spaced_glyphs.iter().map(|(gid, metric)| {
-                        let glyphname = font.glyph_name_for_id_synthesise(GlyphId::new(*gid as u32));
-                        format!("{} ({}), width: {}", glyphname, gid, metric.advance())
-                    }))
+                    "Font is monospaced but {unusual_count} glyphs ({outliers_ratio:.2}%) have a different width. You should check the widths of:",
                 ),
-            ));
+            ).with_items(unusually_spaced_glyphs.iter().map(|(gid, metric)| {
+                let glyphname = font.glyph_name_for_id_synthesise(GlyphId::new(*gid as u32));
+                format!("{} ({}), width: {}", glyphname, gid, metric.advance())
+            })));
         } else if post_isfixedpitch == 0 {
             problems.push(Status::fail(
                 "mono-bad-post-isFixedPitch",
@@ -221,7 +221,7 @@ fn glyph_metrics_stats(f: &TestFont, context: &Context) -> Result<GlyphMetricsSt
     }
 
     let mut widths = HashSet::new();
-    for codepoint in f.codepoints(Some(context)) {
+    for &codepoint in f.codepoints(Some(context)).iter() {
         #[allow(clippy::unwrap_used)] // We know it's mapped!
         let glyphid = f.font().charmap().map(codepoint).unwrap();
         // Skip separators, control and GDEF marks
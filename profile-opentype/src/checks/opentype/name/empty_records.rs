@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/name/empty_records",
+    aliases = "com.google.fonts/check/name/empty_records",
     title = "Check name table for empty records.",
     rationale = "Check the name table for empty records, as this can cause problems in Adobe apps.",
     proposal = "https://github.com/fonttools/fontbakery/pull/2369"
@@ -18,15 +19,18 @@ fn empty_records(t: &Testable, _context: &Context) -> CheckFnResult {
             .trim()
             .is_empty()
         {
-            problems.push(Status::fail(
-                "empty-record",
-                &format!(
-                    "Empty name record found for name ID={} platform ID={} encoding ID={}",
-                    record.name_id(),
-                    record.platform_id(),
-                    record.encoding_id(),
-                ),
-            ));
+            problems.push(
+                Status::fail(
+                    "empty-record",
+                    &format!(
+                        "Empty name record found for name ID={} platform ID={} encoding ID={}",
+                        record.name_id(),
+                        record.platform_id(),
+                        record.encoding_id(),
+                    ),
+                )
+                .with_location(Location::name_record(record)),
+            );
         }
     }
     return_result(problems)
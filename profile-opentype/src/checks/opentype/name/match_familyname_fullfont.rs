@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "opentype/name/match_familyname_fullfont",
+    aliases = "com.google.fonts/check/name/match_familyname_fullfont",
     rationale = r#"
         The FULL_FONT_NAME entry in the ‘name’ table should start with the same string
         as the Family Name (FONT_FAMILY_NAME, TYPOGRAPHIC_FAMILY_NAME or
@@ -5,6 +5,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "opentype/name/postscript_name_consistency",
+    aliases = "com.adobe.fonts/check/name/postscript_name_consistency",
     rationale = "
         The PostScript name entries in the font's 'name' table should be
         consistent across platforms.
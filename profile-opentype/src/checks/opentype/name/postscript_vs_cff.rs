@@ -3,6 +3,7 @@ use read_fonts::{types::NameId, TableProvider};
 
 #[check(
     id = "opentype/name/postscript_vs_cff",
+    aliases = "com.adobe.fonts/check/name/postscript_vs_cff",
     rationale = "
         The PostScript name entries in the font's 'name' table should match
         the FontName string in the 'CFF ' table.
@@ -8,6 +8,7 @@ use skrifa::GlyphId;
 
 #[check(
     id = "opentype/points_out_of_bounds",
+    aliases = "com.google.fonts/check/points_out_of_bounds",
     rationale = "
         The glyf table specifies a bounding box for each glyph. This check
         ensures that all points in all glyph paths are within the bounding
@@ -17,7 +18,7 @@ use skrifa::GlyphId;
     proposal = "https://github.com/fonttools/fontbakery/issues/735",
     title = "Check for points out of bounds"
 )]
-fn points_out_of_bounds(t: &Testable, context: &Context) -> CheckFnResult {
+fn points_out_of_bounds(t: &Testable, _context: &Context) -> CheckFnResult {
     let ttf = testfont!(t);
     let font = ttf.font();
     skip!(!ttf.has_table(b"glyf"), "no-glyf", "No glyf table");
@@ -60,9 +61,9 @@ fn points_out_of_bounds(t: &Testable, context: &Context) -> CheckFnResult {
     if messages.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_warn("points-out-of-bounds", 
-        &format!("The following glyphs have coordinates which are out of bounds:\n\n{}\n\nThis happens a lot when points are not extremes, which is usually bad. However, fixing this alert by adding points on extremes may do more harm than good, especially with italics, calligraphic-script, handwriting, rounded and other fonts. So it is common to ignore this message.",
-        bullet_list(context, messages)))
+        return_result(vec![Status::warn("points-out-of-bounds",
+            "Some glyphs have coordinates which are out of bounds. This happens a lot when points are not extremes, which is usually bad. However, fixing this alert by adding points on extremes may do more harm than good, especially with italics, calligraphic-script, handwriting, rounded and other fonts. So it is common to ignore this message.\n\nThe out of bounds coordinates are:",
         )
+        .with_items(messages)])
     }
 }
@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/post_table_version",
+    aliases = "com.google.fonts/check/post_table_version",
     rationale = r#"
         Format 2.5 of the 'post' table was deprecated in OpenType 1.3 and
         should not be used.
@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "opentype/postscript_name",
+    aliases = "com.adobe.fonts/check/postscript_name",
     title = "PostScript name follows OpenType specification requirements?",
     rationale = "The PostScript name is used by some applications to identify the font. It should only consist of characters from the set A-Z, a-z, 0-9, and hyphen.",
     proposal = "https://github.com/miguelsousa/openbakery/issues/62"
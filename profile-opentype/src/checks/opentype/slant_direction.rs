@@ -3,6 +3,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/slant_direction",
+    aliases = "com.google.fonts/check/slant_direction",
     rationale = "
         The 'slnt' axis values are defined as negative values for a clockwise (right)
         lean, and positive values for counter-clockwise lean. This is counter-intuitive
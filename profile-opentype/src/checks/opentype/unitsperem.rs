@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "opentype/unitsperem",
+    aliases = "com.google.fonts/check/unitsperem",
     title = "Checking unitsPerEm value is reasonable.",
     rationale = "
         According to the OpenType spec:
@@ -6,6 +6,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/varfont/STAT_axis_record_for_each_axis",
+    aliases = "com.adobe.fonts/check/varfont/stat_axis_record_for_each_axis",
+    tags = "variable",
     rationale = "
         According to the OpenType spec, there must be an Axis Record
         for every axis defined in the fvar table.
@@ -15,7 +17,7 @@ use skrifa::MetadataProvider;
     title = "All fvar axes have a correspondent Axis Record on STAT table?",
     proposal = "https://github.com/fonttools/fontbakery/pull/3017"
 )]
-fn STAT_axis_record_for_each_axis(t: &Testable, context: &Context) -> CheckFnResult {
+fn STAT_axis_record_for_each_axis(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     skip!(!f.is_variable_font(), "not-variable", "Not a variable font");
     let fvar_axis_tags: HashSet<_> = f
@@ -36,15 +38,12 @@ fn STAT_axis_record_for_each_axis(t: &Testable, context: &Context) -> CheckFnRes
         .difference(&stat_axis_tags)
         .map(|x| x.as_ref())
         .collect();
-    Ok(if missing_axes.is_empty() {
-        Status::just_one_pass()
-    } else {
-        Status::just_one_fail(
-            "missing-axis-records",
-            &format!(
-                "STAT table is missing Axis Records for the following axes:\n\n{}",
-                bullet_list(context, &missing_axes)
-            ),
-        )
-    })
+    if missing_axes.is_empty() {
+        return Ok(Status::just_one_pass());
+    }
+    return_result(vec![Status::fail(
+        "missing-axis-records",
+        "STAT table is missing Axis Records for the following axes:",
+    )
+    .with_items(missing_axes)])
 }
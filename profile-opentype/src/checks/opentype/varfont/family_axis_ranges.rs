@@ -2,6 +2,8 @@ use fontspector_checkapi::{prelude::*, skip, FileTypeConvert, StatusCode};
 
 #[check(
     id = "opentype/varfont/family_axis_ranges",
+    aliases = "com.google.fonts/check/varfont/family_axis_ranges",
+    tags = "variable",
     title = "Check that family axis ranges are identical",
     rationale = "Between members of a family (such as Roman & Italic), the ranges of variable axes must be identical.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4445",
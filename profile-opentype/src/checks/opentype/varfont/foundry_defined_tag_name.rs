@@ -5,6 +5,8 @@ const REGISTERED_AXIS_TAGS: [&str; 5] = ["ital", "opsz", "slnt", "wdth", "wght"]
 
 #[check(
     id = "opentype/varfont/foundry_defined_tag_name",
+    aliases = "com.adobe.fonts/check/varfont/foundry_defined_tag_name",
+    tags = "variable",
     title = "Validate foundry-defined design-variation axis tag names.",
     rationale = "According to the OpenType spec's syntactic requirements for
     foundry-defined design-variation axis tags available at
@@ -5,6 +5,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/varfont/same_size_instance_records",
+    aliases = "com.adobe.fonts/check/varfont/same_size_instance_records",
+    tags = "variable",
     title = "Validates that all of the instance records in a given font have the same size",
     rationale = "According to the 'fvar' documentation in OpenType spec v1.9
         https://docs.microsoft.com/en-us/typography/opentype/spec/fvar
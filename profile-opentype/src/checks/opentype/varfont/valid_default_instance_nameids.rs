@@ -4,6 +4,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/varfont/valid_default_instance_nameids",
+    aliases = "com.adobe.fonts/check/varfont/valid_default_instance_nameids",
+    tags = "variable",
     title = "Validates subfamilyNameID and postScriptNameID for the default instance record",
     rationale = r#"
         According to the 'fvar' documentation in OpenType spec v1.9.1
@@ -4,6 +4,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "opentype/varfont/valid_nameids",
+    tags = "variable",
     title = "Validates that all of the name IDs in an instance record are within the correct range",
     rationale = r#"
         According to the 'fvar' documentation in OpenType spec v1.9
@@ -34,6 +34,7 @@ const AVG_CHAR_WEIGHTS: [(char, u32); 27] = [
 
 #[check(
     id = "opentype/xavgcharwidth",
+    aliases = "com.google.fonts/check/xavgcharwidth",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     title = "Checking OS/2 fsSelection value.",
     rationale = "
@@ -11,12 +11,13 @@ impl fontspector_checkapi::Plugin for OpenType {
         builder
             .add_section("Opentype Specification Checks")
             .add_and_register_check(checks::opentype::caret_slope)
-            // We don't have enough CFF support here:
-            //   - "opentype/CFF2_call_depth"
-            //   - "opentype/CFF_ascii_strings"
-            //   - "opentype/CFF_call_depth"
-            //   - "opentype/CFF_deprecated_operators"
+            .add_and_register_check(checks::opentype::CFF2_call_depth)
+            .add_and_register_check(checks::opentype::CFF_ascii_strings)
+            .add_and_register_check(checks::opentype::CFF_call_depth)
+            .add_and_register_check(checks::opentype::CFF_deprecated_operators)
             .add_and_register_check(checks::opentype::code_pages)
+            .add_and_register_check(checks::opentype::collection::consistent_glyph_count)
+            .add_and_register_check(checks::opentype::collection::shared_tables)
             .add_and_register_check(checks::opentype::family::bold_italic_unique_for_nameid1)
             .add_and_register_check(checks::opentype::family::consistent_family_name)
             .add_and_register_check(checks::opentype::family::equal_font_versions)
@@ -6,6 +6,7 @@ use skrifa::Tag;
 
 #[check(
     id = "STAT_in_statics",
+    aliases = "com.google.fonts/check/STAT_in_statics",
     rationale = "
         Adobe feature syntax allows for the definition of a STAT table. Fonts built
         with a hand-coded STAT table in feature syntax may be built either as static
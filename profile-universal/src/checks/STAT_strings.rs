@@ -6,6 +6,7 @@ use read_fonts::{tables::stat::AxisValue, TableProvider};
 
 #[check(
     id = "STAT_strings",
+    aliases = "com.google.fonts/check/STAT_strings",
     rationale = "
         On the STAT table, the \"Italic\" keyword must not be used on AxisValues
         for variation axes other than 'ital'.
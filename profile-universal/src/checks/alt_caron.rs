@@ -32,6 +32,7 @@ fn mangle_name(glyph: &str) -> String {
 
 #[check(
     id = "alt_caron",
+    aliases = "com.google.fonts/check/alt_caron",
     title = "Check accent of Lcaron, dcaron, lcaron, tcaron",
     rationale = "
         Lcaron, dcaron, lcaron, tcaron should NOT be composed with quoteright
@@ -9,6 +9,7 @@ const ARABIC_LETTER_HIGH_HAMZA: u32 = 0x0675;
 
 #[check(
     id = "arabic_high_hamza",
+    aliases = "com.google.fonts/check/arabic_high_hamza",
     title = "Check that glyph for U+0675 ARABIC LETTER HIGH HAMZA is not a mark.",
     rationale = "
         Many fonts incorrectly treat ARABIC LETTER HIGH HAMZA (U+0675) as a variant of
@@ -20,11 +20,12 @@ fn is_space(c: char) -> bool {
 
 #[check(
     id = "base_has_width",
+    aliases = "com.google.fonts/check/base_has_width",
     rationale = "Base characters should have non-zero advance width.",
     proposal = "Rod on chat",
     title = "Check base characters have non-zero advance width."
 )]
-fn base_has_width(f: &Testable, context: &Context) -> CheckFnResult {
+fn base_has_width(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let hmtx = font.font().hmtx()?;
     let mut problems = vec![];
@@ -58,12 +59,10 @@ fn base_has_width(f: &Testable, context: &Context) -> CheckFnResult {
     if problems.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "zero-width-bases",
-            &format!(
-                "The following glyphs had zero advance width:\n{}",
-                bullet_list(context, problems),
-            ),
-        ))
+            "The following glyphs had zero advance width:",
+        )
+        .with_items(problems)])
     }
 }
@@ -48,6 +48,7 @@ const CASE_MAPPING_EXCEPTIONS: [u32; 22] = [
 
 #[check(
     id = "case_mapping",
+    aliases = "com.google.fonts/check/case_mapping",
     rationale = "
         Ensure that no glyph lacks its corresponding upper or lower counterpart
         (but only when unicode supports case-mapping).
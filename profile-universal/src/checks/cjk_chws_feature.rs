@@ -5,6 +5,7 @@ use skrifa::Tag;
 
 #[check(
     id = "cjk_chws_feature",
+    aliases = "com.google.fonts/check/cjk_chws_feature",
     rationale = "
         The W3C recommends the addition of chws and vchw features to CJK fonts
         to enhance the spacing of glyphs in environments which do not fully support
@@ -35,6 +35,7 @@ fn is_claiming_to_be_cjk_font(f: &TestFont) -> bool {
 
 #[check(
     id = "cjk_not_enough_glyphs",
+    aliases = "com.google.fonts/check/cjk_not_enough_glyphs",
     rationale = "
         Kana has 150 characters and it's the smallest CJK writing system.
 
@@ -53,21 +54,21 @@ fn cjk_not_enough_glyphs(f: &Testable, context: &Context) -> CheckFnResult {
     );
     let cjk_glyphs: Vec<_> = font.cjk_codepoints(Some(context)).collect();
     let cjk_glyph_count = cjk_glyphs.len();
-    Ok(if cjk_glyph_count > 0 && cjk_glyph_count < 150 {
+    if cjk_glyph_count > 0 && cjk_glyph_count < 150 {
         let num_cjk_glyphs = if cjk_glyph_count == 1 {
             "There is only one CJK glyph"
         } else {
             &format!("There are only {} CJK glyphs", cjk_glyph_count)
         };
-        Status::just_one_warn(
+        return_result(vec![Status::warn(
             "cjk-not-enough-glyphs",
             &format!(
-                "{} when there needs to be at least 150 in order to support the smallest CJK writing system, Kana.\nThe following CJK glyphs were found:\n{}\nPlease check that these glyphs have the correct unicodes.",
+                "{} when there needs to be at least 150 in order to support the smallest CJK writing system, Kana.\nPlease check that these glyphs have the correct unicodes. The following CJK glyphs were found:",
                 num_cjk_glyphs,
-                bullet_list(context, cjk_glyphs)
             ),
         )
+        .with_items(cjk_glyphs)])
     } else {
-        Status::just_one_pass()
-    })
+        Ok(Status::just_one_pass())
+    }
 }
@@ -5,6 +5,7 @@ use read_fonts::{tables::cmap::CmapSubtable, TableProvider};
 
 #[check(
     id = "cmap/format_12",
+    aliases = "com.google.fonts/check/cmap/format_12",
     rationale = "
         If a format 12 cmap table is used to address codepoints beyond the BMP,
         it should actually contain such codepoints. Additionally, it should also
@@ -13,7 +14,7 @@ use read_fonts::{tables::cmap::CmapSubtable, TableProvider};
     proposal = "https://github.com/fonttools/fontbakery/pull/3681",
     title = "Check that format 12 cmap subtables are correctly constituted."
 )]
-fn cmap_format_12(t: &Testable, context: &Context) -> CheckFnResult {
+fn cmap_format_12(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let cmap = f.font().cmap()?;
     let format_4 = cmap
@@ -51,11 +52,8 @@ fn cmap_format_12(t: &Testable, context: &Context) -> CheckFnResult {
             if !unmapped.is_empty() {
                 problems.push(Status::warn(
                     "missing-format-4",
-                    &format!(
-                        "The format 12 subtable did not contain all codepoints from the format 4 subtable: {}",
-                        bullet_list(context, unmapped)
-                    )
-                ))
+                    "The format 12 subtable did not contain all codepoints from the format 4 subtable:",
+                ).with_items(unmapped.into_iter().copied()))
             }
         }
     }
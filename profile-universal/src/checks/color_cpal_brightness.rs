@@ -7,6 +7,7 @@ const MINIMUM_BRIGHTNESS: f32 = 0.1 * 256.0;
 
 #[check(
     id = "color_cpal_brightness",
+    aliases = "com.google.fonts/check/color_cpal_brightness",
     rationale = "
          Layers of a COLRv0 font should not be too dark or too bright. When layer colors
         are set explicitly, they can't be changed and they may turn out illegible
@@ -24,7 +25,7 @@ const MINIMUM_BRIGHTNESS: f32 = 0.1 * 256.0;
     proposal = "https://github.com/fonttools/fontbakery/pull/3908",
     title = "Color layers should have a minimum brightness."
 )]
-fn color_cpal_brightness(t: &Testable, context: &Context) -> CheckFnResult {
+fn color_cpal_brightness(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut dark_glyphs = HashSet::new();
     skip!(
@@ -78,17 +79,14 @@ fn color_cpal_brightness(t: &Testable, context: &Context) -> CheckFnResult {
     if dark_glyphs.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_warn(
+        return_result(vec![Status::warn(
             "glyphs-too-dark-or-too-bright",
-            &format!(
-                "These glyphs have layers with colors that are too dark or too bright: {}",
-                bullet_list(
-                    context,
-                    dark_glyphs
-                        .iter()
-                        .map(|g| f.glyph_name_for_id_synthesise(*g))
-                )
-            ),
-        ))
+            "These glyphs have layers with colors that are too dark or too bright:",
+        )
+        .with_items(
+            dark_glyphs
+                .iter()
+                .map(|g| f.glyph_name_for_id_synthesise(*g)),
+        )])
     }
 }
@@ -5,6 +5,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "varfont/consistent_axes",
+    aliases = "com.google.fonts/check/varfont/consistent_axes",
+    tags = "variable",
     rationale = "
         In order to facilitate the construction of intuitive and friendly user
         interfaces, all variable font files in a given family should have the same set
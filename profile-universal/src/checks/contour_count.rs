@@ -44,6 +44,7 @@ static GLYPHS_BY_UNICODE: LazyLock<HashMap<u32, HashSet<usize>>> = LazyLock::new
 
 #[check(
     id = "contour_count",
+    aliases = "com.google.fonts/check/contour_count",
     rationale = "
         
         Visually QAing thousands of glyphs by hand is tiring. Most glyphs can only
@@ -63,7 +64,7 @@ static GLYPHS_BY_UNICODE: LazyLock<HashMap<u32, HashSet<usize>>> = LazyLock::new
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     title = "Check if each glyph has the recommended amount of contours."
 )]
-fn contour_count(t: &Testable, context: &Context) -> CheckFnResult {
+fn contour_count(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
     let mut bad_glyphs = vec![];
@@ -110,7 +111,6 @@ fn contour_count(t: &Testable, context: &Context) -> CheckFnResult {
     if !bad_glyphs.is_empty() {
         problems.push(Status::warn(
             "contour-count",
-            &format!(
                 "This check inspects the glyph outlines and detects the total number of contours in each of them. The expected values are
      infered from the typical ammounts of contours observed in a
      large collection of reference font families. The divergences
@@ -119,19 +119,14 @@ fn contour_count(t: &Testable, context: &Context) -> CheckFnResult {
      may flag actual bugs in the font such as glyphs mapped to an
      incorrect codepoint. Please consider reviewing the design and
      codepoint assignment of these to make sure they are correct.\n\n
-    The following glyphs do not have the recommended number of contours:\n{}",
-                bullet_list(context, &bad_glyphs),
-            ),
-        ));
+    The following glyphs do not have the recommended number of contours:",
+        ).with_items(bad_glyphs));
     }
     if !zero_contours.is_empty() {
         problems.push(Status::fail(
             "no-contour",
-            &format!(
-                "The following glyphs have no contours even though they were expected to have some:\n{}",
-                bullet_list(context, &zero_contours),
-            ),
-        ));
+            "The following glyphs have no contours even though they were expected to have some:",
+        ).with_items(zero_contours));
     }
     return_result(problems)
 }
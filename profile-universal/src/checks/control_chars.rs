@@ -2,6 +2,7 @@ use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 
 #[check(
     id = "control_chars",
+    aliases = "com.google.fonts/check/control_chars",
     rationale = "
         Use of some unacceptable control characters in the U+0000 - U+001F range can
         lead to rendering issues on some platforms.
@@ -23,12 +24,10 @@ fn control_chars(t: &Testable, context: &Context) -> CheckFnResult {
     if bad_characters.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "unacceptable",
-            &format!(
-                "The following unacceptable control characters were identified:\n{}",
-                bullet_list(context, &bad_characters)
-            ),
-        ))
+            "The following unacceptable control characters were identified:",
+        )
+        .with_items(bad_characters)])
     }
 }
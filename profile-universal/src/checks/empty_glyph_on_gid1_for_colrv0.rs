@@ -6,6 +6,7 @@ use skrifa::GlyphId;
 
 #[check(
     id = "empty_glyph_on_gid1_for_colrv0",
+    aliases = "com.google.fonts/check/empty_glyph_on_gid1_for_colrv0",
     rationale = "
         A rendering bug in Windows 10 paints whichever glyph is on GID 1 on top of
         some glyphs, colored or not. This only occurs for COLR version 0 fonts.
@@ -27,6 +27,7 @@ fn is_letter(codepoint: u32) -> bool {
 }
 #[check(
     id = "empty_letters",
+    aliases = "com.google.fonts/check/empty_letters",
     rationale = "
         Font language, script, and character set tagging approaches typically have an
         underlying assumption that letters (i.e. characters with Unicode general
@@ -50,7 +51,7 @@ fn is_letter(codepoint: u32) -> bool {
     proposal = "https://github.com/fonttools/fontbakery/pull/2460",
     title = "Letters in font have glyphs that are not empty?"
 )]
-fn empty_letters(t: &Testable, context: &Context) -> CheckFnResult {
+fn empty_letters(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let blank_ok_set: HashSet<u32> = ALL_HANGUL_SYLLABLES_CODEPOINTS
         .collect::<HashSet<u32>>()
@@ -81,7 +82,10 @@ fn empty_letters(t: &Testable, context: &Context) -> CheckFnResult {
         }
     }
     if !empties.is_empty() {
-        problems.push(Status::fail("empty-letter", &bullet_list(context, empties)));
+        problems.push(
+            Status::fail("empty-letter", "The following letters have empty glyphs:")
+                .with_items(empties),
+        );
     }
     if num_blank_hangul > 0 {
         problems.push(Status::warn(
@@ -5,6 +5,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "family/vertical_metrics",
+    aliases = "com.google.fonts/check/family/vertical_metrics",
     rationale = "
         We want all fonts within a family to have the same vertical metrics so
         their line spacing is consistent across the family.
@@ -13,7 +14,7 @@ use read_fonts::TableProvider;
     title = "Each font in a family must have the same set of vertical metrics values.",
     proposal = "https://github.com/fonttools/fontbakery/issues/1487"
 )]
-fn family_vertical_metrics(c: &TestableCollection, context: &Context) -> CheckFnResult {
+fn family_vertical_metrics(c: &TestableCollection, _context: &Context) -> CheckFnResult {
     let fonts = TTF.from_collection(c);
     let mut problems = vec![];
     let mut metrics = HashMap::new();
@@ -80,20 +81,18 @@ fn family_vertical_metrics(c: &TestableCollection, context: &Context) -> CheckFn
     for (key, values) in metrics.iter() {
         let all_the_same = values.iter().all(|a| a == &values[0]);
         if !all_the_same {
-            problems.push(Status::fail(
-                &format!("{}-mismatch", key),
-                &format!(
-                    "{} is not the same across the family: {}",
-                    key,
-                    bullet_list(
-                        context,
-                        values
-                            .iter()
-                            .zip(font_names.iter())
-                            .map(|(a, b)| format!("{}: {}", b, a))
-                    )
+            problems.push(
+                Status::fail(
+                    &format!("{}-mismatch", key),
+                    &format!("{} is not the same across the family:", key),
+                )
+                .with_items(
+                    values
+                        .iter()
+                        .zip(font_names.iter())
+                        .map(|(a, b)| format!("{}: {}", b, a)),
                 ),
-            ))
+            )
         }
     }
 
@@ -67,6 +67,7 @@ fn family_metrics(fonts: &[TestFont]) -> Result<Metrics, CheckError> {
 
 #[check(
     id = "family/win_ascent_and_descent",
+    aliases = "com.google.fonts/check/family/win_ascent_and_descent",
     rationale = "
         A font's winAscent and winDescent values should be greater than or equal to
         the head table's yMax, abs(yMin) values. If they are less than these values,
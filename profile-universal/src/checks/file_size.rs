@@ -3,6 +3,7 @@ use humansize::{format_size, DECIMAL};
 
 #[check(
     id = "file_size",
+    aliases = "com.google.fonts/check/file_size",
     rationale = "
         Serving extremely large font files causes usability issues.
         This check ensures that file sizes are reasonable.
@@ -12,7 +13,14 @@ use humansize::{format_size, DECIMAL};
 )]
 fn file_size(t: &Testable, context: &Context) -> CheckFnResult {
     let _ = testfont!(t); // Using this for the skip return
-    let size = t.contents.len();
+
+    // A face of a collection is measured on its own, not as the whole collection;
+    // web fonts are measured as they are served, compressed.
+    let size = if t.contents.face_index().is_some() {
+        t.contents.sfnt().len()
+    } else {
+        t.contents.len()
+    };
     let config = context.local_config("file_size");
     let fail_size = config.get("FAIL_SIZE").and_then(|v| v.as_u64());
     let warn_size = config.get("WARN_SIZE").and_then(|v| v.as_u64());
@@ -8,6 +8,8 @@ const NAMECHECK_API_URL: &str = "http://namecheck.fontdata.com/api/";
 
 #[check(
     id = "fontdata_namecheck",
+    aliases = "com.google.fonts/check/fontdata_namecheck",
+    tags = "network",
     rationale = "
         We need to check names are not already used, and today the best place to check
         that is http://namecheck.fontdata.com
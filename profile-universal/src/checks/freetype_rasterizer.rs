@@ -3,6 +3,8 @@ use freetype;
 
 #[check(
     id = "freetype_rasterizer",
+    aliases = "com.adobe.fonts/check/freetype_rasterizer",
+    tags = "slow",
     rationale = "Malformed fonts can cause FreeType to crash.",
     proposal = "https://github.com/fonttools/fontbakery/issues/3642",
     title = "Ensure that the font can be rasterized by FreeType."
@@ -11,7 +13,8 @@ fn freetype_rasterizer(f: &Testable, _context: &Context) -> CheckFnResult {
     let library = freetype::Library::init().map_err(|e| {
         CheckError::Error(format!("Failed to initialize FreeType library: {:?}", e))
     })?;
-    match library.new_memory_face(f.contents.clone(), 0) {
+    let face_index = f.contents.face_index().unwrap_or_default() as isize;
+    match library.new_memory_face(f.contents.to_vec(), face_index) {
         Ok(face) => {
             if let Err(failed) = face
                 .set_char_size(40 * 64, 0, 50, 0)
@@ -3,6 +3,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "fvar_name_entries",
+    aliases = "com.google.fonts/check/fvar_name_entries",
+    tags = "variable",
     rationale = "The purpose of this check is to make sure that all name entries referenced by variable font instances do exist in the name table.",
     proposal = "https://github.com/fonttools/fontbakery/issues/2069",
     title = "All name entries referenced by fvar instances exist on the name table?"
@@ -1,8 +1,9 @@
 use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
-use read_fonts::{tables::gpos::PositionSubtables, TableProvider};
+use read_fonts::{tables::gpos::PositionSubtables, types::Tag, TableProvider};
 
 #[check(
     id = "gpos7",
+    aliases = "com.google.fonts/check/gpos7",
     rationale = "
         Versions of fonttools >=4.14.0 (19 August 2020) perform an optimisation on
         chained contextual lookups, expressing GSUB6 as GSUB5 and GPOS8 and GPOS7
@@ -23,17 +24,21 @@ fn gpos7(t: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(t);
 
     if let Ok(gpos) = font.font().gpos() {
-        for lookup in gpos.lookup_list()?.lookups().iter().flatten() {
+        for (index, lookup) in gpos.lookup_list()?.lookups().iter().enumerate() {
+            let Ok(lookup) = lookup else {
+                continue;
+            };
             // We use "if let" rather than "?" here because it's *possible*
             // to have a lookup with zero lookups and that causes an OutOfBounds
             // error in read-fonts.
             if let Ok(subtables) = lookup.subtables() {
                 // Handles type 7 and extension
                 if matches!(subtables, PositionSubtables::Contextual(_)) {
-                    return Ok(Status::just_one_warn(
+                    return return_result(vec![Status::warn(
                         "has-gpos7",
                         "Font contains a GPOS7 lookup which is not processed by macOS",
-                    ));
+                    )
+                    .with_location(Location::lookup(Tag::new(b"GPOS"), index as u16))]);
                 }
             }
         }
@@ -3,6 +3,7 @@ use read_fonts::{tables::gpos::PositionSubtables, TableProvider};
 
 #[check(
     id = "gpos_kerning_info",
+    aliases = "com.google.fonts/check/gpos_kerning_info",
     rationale = "
         Well-designed fonts use kerning to improve the spacing between
         specific pairs of glyphs. This check ensures that the font has
@@ -74,6 +74,8 @@ fn any_glyphs_have_instructions(font: &FontRef<'_>) -> Result<bool, ReadError> {
 
 #[check(
     id = "hinting_impact",
+    tags = "slow",
+    aliases = "com.google.fonts/check/hinting_impact",
     rationale = "
         This check is merely informative, displaying an useful comparison of filesizes
         of hinted versus unhinted font files.
@@ -84,7 +86,8 @@ fn any_glyphs_have_instructions(font: &FontRef<'_>) -> Result<bool, ReadError> {
 fn hinting_impact(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     skip!(!is_hinted(&font), "not-hinted", "Font is not hinted");
-    let hinted_size = f.contents.len();
+    // Compare like with like: for web fonts, the size of the decompressed font
+    let hinted_size = f.contents.sfnt().len();
     let dehinted = dehinted(&font.font())?;
     let dehinted_size: usize = dehinted.len();
     let increase = hinted_size as isize - dehinted_size as isize;
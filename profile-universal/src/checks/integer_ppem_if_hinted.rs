@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "integer_ppem_if_hinted",
+    aliases = "com.google.fonts/check/integer_ppem_if_hinted",
     rationale = "
         Hinted fonts must have head table flag bit 3 set.
 
@@ -140,6 +140,8 @@ fn problem_report(p: &Problem) -> String {
 }
 #[check(
     id = "interpolation_issues",
+    aliases = "com.google.fonts/check/interpolation_issues",
+    tags = "variable, slow",
     rationale = "
         When creating a variable font, the designer must make sure that corresponding
         paths have the same start points across masters, as well as that corresponding
@@ -152,7 +154,7 @@ fn problem_report(p: &Problem) -> String {
     proposal = "https://github.com/fonttools/fontbakery/issues/3930",
     title = "Detect any interpolation issues in the font."
 )]
-fn interpolation_issues(t: &Testable, context: &Context) -> CheckFnResult {
+fn interpolation_issues(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let font = f.font();
     let upem = font.head()?.units_per_em();
@@ -191,17 +193,13 @@ fn interpolation_issues(t: &Testable, context: &Context) -> CheckFnResult {
                     .ok_or(CheckError::Error("Can't find master index".to_string()))?;
                 let problems = run_tests(&default_glyph, &glyph, None, None, Some(upem));
                 if !problems.is_empty() {
-                    result.push(Status::warn(
-                        "glyph",
-                        &format!(
-                            "Glyph {} has interpolation issues:\n{}",
-                            glyphname,
-                            bullet_list(
-                                context,
-                                problems.iter().map(problem_report).collect::<Vec<_>>()
-                            )
-                        ),
-                    ))
+                    result.push(
+                        Status::warn(
+                            "glyph",
+                            &format!("Glyph {} has interpolation issues:", glyphname),
+                        )
+                        .with_items(problems.iter().map(problem_report)),
+                    )
                 }
             }
         }
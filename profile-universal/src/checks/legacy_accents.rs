@@ -20,6 +20,7 @@ const LEGACY_ACCENTS: [u32; 13] = [
 
 #[check(
     id = "legacy_accents",
+    aliases = "com.google.fonts/check/legacy_accents",
     rationale = "
         Legacy accents should not have anchors and should have positive width.
         They are often used independently of a letter, either as a placeholder
@@ -6,6 +6,7 @@ use skrifa::GlyphId;
 
 #[check(
     id = "ligature_carets",
+    aliases = "com.google.fonts/check/ligature_carets",
     rationale = "
         
         All ligatures in a font must have corresponding caret (text cursor) positions
@@ -20,7 +21,7 @@ use skrifa::GlyphId;
     proposal = "https://github.com/fonttools/fontbakery/issues/1225",
     title = "Are there caret positions declared for every ligature?"
 )]
-fn ligature_carets(t: &Testable, context: &Context) -> CheckFnResult {
+fn ligature_carets(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let ligature_glyphs = f
         .all_glyphs()
@@ -54,16 +55,11 @@ fn ligature_carets(t: &Testable, context: &Context) -> CheckFnResult {
         ));
     }
     if !missing.is_empty() {
-        return Ok(Status::just_one_warn(
+        return return_result(vec![Status::warn(
             "incomplete-caret-pos-data",
-            &format!(
-                "This font lacks caret positioning values for these ligature glyphs:\n\t- {}\n\n",
-                bullet_list(
-                    context,
-                    missing.iter().map(|g| f.glyph_name_for_id_synthesise(*g))
-                )
-            ),
-        ));
+            "This font lacks caret positioning values for these ligature glyphs:",
+        )
+        .with_items(missing.iter().map(|g| f.glyph_name_for_id_synthesise(*g)))]);
     }
     Ok(Status::just_one_pass())
 }
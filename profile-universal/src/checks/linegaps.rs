@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "linegaps",
+    aliases = "com.google.fonts/check/linegaps",
     rationale = "
         The LineGap value is a space added to the line height created by the union
         of the (typo/hhea)Ascender and (typo/hhea)Descender. It is handled differently
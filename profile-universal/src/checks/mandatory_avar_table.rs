@@ -2,6 +2,8 @@ use fontspector_checkapi::{prelude::*, skip, testfont, FileTypeConvert};
 
 #[check(
     id = "mandatory_avar_table",
+    aliases = "com.google.fonts/check/mandatory_avar_table",
+    tags = "variable",
     rationale = "
         Most variable fonts should include an avar table to correctly define
         axes progression rates.
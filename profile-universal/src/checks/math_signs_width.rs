@@ -27,6 +27,7 @@ const COMMON_WIDTH_MATH_CHARS: [char; 314] = [
 
 #[check(
     id = "math_signs_width",
+    aliases = "com.google.fonts/check/math_signs_width",
     rationale = "
         It is a common practice to have math signs sharing the same width
         (preferably the same width as tabular figures accross the entire font family).
@@ -7,12 +7,13 @@ use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
 
 #[check(
     id = "missing_small_caps_glyphs",
+    aliases = "com.google.fonts/check/missing_small_caps_glyphs",
     rationale = "
         Ensure small caps glyphs are available if a font declares smcp or c2sc OT features.",
     proposal = "https://github.com/fonttools/fontbakery/issues/3154",
     title = "Ensure small caps glyphs are available"
 )]
-fn missing_small_caps_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
+fn missing_small_caps_glyphs(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     // Skip if no smcp or c2sc
     let smcp_lookups = f
@@ -71,17 +72,16 @@ fn missing_small_caps_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
         )?;
 
         if !glyphset.is_empty() {
-            problems.push(Status::fail(
-                error_code,
-                &format!(
-                    "The following letters did not take part in {} substitutions:\n{}",
-                    feature,
-                    bullet_list(
-                        context,
-                        glyphset.iter().map(|g| f.glyph_name_for_id_synthesise(*g))
-                    )
-                ),
-            ));
+            problems.push(
+                Status::fail(
+                    error_code,
+                    &format!(
+                        "The following letters did not take part in {} substitutions:",
+                        feature
+                    ),
+                )
+                .with_items(glyphset.iter().map(|g| f.glyph_name_for_id_synthesise(*g))),
+            );
         }
     }
 
@@ -63,6 +63,7 @@ mod whitespace_ink;
 mod whitespace_widths;
 
 pub mod name;
+pub mod webfont;
 
 pub use alt_caron::alt_caron;
 pub use arabic_high_hamza::arabic_high_hamza;
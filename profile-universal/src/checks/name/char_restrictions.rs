@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "name/char_restrictions",
+    aliases = "com.google.fonts/check/name/ascii_only_entries",
     rationale = r#"
         The OpenType spec requires a subset of ASCII
         (any printable characters except "[]{}()<>/%") for
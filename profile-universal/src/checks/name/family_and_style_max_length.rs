@@ -29,6 +29,7 @@ fn low_level_names(name: &Name<'_>, name_id: NameId) -> HashMap<(u16, u16, u16),
 
 #[check(
     id = "name/family_and_style_max_length",
+    aliases = "com.google.fonts/check/name/family_and_style_max_length",
     rationale = "
         This check ensures that the length of name table entries is not
         too long, as this causes problems in some environments.
@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "name/italic_names",
+    aliases = "com.google.fonts/check/name/italic_names",
     rationale = "
         This check ensures that several entries in the name table
         conform to the font's Upright or Italic style,
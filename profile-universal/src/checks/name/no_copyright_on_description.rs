@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "name/no_copyright_on_description",
+    aliases = "com.google.fonts/check/name/no_copyright_on_description",
     rationale = "
         The name table in a font file contains strings about the font;
         there are entries for a copyright field and a description. If the
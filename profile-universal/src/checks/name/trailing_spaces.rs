@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "name/trailing_spaces",
+    aliases = "com.google.fonts/check/name/trailing_spaces",
     title = "Name table records must not have trailing spaces.",
     rationale = "This check ensures that no entries in the name table end in spaces;
                 trailing spaces, particularly in font names, can be confusing to users.
@@ -29,7 +30,7 @@ fn trailing_spaces(f: &Testable, _context: &Context) -> CheckFnResult {
                     name_record.language_id,
                     name_record.name_id,
                     name_record.string(name_table.string_data()).map_err(|_| CheckError::Error("Error reading string".to_string()))?,
-                )))
+                )).with_location(Location::name_record(name_record)))
             }
         }
     }
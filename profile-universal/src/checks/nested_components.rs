@@ -27,7 +27,7 @@ use super::transformed_components::decompose_components_impl;
     title = "Ensure glyphs do not have components which are themselves components.",
     hotfix = decompose_nested_components
 )]
-fn nested_components(f: &Testable, context: &Context) -> CheckFnResult {
+fn nested_components(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let loca = font
         .font()
@@ -59,13 +59,11 @@ fn nested_components(f: &Testable, context: &Context) -> CheckFnResult {
     if failures.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "found-nested-components",
-            &format!(
-                "The following glyphs have components which are themselves component glyphs:\n{}",
-                bullet_list(context, failures)
-            ),
-        ))
+            "The following glyphs have components which are themselves component glyphs:",
+        )
+        .with_items(failures)])
     }
 }
 
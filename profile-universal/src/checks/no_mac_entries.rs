@@ -20,10 +20,13 @@ fn no_mac_entries(t: &Testable, _context: &Context) -> CheckFnResult {
     let mut problems = vec![];
     for rec in f.font().name()?.name_record() {
         if rec.platform_id() == 1 {
-            problems.push(Status::fail(
-                "mac-names",
-                &format!("Please remove name ID {}", rec.name_id()),
-            ))
+            problems.push(
+                Status::fail(
+                    "mac-names",
+                    &format!("Please remove name ID {}", rec.name_id()),
+                )
+                .with_location(Location::name_record(rec)),
+            )
         }
     }
     return_result(problems)
@@ -3,6 +3,7 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "os2_metrics_match_hhea",
+    aliases = "com.google.fonts/check/os2_metrics_match_hhea",
     rationale = "
         OS/2 and hhea vertical metric values should match. This will produce the
         same linespacing on Mac, GNU+Linux and Windows.
@@ -7,6 +7,7 @@ const OPTIONAL_TABLE_TAGS: [&[u8; 4]; 20] = [
 
 #[check(
     id = "required_tables",
+    aliases = "com.google.fonts/check/required_tables",
     title = "Font contains all required tables?",
     rationale = "
         According to the OpenType spec
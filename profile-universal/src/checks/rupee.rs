@@ -16,6 +16,7 @@ const INDIC_DETECTION_CODEPOINTS: [u32; 11] = [
 
 #[check(
     id = "rupee",
+    aliases = "com.google.fonts/check/rupee",
     rationale = "
         Per Bureau of Indian Standards every font supporting one of the
         official Indian languages needs to include Unicode Character
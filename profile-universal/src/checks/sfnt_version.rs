@@ -16,6 +16,7 @@ fn escape_bytes<B: AsRef<[u8]>>(buf: B) -> String {
 
 #[check(
     id = "sfnt_version",
+    aliases = "com.google.fonts/check/sfnt_version",
     rationale = "
         OpenType fonts that contain TrueType outlines should use the value of 0x00010000
         for the sfntVersion. OpenType fonts containing CFF data (version 1 or 2) should
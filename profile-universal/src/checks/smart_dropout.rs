@@ -12,6 +12,7 @@ fn find_subsequence(haystack: &[u8], needle: &[u8]) -> bool {
 
 #[check(
     id = "smart_dropout",
+    aliases = "com.google.fonts/check/smart_dropout",
     rationale = "
         This setup is meant to ensure consistent rendering quality for fonts across
         all devices (with different rendering/hinting capabilities).
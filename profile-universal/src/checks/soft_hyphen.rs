@@ -2,6 +2,7 @@ use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 
 #[check(
     id = "soft_hyphen",
+    aliases = "com.google.fonts/check/soft_hyphen",
     rationale = "
         The 'Soft Hyphen' character (codepoint 0x00AD) is used to mark
         a hyphenation possibility within a word in the absence of or
@@ -2,6 +2,7 @@ use fontspector_checkapi::{prelude::*, testfont, FileTypeConvert};
 
 #[check(
     id = "stylisticset_description",
+    aliases = "com.google.fonts/check/stylisticset_description",
     rationale = "
         Stylistic sets should provide description text. Programs such as InDesign,
         TextEdit and Inkscape use that info to display to the users so that they know
@@ -40,6 +40,7 @@ const NUMERALS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 
 #[check(
     id = "tabular_kerning",
+    aliases = "com.google.fonts/check/tabular_kerning",
     rationale = "
         
         Tabular glyphs should not have kerning, as they are meant to be used in tables.
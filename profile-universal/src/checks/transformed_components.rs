@@ -29,6 +29,7 @@ fn transform_is_semi_flipped(t: Transform) -> bool {
 
 #[check(
     id = "transformed_components",
+    aliases = "com.google.fonts/check/transformed_components",
     rationale = "
         Some families have glyphs which have been constructed by using
         transformed components e.g the 'u' being constructed from a flipped 'n'.
@@ -54,7 +55,7 @@ fn transform_is_semi_flipped(t: Transform) -> bool {
     title = "Ensure component transforms do not perform scaling or rotation.",
     hotfix = decompose_transformed_components
 )]
-fn transformed_components(f: &Testable, context: &Context) -> CheckFnResult {
+fn transformed_components(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
     let loca = font
         .font()
@@ -92,11 +93,11 @@ fn transformed_components(f: &Testable, context: &Context) -> CheckFnResult {
     if failures.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "transformed-components",
-            &format!("The following glyphs had components with scaling or rotation or inverted outline direction:\n{}",
-            bullet_list(context, failures))
-        ))
+            "The following glyphs had components with scaling or rotation or inverted outline direction:",
+        )
+        .with_items(failures)])
     }
 }
 
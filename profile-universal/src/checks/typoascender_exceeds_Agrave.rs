@@ -7,6 +7,7 @@ use skrifa::{
 
 #[check(
     id = "typoascender_exceeds_Agrave",
+    aliases = "com.google.fonts/check/typoascender_exceeds_Agrave",
     rationale = "
         MacOS uses OS/2.sTypoAscender/Descender values to determine the line height
         of a font. If the sTypoAscender value is smaller than the maximum height of
@@ -22,7 +23,7 @@ use skrifa::{
     ",
     proposal = "https://github.com/fonttools/fontbakery/issues/3170",
     title = "Checking that the typoAscender exceeds the yMax of the /Agrave.",
-    metadata = "{\"experimental\": \"since 2024/Jul/17\"}"
+    experimental = true
 )]
 fn typoascender_exceeds_Agrave(f: &Testable, _context: &Context) -> CheckFnResult {
     let font = testfont!(f);
@@ -3,6 +3,7 @@ use read_fonts::types::NameId;
 
 #[check(
     id = "typographic_family_name",
+    aliases = "com.google.fonts/check/typographic_family_name",
     rationale = "
         Check whether Name ID 16 (Typographic Family name) is consistent
         across the set of fonts.
@@ -8,11 +8,12 @@ use read_fonts::{
 
 #[check(
     id = "unique_glyphnames",
+    aliases = "com.google.fonts/check/unique_glyphnames",
     rationale = "Duplicate glyph names prevent font installation on Mac OS X.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",
     title = "Font contains unique glyph names?"
 )]
-fn unique_glyphnames(t: &Testable, context: &Context) -> CheckFnResult {
+fn unique_glyphnames(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     skip!(
         f.font().table_directory.sfnt_version() == TT_SFNT_VERSION
@@ -44,13 +45,10 @@ fn unique_glyphnames(t: &Testable, context: &Context) -> CheckFnResult {
     if duplicates.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "duplicated-glyph-names",
-            &format!(
-                "
-            These glyph names occur more than once: {}",
-                bullet_list(context, duplicates)
-            ),
-        ))
+            "These glyph names occur more than once:",
+        )
+        .with_items(duplicates)])
     }
 }
@@ -13,6 +13,7 @@ use skrifa::{charmap::MapVariant, GlyphId, MetadataProvider};
 
 #[check(
     id = "unreachable_glyphs",
+    aliases = "com.google.fonts/check/unreachable_glyphs",
     rationale = "
         Glyphs are either accessible directly through Unicode codepoints or through
         substitution rules.
@@ -26,7 +27,7 @@ use skrifa::{charmap::MapVariant, GlyphId, MetadataProvider};
     proposal = "https://github.com/fonttools/fontbakery/issues/3160",
     title = "Check font contains no unreachable glyphs"
 )]
-fn unreachable_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
+fn unreachable_glyphs(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut glyphs = f.all_glyphs().collect::<HashSet<_>>();
     // cmap
@@ -121,18 +122,15 @@ fn unreachable_glyphs(t: &Testable, context: &Context) -> CheckFnResult {
     if glyphs.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_warn(
+        return_result(vec![Status::warn(
             "unreachable-glyphs",
-            &format!(
-                "The following glyphs could not be reached by codepoint or substitution rules:\n\n{}",
-                bullet_list(
-                    context,
-                    glyphs
-                        .iter()
-                        .sorted()
-                        .map(|gid| f.glyph_name_for_id_synthesise(*gid))
-                )
-            ),
-        ))
+            "The following glyphs could not be reached by codepoint or substitution rules:",
+        )
+        .with_items(
+            glyphs
+                .iter()
+                .sorted()
+                .map(|gid| f.glyph_name_for_id_synthesise(*gid)),
+        )])
     }
 }
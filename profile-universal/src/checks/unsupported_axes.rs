@@ -3,6 +3,8 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "varfont/unsupported_axes",
+    aliases = "com.google.fonts/check/varfont/unsupported_axes",
+    tags = "variable",
     rationale = "
         The 'ital' axis is not supported yet in Google Chrome.
 
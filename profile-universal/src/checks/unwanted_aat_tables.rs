@@ -9,6 +9,7 @@ const UNWANTED_TABLES: [&[u8; 4]; 23] = [
 
 #[check(
     id = "unwanted_aat_tables",
+    aliases = "com.google.fonts/check/aat",
     title = "Are there unwanted Apple tables?",
     rationale = "
         Apple's TrueType reference manual [1] describes SFNT tables not in the
@@ -25,6 +25,7 @@ The current recommendation is to completely remove the DSIG table."),
 
 #[check(
     id = "unwanted_tables",
+    aliases = "com.google.fonts/check/unwanted_tables",
     title = "Are there unwanted tables?",
     rationale = "Some font editors store source data in their own SFNT tables, and these can sometimes sneak into final release files, which should only have OpenType spec tables.",
     proposal = "https://github.com/fonttools/fontbakery/issues/4829",  // legacy check
@@ -29,6 +29,7 @@ fn test_glyph_name(s: &str) -> NameValidity {
 
 #[check(
     id = "valid_glyphnames",
+    aliases = "com.google.fonts/check/valid_glyphnames",
     title = "Glyph names are all valid?",
     rationale = "Microsoft's recommendations for OpenType Fonts states the following:
 
@@ -17,6 +17,8 @@ use skrifa::{GlyphId, MetadataProvider};
 
 #[check(
     id = "varfont/duplexed_axis_reflow",
+    aliases = "com.google.fonts/check/varfont/duplexed_axis_reflow",
+    tags = "variable",
     rationale = "
         
         Certain axes, such as grade (GRAD) or roundness (ROND), should not
@@ -27,7 +29,7 @@ use skrifa::{GlyphId, MetadataProvider};
     proposal = "https://github.com/fonttools/fontbakery/issues/3187",
     title = "Ensure VFs with duplexed axes do not vary horizontal advance."
 )]
-fn varfont_duplexed_axis_reflow(t: &Testable, context: &Context) -> CheckFnResult {
+fn varfont_duplexed_axis_reflow(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
     const DUPLEXED_AXES: [&str; 2] = ["GRAD", "ROND"];
@@ -110,13 +112,16 @@ fn varfont_duplexed_axis_reflow(t: &Testable, context: &Context) -> CheckFnResul
         let glyphs_list = glyphs
             .iter()
             .map(|(g, pos)| f.glyph_name_for_id_synthesise(*g) + " at position " + pos);
-        problems.push(Status::fail(
-            &format!("{}-causes-reflow", tag.to_lowercase()),
-            &format!(
-                "The following glyphs have variation in horizontal advance due to duplexed axis {}:\n{}",
-                tag, bullet_list(context, glyphs_list)
-            ),
-        ));
+        problems.push(
+            Status::fail(
+                &format!("{}-causes-reflow", tag.to_lowercase()),
+                &format!(
+                    "The following glyphs have variation in horizontal advance due to duplexed axis {}:",
+                    tag
+                ),
+            )
+            .with_items(glyphs_list),
+        );
     }
 
     // Determine if any kerning rules vary the horizontal advance.
@@ -4,6 +4,8 @@ use std::collections::HashMap;
 
 #[check(
     id = "varfont/instances_in_order",
+    aliases = "com.google.fonts/check/varfont/instances_in_order",
+    tags = "variable",
     rationale = "
         Ensure that the fvar table instances are in ascending order of weight.
         Some software, such as Canva, displays the instances in the order they
@@ -13,7 +15,7 @@ use std::collections::HashMap;
     proposal = "https://github.com/googlefonts/fontbakery/issues/3334",
     title = "Ensure the font's instances are in the correct order."
 )]
-fn varfont_instances_in_order(t: &Testable, context: &Context) -> CheckFnResult {
+fn varfont_instances_in_order(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     skip!(!f.has_axis("wght"), "no-wght", "Font has no weight axis");
     let mut problems = vec![];
@@ -39,19 +41,18 @@ fn varfont_instances_in_order(t: &Testable, context: &Context) -> CheckFnResult
     for sublist in sublists {
         let wght_values: Vec<&f32> = sublist.iter().flat_map(|i| i.get("wght")).collect();
         if !wght_values.iter().is_sorted() {
-            problems.push(Status::fail(
-                "instances-not-in-order",
-                &format!(
-                    "The fvar table instances are not in ascending order of weight:\n{}",
-                    bullet_list(
-                        context,
-                        sublist.iter().map(|coords| coords
-                            .iter()
-                            .map(|(k, v)| format!("{}={}", k, v))
-                            .join(", "))
-                    ),
-                ),
-            ));
+            problems.push(
+                Status::fail(
+                    "instances-not-in-order",
+                    "The fvar table instances are not in ascending order of weight:",
+                )
+                .with_items(sublist.iter().map(|coords| {
+                    coords
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .join(", ")
+                })),
+            );
         }
     }
     return_result(problems)
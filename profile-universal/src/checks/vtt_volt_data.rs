@@ -7,13 +7,14 @@ use read_fonts::TableProvider;
 
 #[check(
     id = "vtt_volt_data",
+    aliases = "com.google.fonts/check/vtt_volt_data",
     title = "VTT or Volt Source Data must not be present.",
     rationale = "
         Check to make sure all the VTT source (TSI* tables) and
         VOLT stuff (TSIV and zz features & langsys records) are gone.",
     proposal = "https://github.com/fonttools/fontbakery/pull/4657"
 )]
-fn vtt_volt_data(t: &Testable, context: &Context) -> CheckFnResult {
+fn vtt_volt_data(t: &Testable, _context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let mut problems = vec![];
     let bad_tags = f
@@ -22,13 +23,9 @@ fn vtt_volt_data(t: &Testable, context: &Context) -> CheckFnResult {
         .filter(|tag| tag.starts_with("zz"))
         .collect::<Vec<_>>();
     if !bad_tags.is_empty() {
-        problems.push(Status::fail(
-            "volt-feature",
-            &format!(
-                "Found unwanted VOLT feature tags:\n{}",
-                bullet_list(context, bad_tags)
-            ),
-        ));
+        problems.push(
+            Status::fail("volt-feature", "Found unwanted VOLT feature tags:").with_items(bad_tags),
+        );
     }
     // Language systems, urgh
     let mut bad_langsys = HashSet::new();
@@ -59,13 +56,10 @@ fn vtt_volt_data(t: &Testable, context: &Context) -> CheckFnResult {
         }
     }
     if !bad_langsys.is_empty() {
-        problems.push(Status::fail(
-            "volt-langsys",
-            &format!(
-                "Found unwanted VOLT language system tags:\n{}",
-                bullet_list(context, bad_langsys.iter())
-            ),
-        ));
+        problems.push(
+            Status::fail("volt-langsys", "Found unwanted VOLT language system tags:")
+                .with_items(bad_langsys),
+        );
     }
     return_result(problems)
 }
@@ -0,0 +1,100 @@
+use fontspector_checkapi::{prelude::*, WebFont};
+use quick_xml::events::Event;
+
+/// Check that the metadata is well-formed XML with a `<metadata version="1.0">` root
+fn validate_metadata(xml: &str) -> Result<(), String> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut open_elements = vec![];
+    let mut seen_root = false;
+    loop {
+        let (element, empty) = match reader.read_event().map_err(|e| e.to_string())? {
+            Event::Start(element) => (element, false),
+            Event::Empty(element) => (element, true),
+            Event::End(_) => {
+                open_elements.pop();
+                continue;
+            }
+            Event::Text(text) if open_elements.is_empty() => {
+                if !text.iter().all(|b| b.is_ascii_whitespace()) {
+                    return Err("Text found outside the root element".to_string());
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        let name = String::from_utf8_lossy(element.name().as_ref()).to_string();
+        if open_elements.is_empty() {
+            if seen_root {
+                return Err(format!("Unexpected second root element <{}>", name));
+            }
+            seen_root = true;
+            if name != "metadata" {
+                return Err(format!("Root element is <{}>, not <metadata>", name));
+            }
+            let version = element
+                .try_get_attribute("version")
+                .map_err(|e| e.to_string())?
+                .map(|v| String::from_utf8_lossy(&v.value).to_string());
+            if version.as_deref() != Some("1.0") {
+                return Err("The <metadata> element must have version=\"1.0\"".to_string());
+            }
+        }
+        if !empty {
+            open_elements.push(name);
+        }
+    }
+    if let Some(unclosed) = open_elements.last() {
+        return Err(format!("Element <{}> is never closed", unclosed));
+    }
+    if !seen_root {
+        return Err("No root element found".to_string());
+    }
+    Ok(())
+}
+
+#[check(
+    id = "webfont/metadata",
+    tags = "metadata",
+    rationale = "
+        WOFF and WOFF2 files may carry an extended metadata block, containing
+        licensing and vendor information. If present, it must decompress to the
+        length recorded in the header, and it must be a well-formed UTF-8 XML
+        document whose root element is `<metadata version=\"1.0\">`. User agents
+        are required to ignore the whole block if it is not.
+    ",
+    proposal = "https://www.w3.org/TR/WOFF2/#Metadata",
+    title = "Is the web font extended metadata block well-formed?",
+    applies_to = "WOFF"
+)]
+fn metadata(t: &Testable, _context: &Context) -> CheckFnResult {
+    let webfont = WebFont::new(&t.contents).map_err(|e| {
+        CheckError::skip(
+            "unparsable",
+            &format!("Could not read the web font header: {}", e),
+        )
+    })?;
+    let metadata = match webfont.metadata() {
+        Ok(Some(metadata)) => metadata,
+        Ok(None) => return Ok(Status::just_one_pass()),
+        Err(e) => {
+            return Ok(Status::just_one_fail(
+                "bad-compression",
+                &format!("The metadata block could not be decompressed: {}", e),
+            ))
+        }
+    };
+    let Ok(xml) = std::str::from_utf8(&metadata) else {
+        return Ok(Status::just_one_fail(
+            "not-utf8",
+            "The metadata block is not valid UTF-8",
+        ));
+    };
+    Ok(match validate_metadata(xml) {
+        Ok(()) => Status::just_one_pass(),
+        Err(e) => Status::just_one_fail(
+            "malformed-xml",
+            &format!("The metadata block is not well-formed: {}", e),
+        ),
+    })
+}
@@ -0,0 +1,7 @@
+mod metadata;
+mod private_data;
+mod woff2_transforms;
+
+pub use metadata::metadata;
+pub use private_data::private_data;
+pub use woff2_transforms::woff2_transforms;
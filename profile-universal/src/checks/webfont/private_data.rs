@@ -0,0 +1,32 @@
+use fontspector_checkapi::{prelude::*, WebFont};
+
+#[check(
+    id = "webfont/private_data",
+    rationale = "
+        WOFF and WOFF2 files may carry a private data block, which is ignored by
+        user agents. It is usually left over from build tooling and only adds to
+        the size of the file which every visitor to a website has to download.
+    ",
+    proposal = "https://www.w3.org/TR/WOFF2/#Private",
+    title = "Does the web font contain a private data block?",
+    applies_to = "WOFF"
+)]
+fn private_data(t: &Testable, _context: &Context) -> CheckFnResult {
+    let webfont = WebFont::new(&t.contents).map_err(|e| {
+        CheckError::skip(
+            "unparsable",
+            &format!("Could not read the web font header: {}", e),
+        )
+    })?;
+    Ok(match webfont.private_data() {
+        Err(e) => Status::just_one_fail("bad-private-data", &e.to_string()),
+        Ok(Some(data)) => Status::just_one_warn(
+            "private-data",
+            &format!(
+                "The font contains a private data block of {} bytes, which browsers ignore.",
+                data.len()
+            ),
+        ),
+        Ok(None) => Status::just_one_pass(),
+    })
+}
@@ -0,0 +1,51 @@
+use fontspector_checkapi::{prelude::*, WebFont};
+
+#[check(
+    id = "webfont/woff2_transforms",
+    rationale = "
+        WOFF2 encoders may transform the glyf, loca and hmtx tables to make them
+        compress better. The transforms must be reversible: glyf and loca must be
+        transformed together, the transformed loca table must be empty, hmtx may
+        only be transformed alongside glyf, and the reconstructed tables must match
+        the lengths recorded in the table directory. A font which fails these
+        requirements will be rejected by browsers.
+    ",
+    proposal = "https://www.w3.org/TR/WOFF2/#table_tranforms",
+    title = "Are the WOFF2 table transforms valid?",
+    applies_to = "WOFF2"
+)]
+fn woff2_transforms(t: &Testable, _context: &Context) -> CheckFnResult {
+    let webfont = WebFont::new(&t.contents).map_err(|e| {
+        CheckError::skip(
+            "unparsable",
+            &format!("Could not read the WOFF2 header: {}", e),
+        )
+    })?;
+    let mut problems = vec![];
+    for table in webfont.tables.iter() {
+        let known = match table.tag.to_be_bytes().as_slice() {
+            b"glyf" | b"loca" => table.transform_version == 0 || table.transform_version == 3,
+            b"hmtx" => table.transform_version <= 1,
+            _ => table.transform_version == 0,
+        };
+        if !known {
+            problems.push(Status::fail(
+                "unknown-transform",
+                &format!(
+                    "Table {} uses unknown transform version {}",
+                    table.tag, table.transform_version
+                ),
+            ));
+        }
+    }
+    if problems.is_empty() {
+        // This is the decompressed font which the other checks use, so only build it once
+        if let Err(e) = t.contents.try_sfnt() {
+            problems.push(Status::fail(
+                "reconstruction-failed",
+                &format!("The font tables could not be reconstructed: {}", e),
+            ));
+        }
+    }
+    return_result(problems)
+}
@@ -3,6 +3,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "whitespace_glyphs",
+    aliases = "com.google.fonts/check/whitespace_glyphs",
     rationale = "
         The OpenType specification recommends that fonts should contain
         glyphs for the following whitespace characters:
@@ -9,6 +9,7 @@ const BUT_NOT: [u32; 2] = [0xAD, 0x1680];
 
 #[check(
     id = "whitespace_ink",
+    aliases = "com.google.fonts/check/whitespace_ink",
     rationale = "
            This check ensures that certain whitespace glyphs are empty.
            Certain text layout engines will assume that these glyphs are empty,
@@ -22,7 +23,8 @@ fn whitespace_ink(t: &Testable, context: &Context) -> CheckFnResult {
     let f = testfont!(t);
     let inky = f
         .codepoints(Some(context))
-        .into_iter()
+        .iter()
+        .copied()
         .filter(|cp| {
             (EXTRA_NON_DRAWING.contains(cp)
                 || (char::from_u32(*cp)
@@ -56,12 +58,10 @@ fn whitespace_ink(t: &Testable, context: &Context) -> CheckFnResult {
     if inky.is_empty() {
         Ok(Status::just_one_pass())
     } else {
-        Ok(Status::just_one_fail(
+        return_result(vec![Status::fail(
             "has-ink",
-            &format!(
-                "The following glyphs have ink; they should be replaced by an empty glyph:\n{}",
-                bullet_list(context, inky)
-            ),
-        ))
+            "The following glyphs have ink; they should be replaced by an empty glyph:",
+        )
+        .with_items(inky)])
     }
 }
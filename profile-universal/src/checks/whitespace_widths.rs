@@ -4,6 +4,7 @@ use skrifa::MetadataProvider;
 
 #[check(
     id = "whitespace_widths",
+    aliases = "com.google.fonts/check/whitespace_widths",
     rationale = "
         If the space and nbspace glyphs have different widths, then Google Workspace
         has problems with the font.
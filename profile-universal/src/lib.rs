@@ -89,6 +89,10 @@ impl fontspector_checkapi::Plugin for Universal {
             .add_and_register_check(checks::whitespace_glyphs)
             .add_and_register_check(checks::whitespace_ink)
             .add_and_register_check(checks::whitespace_widths)
+            .add_section("Web Font Container Checks")
+            .add_and_register_check(checks::webfont::metadata)
+            .add_and_register_check(checks::webfont::private_data)
+            .add_and_register_check(checks::webfont::woff2_transforms)
             .build("universal", cr)
 
         //  Checks which don't make sense any more